@@ -1,37 +1,409 @@
+mod watch;
+
 use clap::Parser;
+use serde::Serialize;
 use std::fs;
+use std::io::{IsTerminal, Write};
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use muri::cli::{Cli, GroupBy, OutputFormat};
+use muri::codeowners::CodeOwners;
+use muri::reporter::{
+    markdown_report, report_diff_text, report_json, report_markdown, report_paths, report_sarif,
+    report_text, report_text_by_owner,
+};
+use muri::{
+    ActivePlugin, COMPONENT_EXTENSIONS, DEFAULT_CACHE_DIR, DEFAULT_EXTENSIONS, FileConfig,
+    MuriConfig, MuriError, PluginTrigger, ProgressEvent, ProgressHandler, Report,
+    explain_reachable, find_active_plugins, find_duplicate_files, find_reachable_files,
+    find_unused_files, find_unused_files_workspace,
+};
+use std::collections::BTreeMap;
+use std::process::Command;
+
+/// Project pattern used when neither the CLI nor the config file sets one
+const DEFAULT_PROJECT_PATTERN: &str = "**/*.{ts,tsx,js,jsx,mjs,cjs}";
 
-use muri::cli::{Cli, OutputFormat};
-use muri::reporter::{report_json, report_text};
-use muri::{FileConfig, MuriConfig, MuriError, find_unused_files};
+/// Config file names discovery looks for, in order of precedence
+const CONFIG_FILE_NAMES: &[&str] = &["muri.json", "muri.jsonc", "muri.yaml", "muri.yml"];
 
-/// Find default config file in directory
+/// Find the default config file in `dir` or the closest ancestor that has one.
+///
+/// The walk stops at the repository root (a directory containing `.git`) so a
+/// config outside the project is never picked up.
 fn find_default_config(dir: &Path) -> Option<PathBuf> {
-    let json_path = dir.join("muri.json");
-    if json_path.exists() {
-        return Some(json_path);
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+    for ancestor in dir.ancestors() {
+        for name in CONFIG_FILE_NAMES {
+            let path = ancestor.join(name);
+            if path.exists() {
+                return Some(path);
+            }
+        }
+        if ancestor.join(".git").exists() {
+            break;
+        }
     }
+    None
+}
+
+/// Load config from file path, supporting .json, .jsonc, .yaml and .yml
+fn load_config_file(path: &Path) -> Result<FileConfig, MuriError> {
+    let parse_error =
+        |message: String| MuriError::ConfigParse { path: path.to_path_buf(), message };
 
-    let jsonc_path = dir.join("muri.jsonc");
-    if jsonc_path.exists() {
-        return Some(jsonc_path);
+    let mut content = fs::read_to_string(path).map_err(|e| parse_error(e.to_string()))?;
+    if matches!(path.extension().and_then(|ext| ext.to_str()), Some("yaml" | "yml")) {
+        return serde_yaml::from_str(&content).map_err(|e| parse_error(e.to_string()));
     }
+    json_strip_comments::strip(&mut content).map_err(|e| parse_error(e.to_string()))?;
+    serde_json::from_str(&content).map_err(|e| parse_error(e.to_string()))
+}
 
-    None
+/// Entry points `--init` looks for, in order
+const INIT_ENTRY_CANDIDATES: &[&str] = &[
+    "src/index.ts",
+    "src/index.tsx",
+    "src/main.ts",
+    "src/main.tsx",
+    "src/index.js",
+    "src/index.jsx",
+    "src/main.js",
+    "index.ts",
+    "index.js",
+    "main.ts",
+    "main.js",
+];
+
+/// Build output directories `--init` ignores when present
+const INIT_IGNORED_DIRS: &[&str] = &["dist", "build", "coverage"];
+
+/// Build a starter config from the project layout: the first conventional entry
+/// point that exists (or `src/index.ts`), the default project pattern, and any
+/// build output directories
+fn starter_config(cwd: &Path) -> FileConfig {
+    let entry = INIT_ENTRY_CANDIDATES
+        .iter()
+        .find(|candidate| cwd.join(candidate).is_file())
+        .unwrap_or(&INIT_ENTRY_CANDIDATES[0]);
+    let ignore = INIT_IGNORED_DIRS
+        .iter()
+        .filter(|dir| cwd.join(dir).is_dir())
+        .map(|dir| format!("{dir}/**"))
+        .collect();
+
+    FileConfig {
+        entry: vec![entry.to_string()],
+        project: vec![DEFAULT_PROJECT_PATTERN.to_string()],
+        ignore,
+        ..Default::default()
+    }
+}
+
+/// Write a starter `muri.json` to `cwd`. An existing file is only replaced with `force`.
+fn write_starter_config(cwd: &Path, force: bool) -> Result<PathBuf, String> {
+    let path = cwd.join("muri.json");
+    if path.exists() && !force {
+        return Err(format!("{} already exists (use --force to overwrite)", path.display()));
+    }
+
+    let json = serde_json::to_string_pretty(&starter_config(cwd)).map_err(|e| e.to_string())?;
+    fs::write(&path, json + "\n")
+        .map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+    Ok(path)
+}
+
+/// Load a report saved with `--format json`. Relative paths are resolved against `cwd`.
+fn load_baseline(path: &Path, cwd: &Path) -> Result<Report, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read baseline report {}: {e}", path.display()))?;
+    let mut report: Report = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse baseline report {}: {e}", path.display()))?;
+    for file in &mut report.unused_files {
+        *file = cwd.join(&*file);
+    }
+    Ok(report)
+}
+
+/// Files with a JS/TS or component extension changed between `git_ref` and `HEAD`,
+/// relative to `cwd` (files outside it are left out)
+fn changed_since(cwd: &Path, git_ref: &str) -> Result<Vec<PathBuf>, String> {
+    let output = Command::new("git")
+        .args(["diff", "--name-only", "--relative", &format!("{git_ref}...HEAD")])
+        .current_dir(cwd)
+        .output()
+        .map_err(|e| format!("--since requires git, which could not be run: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git diff against '{git_ref}' failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    let is_project_file = |file: &str| {
+        DEFAULT_EXTENSIONS.iter().chain(COMPONENT_EXTENSIONS).any(|ext| file.ends_with(ext))
+    };
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|file| is_project_file(file))
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// Delete unused files, or only print them when `dry_run` is set.
+/// Files outside `cwd` are never touched. Returns `true` if any deletion failed.
+fn fix_unused_files(files: &[PathBuf], cwd: &Path, dry_run: bool) -> bool {
+    let mut had_errors = false;
+
+    for file in files {
+        let relative = file.strip_prefix(cwd).unwrap_or(file);
+
+        if !file.starts_with(cwd) {
+            eprintln!("Warning: Skipping file outside working directory: {}", file.display());
+            continue;
+        }
+
+        if dry_run {
+            eprintln!("Would delete: {}", relative.display());
+            continue;
+        }
+
+        match fs::remove_file(file) {
+            Ok(()) => eprintln!("Deleted: {}", relative.display()),
+            Err(e) => {
+                eprintln!("Error: Failed to delete '{}': {}", relative.display(), e);
+                had_errors = true;
+            }
+        }
+    }
+
+    had_errors
+}
+
+/// Progress handler that draws a spinner and percentage on a single stderr line
+fn stderr_progress() -> ProgressHandler {
+    const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+    let tick = AtomicUsize::new(0);
+
+    ProgressHandler::new(move |event| {
+        let frame = SPINNER[tick.fetch_add(1, Ordering::Relaxed) % SPINNER.len()];
+        let mut stderr = std::io::stderr().lock();
+        let _ = match event {
+            ProgressEvent::FilesCollected(count) => {
+                write!(stderr, "\r\x1b[2K{frame} Collected {count} files")
+            }
+            ProgressEvent::Parsed(done, total) => {
+                let percent = done * 100 / total.max(1);
+                write!(stderr, "\r\x1b[2K{frame} Parsing {done}/{total} ({percent}%)")
+            }
+            // Clear the line so the report starts on a clean terminal
+            ProgressEvent::Done => write!(stderr, "\r\x1b[2K"),
+        };
+        let _ = stderr.flush();
+    })
+}
+
+/// Print a report in the requested format; text output is grouped by owner when
+/// CODEOWNERS rules are given
+fn print_report(
+    report: &Report,
+    cwd: &Path,
+    format: &OutputFormat,
+    owners: Option<&CodeOwners>,
+    summary: bool,
+    pretty: bool,
+) {
+    match (format, owners) {
+        (OutputFormat::Text, Some(owners)) => report_text_by_owner(report, cwd, owners, summary),
+        (OutputFormat::Text, None) => report_text(report, cwd, summary),
+        (OutputFormat::Json, _) => report_json(report, cwd, pretty),
+        (OutputFormat::Sarif, _) => report_sarif(report, cwd, pretty),
+        (OutputFormat::Markdown, _) => report_markdown(report, cwd),
+        (OutputFormat::Paths, _) => report_paths(report, cwd),
+    }
+}
+
+/// Print a JSON value on stdout, pretty-printed or on a single line
+fn print_json<T: Serialize + ?Sized>(value: &T, pretty: bool) -> serde_json::Result<()> {
+    let json =
+        if pretty { serde_json::to_string_pretty(value)? } else { serde_json::to_string(value)? };
+    println!("{json}");
+    Ok(())
+}
+
+/// Print the import chain from an entry point to `target` (relative to cwd), or
+/// that `target` is unreachable when there is no chain
+fn print_why(
+    chain: Option<&[PathBuf]>,
+    target: &Path,
+    format: &OutputFormat,
+    pretty: bool,
+) -> serde_json::Result<()> {
+    match (format, chain) {
+        (OutputFormat::Json, Some(chain)) => print_json(
+            &serde_json::json!({ "target": chain.last(), "chain": chain, "reachable": true }),
+            pretty,
+        )?,
+        (OutputFormat::Json, None) => {
+            print_json(&serde_json::json!({ "reachable": false }), pretty)?
+        }
+        (_, Some(chain)) => {
+            for (i, file) in chain.iter().enumerate() {
+                if i == 0 {
+                    println!("{}", file.display());
+                } else {
+                    println!("{}-> {}", "  ".repeat(i), file.display());
+                }
+            }
+        }
+        (_, None) => println!("{} is unreachable", target.display()),
+    }
+    Ok(())
+}
+
+/// Print the files reachable from the entry points, relative to `cwd`
+fn print_used_files(
+    files: &[PathBuf],
+    cwd: &Path,
+    format: &OutputFormat,
+    pretty: bool,
+) -> serde_json::Result<()> {
+    let relative: Vec<&Path> =
+        files.iter().map(|file| file.strip_prefix(cwd).unwrap_or(file)).collect();
+    match format {
+        OutputFormat::Json => print_json(
+            &serde_json::json!({ "used_files": relative, "used_count": files.len() }),
+            pretty,
+        )?,
+        OutputFormat::Paths => relative.iter().for_each(|file| println!("{}", file.display())),
+        _ => {
+            println!("Used files ({}):", files.len());
+            relative.iter().for_each(|file| println!("  {}", file.display()));
+        }
+    }
+    Ok(())
 }
 
-/// Load config from file path, supporting .json and .jsonc
-fn load_config_file(path: &Path) -> Result<FileConfig, Box<dyn std::error::Error>> {
-    let mut content = fs::read_to_string(path)?;
-    json_strip_comments::strip(&mut content)?;
-    let config: FileConfig = serde_json::from_str(&content)?;
-    Ok(config)
+/// Print the enabled plugins and what enabled each of them
+fn print_active_plugins(
+    plugins: &[ActivePlugin],
+    format: &OutputFormat,
+    pretty: bool,
+) -> serde_json::Result<()> {
+    match format {
+        OutputFormat::Json => print_json(&serde_json::json!({ "plugins": plugins }), pretty)?,
+        OutputFormat::Paths => plugins.iter().for_each(|plugin| println!("{}", plugin.name)),
+        _ if plugins.is_empty() => println!("No plugins enabled."),
+        _ => {
+            println!("Enabled plugins ({}):", plugins.len());
+            let width = plugins.iter().map(|plugin| plugin.name.len()).max().unwrap_or(0);
+            for plugin in plugins {
+                let reason = match &plugin.trigger {
+                    PluginTrigger::Config => "enabled in config".to_string(),
+                    PluginTrigger::Dependency(dep) => format!("dependency {dep}"),
+                    PluginTrigger::Detected => "detected".to_string(),
+                };
+                println!("  {:width$}  {reason}", plugin.name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Print groups of identical files, relative to `cwd`. Text output separates groups
+/// with a blank line; paths output prints one group per line, tab-separated.
+fn print_duplicate_groups(
+    groups: &[Vec<PathBuf>],
+    cwd: &Path,
+    format: &OutputFormat,
+    pretty: bool,
+) -> serde_json::Result<()> {
+    let groups: Vec<Vec<&Path>> = groups
+        .iter()
+        .map(|group| group.iter().map(|file| file.strip_prefix(cwd).unwrap_or(file)).collect())
+        .collect();
+    match format {
+        OutputFormat::Json => {
+            print_json(&serde_json::json!({ "duplicate_groups": groups }), pretty)?
+        }
+        OutputFormat::Paths => {
+            for group in &groups {
+                let line: Vec<String> = group.iter().map(|f| f.display().to_string()).collect();
+                println!("{}", line.join("\t"));
+            }
+        }
+        _ if groups.is_empty() => println!("No duplicate files found."),
+        _ => {
+            println!("Duplicate file groups ({}):", groups.len());
+            for (i, group) in groups.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                group.iter().for_each(|file| println!("  {}", file.display()));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Print per-package reports. Paths are relative to the workspace root.
+/// SARIF output merges all packages into a single run.
+fn report_workspaces(
+    reports: &BTreeMap<PathBuf, Report>,
+    cwd: &Path,
+    format: &OutputFormat,
+    summary: bool,
+    pretty: bool,
+) -> serde_json::Result<()> {
+    match format {
+        OutputFormat::Text => {
+            for (package, report) in reports {
+                println!("{}:", package.display());
+                report_text(report, cwd, summary);
+                println!();
+            }
+        }
+        OutputFormat::Json => {
+            let reports: BTreeMap<_, _> = reports
+                .iter()
+                .map(|(package, report)| (package, report.relative_to(cwd)))
+                .collect();
+            print_json(&reports, pretty)?;
+        }
+        OutputFormat::Sarif => {
+            let files = reports.values().flat_map(|r| r.unused_files.iter().cloned()).collect();
+            let total = reports.values().map(|r| r.total_files).sum();
+            report_sarif(&Report::new(files, total), cwd, pretty);
+        }
+        OutputFormat::Markdown => {
+            for (package, report) in reports {
+                println!("### `{}`\n", package.display());
+                println!("{}", markdown_report(report, cwd));
+            }
+        }
+        OutputFormat::Paths => reports.values().for_each(|report| report_paths(report, cwd)),
+    }
+    Ok(())
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
+    if cli.init {
+        match write_starter_config(&cli.cwd, cli.force) {
+            Ok(path) => {
+                println!("Created {}", path.display());
+                return Ok(());
+            }
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Load config file
     let file_config = if let Some(config_path) = &cli.config {
         // Use specified config file (error if not found)
@@ -39,14 +411,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             eprintln!("Error: Config file not found: {}", config_path.display());
             std::process::exit(1);
         }
-        Some(load_config_file(config_path)?)
+        match load_config_file(config_path) {
+            Ok(cfg) => Some(cfg),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
     } else {
-        // Look for default config file in cwd
+        // Look for a default config file in cwd or its ancestors
         match find_default_config(&cli.cwd) {
             Some(path) => match load_config_file(&path) {
                 Ok(cfg) => Some(cfg),
                 Err(e) => {
-                    eprintln!("Warning: Failed to parse config file '{}': {}", path.display(), e);
+                    eprintln!("Warning: {e}");
                     None
                 }
             },
@@ -55,20 +433,25 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     // Merge config: CLI args override file config
+    let auto_entry = cli.auto_entry || file_config.as_ref().is_some_and(|cfg| cfg.auto_entry);
+    // Duplicate detection only looks at project files, and plugin listing at none
+    let entry_optional = auto_entry || cli.duplicates || cli.list_plugins;
     let entry = if !cli.entry.is_empty() {
         cli.entry
     } else if let Some(ref cfg) = file_config {
-        if cfg.entry.is_empty() {
+        if cfg.entry.is_empty() && !entry_optional {
             eprintln!("Error: No entry files specified in config or CLI");
             std::process::exit(1);
         }
         cfg.entry.clone()
+    } else if entry_optional {
+        Vec::new()
     } else {
         eprintln!("Error: No entry files specified. Use --entry or provide a config file.");
         std::process::exit(1);
     };
 
-    let default_project = vec!["**/*.{ts,tsx,js,jsx,mjs,cjs}".to_string()];
+    let default_project = vec![DEFAULT_PROJECT_PATTERN.to_string()];
     let project = if !cli.project.is_empty() {
         cli.project
     } else if let Some(ref cfg) = file_config {
@@ -85,22 +468,225 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Vec::new()
     };
 
+    let allowlist = if !cli.allow.is_empty() {
+        cli.allow
+    } else if let Some(ref cfg) = file_config {
+        cfg.allowlist.clone()
+    } else {
+        Vec::new()
+    };
+
+    let report_classes = if !cli.report_classes.is_empty() {
+        cli.report_classes
+    } else if let Some(ref cfg) = file_config {
+        cfg.report_classes.clone()
+    } else {
+        Vec::new()
+    };
+
     let plugins = file_config.as_ref().map(|cfg| cfg.plugins.clone()).unwrap_or_default();
+    let compilers = file_config.as_ref().and_then(|cfg| cfg.compilers.clone()).unwrap_or_default();
+
+    let cache_enabled = !cli.no_cache
+        && (cli.cache || file_config.as_ref().and_then(|cfg| cfg.cache).unwrap_or(false));
+    let cache_dir = cache_enabled.then(|| PathBuf::from(DEFAULT_CACHE_DIR));
+
+    let project_files = if cli.stdin {
+        let files = std::io::stdin()
+            .lines()
+            .map_while(Result::ok)
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .map(PathBuf::from)
+            .collect();
+        Some(files)
+    } else {
+        None
+    };
 
-    let config =
-        MuriConfig { entry, project, cwd: cli.cwd.clone(), ignore, plugins, verbose: cli.verbose };
+    let changed_files = match &cli.since {
+        Some(git_ref) => match changed_since(&cli.cwd, git_ref) {
+            Ok(files) => Some(files),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    let config = MuriConfig {
+        entry,
+        project,
+        cwd: cli.cwd.clone(),
+        ignore,
+        plugins,
+        compilers,
+        verbose: cli.verbose,
+        report_classes,
+        allowlist,
+        max_files: cli.max_files,
+        max_depth: cli.max_depth,
+        cache_dir,
+        unused_exports: cli.unused_exports
+            || file_config.as_ref().is_some_and(|cfg| cfg.unused_exports),
+        respect_gitignore: !cli.no_gitignore,
+        follow_symlinks: cli.follow_symlinks,
+        include_dts: cli.include_types || file_config.as_ref().is_some_and(|cfg| cfg.include_dts),
+        auto_entry,
+        concurrency: cli
+            .concurrency
+            .map(NonZeroUsize::get)
+            .or_else(|| file_config.as_ref().and_then(|cfg| cfg.concurrency)),
+        project_files,
+        changed_files,
+        // Only draw progress on an interactive terminal, so piped stderr stays clean
+        progress: (!cli.quiet && std::io::stderr().is_terminal()).then(stderr_progress),
+        cancel: None,
+    };
 
     let cwd = config.cwd.canonicalize()?;
 
+    if cli.list_plugins {
+        if matches!(cli.format, OutputFormat::Sarif | OutputFormat::Markdown) {
+            eprintln!("Error: --list-plugins supports the text, json and paths formats");
+            std::process::exit(1);
+        }
+        match find_active_plugins(config) {
+            Ok(plugins) => print_active_plugins(&plugins, &cli.format, !cli.compact)?,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if let Some(target) = &cli.why {
+        match explain_reachable(config, target) {
+            Ok(chain) => {
+                print_why(chain.as_deref(), target, &cli.format, !cli.compact)?;
+                if chain.is_none() {
+                    std::process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if cli.duplicates {
+        match find_duplicate_files(config) {
+            Ok(groups) => {
+                print_duplicate_groups(&groups, &cwd, &cli.format, !cli.compact)?;
+                if !groups.is_empty() {
+                    std::process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    if cli.report_used {
+        if matches!(cli.format, OutputFormat::Sarif | OutputFormat::Markdown) {
+            eprintln!("Error: --report-used supports the text, json and paths formats");
+            std::process::exit(1);
+        }
+        match find_reachable_files(config) {
+            Ok(files) => print_used_files(&files, &cwd, &cli.format, !cli.compact)?,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    let owners = (cli.group_by == Some(GroupBy::Owner)).then(|| {
+        CodeOwners::find(&cwd).unwrap_or_else(|| {
+            eprintln!("Warning: No CODEOWNERS file found; all files are unowned");
+            CodeOwners::parse("")
+        })
+    });
+
+    if cli.watch {
+        let format = cli.format.clone();
+        let summary = cli.summary;
+        let pretty = !cli.compact;
+        return watch::run(config, &cwd, |report| {
+            print_report(report, &cwd, &format, owners.as_ref(), summary, pretty)
+        });
+    }
+
+    if cli.workspaces {
+        match find_unused_files_workspace(config) {
+            Ok(reports) => {
+                report_workspaces(&reports, &cwd, &cli.format, cli.summary, !cli.compact)?;
+
+                let unused: Vec<PathBuf> =
+                    reports.values().flat_map(|r| r.unused_files.iter().cloned()).collect();
+                if cli.fix {
+                    if fix_unused_files(&unused, &cwd, cli.dry_run) {
+                        std::process::exit(1);
+                    }
+                    return Ok(());
+                }
+                if unused.len() > cli.max_unused {
+                    std::process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
+    let baseline = match cli.baseline.as_deref().map(|path| load_baseline(path, &cwd)).transpose() {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(1);
+        }
+    };
+
     match find_unused_files(config) {
         Ok(report) => {
-            match cli.format {
-                OutputFormat::Text => report_text(&report, &cwd),
-                OutputFormat::Json => report_json(&report),
+            if let Some(baseline) = &baseline {
+                // Only newly unused files fail the run
+                let diff = report.diff(baseline);
+                match cli.format {
+                    OutputFormat::Json => print_json(&diff, !cli.compact)?,
+                    // Just the newly unused files
+                    OutputFormat::Paths => report_paths(&Report::new(diff.added.clone(), 0), &cwd),
+                    _ => report_diff_text(&diff, &cwd),
+                }
+                if !diff.added.is_empty() {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+
+            print_report(&report, &cwd, &cli.format, owners.as_ref(), cli.summary, !cli.compact);
+
+            if cli.fix {
+                // In fix mode, only deletion failures are errors
+                if fix_unused_files(&report.unused_files, &cwd, cli.dry_run) {
+                    std::process::exit(1);
+                }
+                return Ok(());
             }
 
-            // Exit with error code if unused files found
-            if report.unused_count > 0 {
+            // Exit with error code if unused files exceed the budget, or unused exports are found
+            if report.unused_count > cli.max_unused || !report.unused_exports.is_empty() {
                 std::process::exit(1);
             }
         }
@@ -0,0 +1,156 @@
+use muri::{DEFAULT_CACHE_DIR, MuriConfig, MuriError, Report, Session};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+/// Directories whose contents never affect the analysis
+const IGNORED_DIRS: &[&str] = &["node_modules", ".git", DEFAULT_CACHE_DIR];
+
+/// Quiet period after the last filesystem event before re-running
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Check whether a changed path can affect the analysis
+fn is_relevant(path: &Path, cwd: &Path) -> bool {
+    let relative = path.strip_prefix(cwd).unwrap_or(path);
+    !relative
+        .components()
+        .any(|c| c.as_os_str().to_str().is_some_and(|s| IGNORED_DIRS.contains(&s)))
+}
+
+/// Merge bursts of changed paths: forward a batch once no new changes
+/// have arrived for the debounce window
+fn debounce(raw: Receiver<Vec<PathBuf>>, batches: Sender<Vec<PathBuf>>) {
+    while let Ok(mut batch) = raw.recv() {
+        let disconnected = loop {
+            match raw.recv_timeout(DEBOUNCE) {
+                Ok(more) => batch.extend(more),
+                Err(RecvTimeoutError::Timeout) => break false,
+                Err(RecvTimeoutError::Disconnected) => break true,
+            }
+        };
+
+        batch.sort();
+        batch.dedup();
+        if batches.send(batch).is_err() || disconnected {
+            return;
+        }
+    }
+}
+
+/// Run the analysis once, then again for every batch of changed paths until `changes` closes.
+/// Only the changed files are reparsed; everything else is served from the session's cache.
+pub fn watch_loop(
+    session: &Session,
+    changes: Receiver<Vec<PathBuf>>,
+    mut on_report: impl FnMut(Result<Report, MuriError>),
+) {
+    on_report(session.find_unused_files());
+
+    for paths in changes {
+        session.invalidate(&paths);
+        on_report(session.find_unused_files());
+    }
+}
+
+/// Watch `cwd` and print an updated report after every change, until Ctrl-C
+pub fn run(
+    config: MuriConfig,
+    cwd: &Path,
+    print: impl Fn(&Report),
+) -> Result<(), Box<dyn std::error::Error>> {
+    ctrlc::set_handler(|| std::process::exit(0))?;
+
+    let session = Session::new(config)?;
+    let (raw_tx, raw_rx) = mpsc::channel();
+    let (tx, rx) = mpsc::channel();
+
+    let watch_root = cwd.to_path_buf();
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        // Reads (including muri's own) show up as access events; only changes matter
+        let Ok(event) = result else {
+            return;
+        };
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        let paths: Vec<PathBuf> =
+            event.paths.into_iter().filter(|path| is_relevant(path, &watch_root)).collect();
+        if !paths.is_empty() {
+            let _ = raw_tx.send(paths);
+        }
+    })?;
+    watcher.watch(cwd, RecursiveMode::Recursive)?;
+    std::thread::spawn(move || debounce(raw_rx, tx));
+
+    watch_loop(&session, rx, |result| {
+        match result {
+            Ok(report) => print(&report),
+            Err(e) => eprintln!("Error: {e}"),
+        }
+        eprintln!("\nWatching for changes... (Ctrl-C to exit)");
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_deletion_triggers_recomputation() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "import './used';").unwrap();
+        fs::write(temp.path().join("used.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan-a.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan-b.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let session = Session::new(config).unwrap();
+
+        // After the initial run, delete an orphan and emit the debounced event for it
+        let (tx, rx) = mpsc::channel();
+        let mut tx = Some(tx);
+        let deleted = temp.path().join("orphan-a.ts");
+
+        let mut counts = Vec::new();
+        watch_loop(&session, rx, |result| {
+            counts.push(result.unwrap().unused_count);
+            if let Some(tx) = tx.take() {
+                fs::remove_file(&deleted).unwrap();
+                tx.send(vec![deleted.clone()]).unwrap();
+            }
+        });
+
+        assert_eq!(counts, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_ignored_directories_are_not_relevant() {
+        let cwd = Path::new("/project");
+        assert!(is_relevant(Path::new("/project/src/a.ts"), cwd));
+        assert!(!is_relevant(Path::new("/project/node_modules/x/index.js"), cwd));
+        assert!(!is_relevant(Path::new("/project/.muri-cache/modules.json"), cwd));
+    }
+
+    #[test]
+    fn test_debounce_merges_bursts() {
+        let (raw_tx, raw_rx) = mpsc::channel();
+        let (tx, rx) = mpsc::channel();
+
+        raw_tx.send(vec![PathBuf::from("a.ts")]).unwrap();
+        raw_tx.send(vec![PathBuf::from("b.ts"), PathBuf::from("a.ts")]).unwrap();
+        drop(raw_tx);
+        debounce(raw_rx, tx);
+
+        let batches: Vec<_> = rx.iter().collect();
+        assert_eq!(batches, vec![vec![PathBuf::from("a.ts"), PathBuf::from("b.ts")]]);
+    }
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_auto_entry_uses_bin_scripts() {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("bin")).unwrap();
+    fs::create_dir_all(temp.path().join("lib")).unwrap();
+    fs::write(
+        temp.path().join("package.json"),
+        r#"{ "name": "tool", "bin": { "tool": "bin/cli.js" } }"#,
+    )
+    .unwrap();
+    fs::write(temp.path().join("bin/cli.js"), "require('../lib/run');\n").unwrap();
+    fs::write(temp.path().join("lib/run.js"), "module.exports = {};\n").unwrap();
+    fs::write(temp.path().join("lib/old.js"), "module.exports = {};\n").unwrap();
+
+    let output = muri(temp.path(), &["--auto-entry", "--quiet"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Unused files (1):\n  lib/old.js\n"), "{stdout}");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_entry_required_without_auto_entry() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("package.json"), r#"{ "main": "index.js" }"#).unwrap();
+    fs::write(temp.path().join("index.js"), "").unwrap();
+
+    let output = muri(temp.path(), &[]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No entry files specified"), "{stderr}");
+
+    let output = muri(temp.path(), &["--auto-entry", "--quiet"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "No unused files found.\n");
+    assert!(output.status.success());
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+use tempfile::tempdir;
+
+fn muri(cwd: &Path, args: &[&str]) -> Output {
+    common::muri(cwd, &[&["--entry", "index.ts", "--quiet"], args].concat())
+}
+
+/// Save a JSON report of the project's current state as the baseline
+fn save_baseline(cwd: &Path) -> std::path::PathBuf {
+    let output = muri(cwd, &["--format", "json"]);
+    let path = cwd.join("baseline.json");
+    fs::write(&path, &output.stdout).unwrap();
+    path
+}
+
+fn setup() -> tempfile::TempDir {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "import './used';").unwrap();
+    fs::write(temp.path().join("used.ts"), "").unwrap();
+    fs::write(temp.path().join("old.ts"), "").unwrap();
+    temp
+}
+
+#[test]
+fn test_unchanged_against_baseline() {
+    let temp = setup();
+    let baseline = save_baseline(temp.path());
+
+    let output = muri(temp.path(), &["--baseline", baseline.to_str().unwrap()]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "No changes in unused files since the baseline.\n"
+    );
+}
+
+#[test]
+fn test_newly_unused_file_fails() {
+    let temp = setup();
+    let baseline = save_baseline(temp.path());
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["--baseline", baseline.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Newly unused files (1):\n  + used.ts\n"), "{stdout}");
+    // Files that were already unused aren't repeated
+    assert!(!stdout.contains("old.ts"), "{stdout}");
+}
+
+#[test]
+fn test_no_longer_unused_file_succeeds() {
+    let temp = setup();
+    let baseline = save_baseline(temp.path());
+    fs::remove_file(temp.path().join("old.ts")).unwrap();
+
+    let output = muri(temp.path(), &["--baseline", baseline.to_str().unwrap(), "--format", "json"]);
+    assert!(output.status.success());
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(diff["added"].as_array().unwrap().is_empty());
+    let removed = diff["removed"].as_array().unwrap();
+    assert_eq!(removed.len(), 1);
+    assert!(removed[0].as_str().unwrap().ends_with("old.ts"));
+}
+
+#[test]
+fn test_minimal_unversioned_baseline_loads() {
+    let temp = setup();
+    let baseline = temp.path().join("baseline.json");
+    fs::write(&baseline, r#"{ "unused_files": ["old.ts"] }"#).unwrap();
+
+    let output = muri(temp.path(), &["--baseline", baseline.to_str().unwrap(), "--format", "json"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(diff["added"].as_array().unwrap().is_empty());
+    assert!(diff["removed"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_invalid_baseline_is_an_error() {
+    let temp = setup();
+    fs::write(temp.path().join("baseline.json"), "not json").unwrap();
+
+    let output =
+        muri(temp.path(), &["--baseline", temp.path().join("baseline.json").to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .starts_with("Error: Failed to parse baseline report")
+    );
+}
@@ -0,0 +1,7 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+/// Run the `muri` binary in `cwd` with `args`
+pub fn muri(cwd: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_muri")).arg("-C").arg(cwd).args(args).output().unwrap()
+}
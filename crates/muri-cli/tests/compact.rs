@@ -0,0 +1,53 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_compact_json_is_a_single_line() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+    fs::write(temp.path().join("old.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["-e", "index.ts", "--format", "json", "--compact"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.trim_end().lines().count(), 1);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["unused_files"], serde_json::json!([{ "path": "old.ts", "bytes": 0 }]));
+}
+
+#[test]
+fn test_json_is_pretty_by_default() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+    fs::write(temp.path().join("old.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["-e", "index.ts", "--format", "json"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.trim_end().lines().count() > 1);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(report["unused_files"], serde_json::json!([{ "path": "old.ts", "bytes": 0 }]));
+}
+
+#[test]
+fn test_compact_applies_to_every_json_output() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "import './used';").unwrap();
+    fs::write(temp.path().join("used.ts"), "").unwrap();
+
+    for args in [
+        &["--report-used", "--format", "json"][..],
+        &["--list-plugins", "--format", "json"],
+        &["--duplicates", "--format", "json"],
+        &["--format", "sarif"],
+    ] {
+        let compact = muri(temp.path(), &[&["-e", "index.ts", "--compact"], args].concat());
+        let stdout = String::from_utf8_lossy(&compact.stdout);
+        assert_eq!(stdout.trim_end().lines().count(), 1, "{args:?}");
+        serde_json::from_str::<serde_json::Value>(&stdout).unwrap();
+
+        let pretty = muri(temp.path(), &[&["-e", "index.ts"], args].concat());
+        assert!(String::from_utf8_lossy(&pretty.stdout).trim_end().lines().count() > 1);
+    }
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_config_discovered_in_ancestor_directory() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+    fs::create_dir(root.join(".git")).unwrap();
+    fs::create_dir_all(root.join("packages/app/src")).unwrap();
+    fs::write(root.join("muri.jsonc"), "// shared config\n{ \"entry\": [\"src/index.ts\"] }")
+        .unwrap();
+    fs::write(root.join("packages/app/src/index.ts"), "").unwrap();
+    fs::write(root.join("packages/app/src/unused.ts"), "").unwrap();
+
+    let output = muri(&root.join("packages/app"), &["--quiet"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Unused files (1):\n  src/unused.ts\n"), "{stdout}");
+
+    // A config closer to the working directory wins
+    fs::write(root.join("packages/app/muri.json"), r#"{ "entry": ["src/*.ts"] }"#).unwrap();
+    let output = muri(&root.join("packages/app"), &["--quiet"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "No unused files found.\n");
+}
+
+#[test]
+fn test_config_search_stops_at_git_boundary() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+    fs::write(root.join("muri.json"), r#"{ "entry": ["src/index.ts"] }"#).unwrap();
+    fs::create_dir_all(root.join("repo/.git")).unwrap();
+    fs::create_dir_all(root.join("repo/src")).unwrap();
+    fs::write(root.join("repo/src/index.ts"), "").unwrap();
+
+    let output = muri(&root.join("repo/src"), &["--quiet"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No entry files specified"), "{stderr}");
+}
+
+#[test]
+fn test_yaml_config_matches_json_config() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+    fs::create_dir(root.join("src")).unwrap();
+    fs::write(root.join("src/index.ts"), "import './used';").unwrap();
+    fs::write(root.join("src/used.ts"), "").unwrap();
+    fs::write(root.join("src/unused.ts"), "").unwrap();
+    fs::write(root.join("src/index.stories.ts"), "").unwrap();
+
+    let json = r#"{
+  "entry": ["src/index.ts"],
+  "project": ["src/**/*.ts"],
+  "plugins": { "storybook": false, "jest": { "entry": ["test/**/*.check.ts"] } }
+}"#;
+    let yaml = "\
+# Same settings as the JSON config
+entry:
+  - src/index.ts
+project:
+  - \"src/**/*.ts\"
+plugins:
+  storybook: false
+  jest:
+    entry: [\"test/**/*.check.ts\"]
+";
+    let from_json: muri::FileConfig = serde_json::from_str(json).unwrap();
+    let from_yaml: muri::FileConfig = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        serde_json::to_value(&from_yaml).unwrap(),
+        serde_json::to_value(&from_json).unwrap()
+    );
+
+    fs::write(root.join("muri.yml"), yaml).unwrap();
+    let output = muri(root, &["--quiet"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.stderr.is_empty(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(
+        stdout.starts_with("Unused files (2):\n  src/index.stories.ts\n  src/unused.ts\n"),
+        "{stdout}"
+    );
+
+    fs::remove_file(root.join("muri.yml")).unwrap();
+    fs::write(root.join("config.json"), json).unwrap();
+    let config = root.join("config.json");
+    let from_json_run = muri(root, &["--quiet", "--config", config.to_str().unwrap()]);
+    assert_eq!(String::from_utf8_lossy(&from_json_run.stdout), stdout);
+}
+
+#[test]
+fn test_invalid_yaml_config_is_reported() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("muri.yaml"), "entry: [unclosed\n").unwrap();
+
+    let config = temp.path().join("muri.yaml");
+    let output = muri(temp.path(), &["--config", config.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("muri.yaml"), "{stderr}");
+}
@@ -0,0 +1,31 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_duplicates_prints_identical_files() {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("src/old")).unwrap();
+    fs::write(temp.path().join("src/date.ts"), "export const today = () => new Date();").unwrap();
+    fs::write(temp.path().join("src/old/date.ts"), "export const today = () => new Date();")
+        .unwrap();
+    fs::write(temp.path().join("src/time.ts"), "export const now = () => Date.now();").unwrap();
+
+    let output = muri(temp.path(), &["--duplicates", "--quiet"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Duplicate file groups (1):\n  src/date.ts\n  src/old/date.ts\n"
+    );
+
+    let output = muri(temp.path(), &["--duplicates", "--format", "json", "--quiet"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["duplicate_groups"], serde_json::json!([["src/date.ts", "src/old/date.ts"]]));
+
+    fs::remove_file(temp.path().join("src/old/date.ts")).unwrap();
+    let output = muri(temp.path(), &["--duplicates", "--quiet"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "No duplicate files found.\n");
+}
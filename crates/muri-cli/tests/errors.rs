@@ -0,0 +1,34 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_invalid_config_reports_path() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+    let config = temp.path().join("muri.json");
+    fs::write(&config, "{ \"entry\": [").unwrap();
+
+    let output = muri(temp.path(), &["--config", config.to_str().unwrap()]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.starts_with("Error: Failed to parse config file"), "{stderr}");
+    assert!(stderr.contains(&config.display().to_string()), "{stderr}");
+}
+
+#[test]
+fn test_invalid_pattern_reports_pattern() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["--entry", "index.ts", "--ignore", "src/[abc"]);
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Error: Invalid glob pattern: \"src/[abc\"\n"
+    );
+}
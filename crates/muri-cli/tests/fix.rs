@@ -0,0 +1,52 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn write_project(dir: &Path) {
+    fs::write(dir.join("index.ts"), "import './used';").unwrap();
+    fs::write(dir.join("used.ts"), "export {};").unwrap();
+    fs::write(dir.join("unused.ts"), "export {};").unwrap();
+}
+
+fn run_muri(dir: &Path, args: &[&str]) -> std::process::Output {
+    common::muri(dir, &[&["--entry", "index.ts"], args].concat())
+}
+
+#[test]
+fn test_fix_deletes_unused_files() {
+    let temp = tempdir().unwrap();
+    write_project(temp.path());
+
+    let output = run_muri(temp.path(), &["--fix"]);
+
+    assert!(output.status.success());
+    assert!(!temp.path().join("unused.ts").exists());
+    assert!(temp.path().join("used.ts").exists());
+    assert!(temp.path().join("index.ts").exists());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Deleted: unused.ts"));
+}
+
+#[test]
+fn test_fix_dry_run_keeps_files() {
+    let temp = tempdir().unwrap();
+    write_project(temp.path());
+
+    let output = run_muri(temp.path(), &["--fix", "--dry-run"]);
+
+    assert!(output.status.success());
+    assert!(temp.path().join("unused.ts").exists());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Would delete: unused.ts"));
+}
+
+#[test]
+fn test_dry_run_requires_fix() {
+    let temp = tempdir().unwrap();
+    write_project(temp.path());
+
+    let output = run_muri(temp.path(), &["--dry-run"]);
+
+    assert!(!output.status.success());
+    assert!(temp.path().join("unused.ts").exists());
+}
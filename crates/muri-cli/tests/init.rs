@@ -0,0 +1,49 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_init_writes_config_that_loads() {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("src")).unwrap();
+    fs::create_dir(temp.path().join("dist")).unwrap();
+    fs::write(temp.path().join("src/main.ts"), "import './used';").unwrap();
+    fs::write(temp.path().join("src/used.ts"), "").unwrap();
+    fs::write(temp.path().join("src/unused.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["--init"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let config: muri::FileConfig =
+        serde_json::from_str(&fs::read_to_string(temp.path().join("muri.json")).unwrap()).unwrap();
+    assert_eq!(config.entry, vec!["src/main.ts"]);
+    assert_eq!(config.project, vec!["**/*.{ts,tsx,js,jsx,mjs,cjs}"]);
+    assert_eq!(config.ignore, vec!["dist/**"]);
+
+    // A plain run picks up the generated config, with its entry and no warnings
+    let output = muri(temp.path(), &["--format", "json", "--quiet"]);
+    assert!(output.stderr.is_empty(), "{}", String::from_utf8_lossy(&output.stderr));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let unused = report["unused_files"].as_array().unwrap();
+    assert_eq!(unused.len(), 1);
+    assert!(unused[0]["path"].as_str().unwrap().ends_with("src/unused.ts"));
+}
+
+#[test]
+fn test_init_refuses_to_overwrite_without_force() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("muri.json"), "{ \"entry\": [\"custom.ts\"] }").unwrap();
+
+    let output = muri(temp.path(), &["--init"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("use --force to overwrite"));
+    assert!(fs::read_to_string(temp.path().join("muri.json")).unwrap().contains("custom.ts"));
+
+    let output = muri(temp.path(), &["--init", "--force"]);
+    assert!(output.status.success());
+    let content = fs::read_to_string(temp.path().join("muri.json")).unwrap();
+    assert!(content.contains("\"src/index.ts\""), "{content}");
+    assert!(content.contains("\"plugins\": {}"), "{content}");
+}
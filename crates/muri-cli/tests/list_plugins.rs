@@ -0,0 +1,46 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_list_plugins_shows_triggering_dependencies() {
+    let temp = tempdir().unwrap();
+    fs::write(
+        temp.path().join("package.json"),
+        r#"{ "dependencies": { "next": "^14" }, "devDependencies": { "jest": "^29" } }"#,
+    )
+    .unwrap();
+    fs::write(temp.path().join("muri.json"), r#"{ "plugins": { "vite": { "enabled": true } } }"#)
+        .unwrap();
+
+    // No entry points are needed to list plugins
+    let output = muri(temp.path(), &["--list-plugins"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Enabled plugins (3):\n  jest    dependency jest\n  nextjs  dependency next\n  vite    enabled in config\n"
+    );
+
+    let output = muri(temp.path(), &["--list-plugins", "--format", "json"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        report["plugins"],
+        serde_json::json!([
+            { "name": "jest", "enabled_by": "dependency", "dependency": "jest" },
+            { "name": "nextjs", "enabled_by": "dependency", "dependency": "next" },
+            { "name": "vite", "enabled_by": "config" },
+        ])
+    );
+}
+
+#[test]
+fn test_list_plugins_without_any() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("package.json"), "{}").unwrap();
+
+    let output = muri(temp.path(), &["--list-plugins"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "No plugins enabled.\n");
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::process::Output;
+use tempfile::tempdir;
+
+/// A project with two unused files
+fn setup() -> tempfile::TempDir {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+    fs::write(temp.path().join("a.ts"), "").unwrap();
+    fs::write(temp.path().join("b.ts"), "").unwrap();
+    temp
+}
+
+fn muri(cwd: &Path, max_unused: &str) -> Output {
+    common::muri(
+        cwd,
+        &["--entry", "index.ts", "--format", "json", "--quiet", "--max-unused", max_unused],
+    )
+}
+
+fn unused_count(output: &Output) -> u64 {
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    report["unused_count"].as_u64().unwrap()
+}
+
+#[test]
+fn test_below_threshold_succeeds() {
+    let temp = setup();
+    let output = muri(temp.path(), "3");
+
+    assert!(output.status.success());
+    assert_eq!(unused_count(&output), 2);
+}
+
+#[test]
+fn test_at_threshold_succeeds() {
+    let temp = setup();
+    let output = muri(temp.path(), "2");
+
+    assert!(output.status.success());
+    assert_eq!(unused_count(&output), 2);
+}
+
+#[test]
+fn test_above_threshold_fails() {
+    let temp = setup();
+    let output = muri(temp.path(), "1");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(unused_count(&output), 2);
+}
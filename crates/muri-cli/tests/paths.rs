@@ -0,0 +1,29 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_paths_format_prints_only_unused_paths() {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("src/utils")).unwrap();
+    fs::write(temp.path().join("src/index.ts"), "import './used';").unwrap();
+    fs::write(temp.path().join("src/used.ts"), "").unwrap();
+    fs::write(temp.path().join("src/old.ts"), "export const old = 1;").unwrap();
+    fs::write(temp.path().join("src/utils/dead.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["-e", "src/index.ts", "--format", "paths", "--verbose"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "src/old.ts\nsrc/utils/dead.ts\n");
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_paths_format_with_nothing_unused_is_empty() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["-e", "index.ts", "--format", "paths"]);
+    assert!(output.stdout.is_empty());
+    assert!(output.status.success());
+}
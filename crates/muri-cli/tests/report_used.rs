@@ -0,0 +1,56 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+fn setup() -> tempfile::TempDir {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("src/utils")).unwrap();
+    fs::write(temp.path().join("src/index.ts"), "import './app';").unwrap();
+    fs::write(temp.path().join("src/app.ts"), "import { fmt } from './utils/format';").unwrap();
+    fs::write(temp.path().join("src/utils/format.ts"), "export const fmt = 1;").unwrap();
+    fs::write(temp.path().join("src/unused.ts"), "").unwrap();
+    temp
+}
+
+#[test]
+fn test_report_used_lists_reachable_files() {
+    let temp = setup();
+
+    let output = muri(temp.path(), &["-e", "src/index.ts", "--report-used", "--quiet"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Used files (3):\n  src/app.ts\n  src/index.ts\n  src/utils/format.ts\n"
+    );
+
+    let output =
+        muri(temp.path(), &["-e", "src/index.ts", "--report-used", "--format", "paths", "--quiet"]);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "src/app.ts\nsrc/index.ts\nsrc/utils/format.ts\n"
+    );
+
+    let output =
+        muri(temp.path(), &["-e", "src/index.ts", "--report-used", "--format", "json", "--quiet"]);
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["used_count"], 3);
+    assert_eq!(
+        report["used_files"],
+        serde_json::json!(["src/app.ts", "src/index.ts", "src/utils/format.ts"])
+    );
+}
+
+#[test]
+fn test_report_used_rejects_sarif() {
+    let temp = setup();
+
+    let output =
+        muri(temp.path(), &["-e", "src/index.ts", "--report-used", "--format", "sarif", "--quiet"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--report-used supports"), "{stderr}");
+}
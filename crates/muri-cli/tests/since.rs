@@ -0,0 +1,61 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::tempdir;
+
+fn git(cwd: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=muri", "-c", "user.email=muri@example.com"])
+        .args(args)
+        .current_dir(cwd)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn test_since_reports_only_changed_unused_files() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+    fs::create_dir(root.join("src")).unwrap();
+    fs::write(root.join("src/index.ts"), "import './old-used';\n").unwrap();
+    fs::write(root.join("src/old-used.ts"), "").unwrap();
+    fs::write(root.join("src/old-unused.ts"), "").unwrap();
+    git(root, &["init", "-q", "-b", "main"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "initial"]);
+
+    git(root, &["checkout", "-q", "-b", "feature"]);
+    fs::write(root.join("src/index.ts"), "import './old-used';\nimport './new-used';\n").unwrap();
+    fs::write(root.join("src/new-used.ts"), "").unwrap();
+    fs::write(root.join("src/new-unused.ts"), "").unwrap();
+    fs::write(root.join("README.md"), "").unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "feature"]);
+
+    let output = muri(root, &["-e", "src/index.ts", "--since", "main", "--quiet"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Unused files (1):\n  src/new-unused.ts\n"), "{stdout}");
+    assert!(!stdout.contains("old-unused"), "{stdout}");
+    assert_eq!(output.status.code(), Some(1));
+
+    // Nothing changed since the feature branch itself
+    let output = muri(root, &["-e", "src/index.ts", "--since", "feature", "--quiet"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "No unused files found.\n");
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_since_outside_git_repository_fails() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "").unwrap();
+
+    let output = muri(temp.path(), &["-e", "index.ts", "--since", "main"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.starts_with("Error: git diff against 'main' failed:"), "{stderr}");
+}
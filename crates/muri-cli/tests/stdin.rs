@@ -0,0 +1,30 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use tempfile::tempdir;
+
+#[test]
+fn test_stdin_limits_project_files() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("index.ts"), "import './used';").unwrap();
+    fs::write(temp.path().join("used.ts"), "export {};").unwrap();
+    fs::write(temp.path().join("listed.ts"), "export {};").unwrap();
+    fs::write(temp.path().join("unlisted.ts"), "export {};").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_muri"))
+        .arg("-C")
+        .arg(temp.path())
+        .args(["--entry", "index.ts", "--stdin", "--format", "json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"used.ts\nlisted.ts\n\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(!output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["total_files"], 2);
+    assert_eq!(report["unused_count"], 1);
+    assert!(report["unused_files"][0]["path"].as_str().unwrap().ends_with("listed.ts"));
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+fn setup() -> tempfile::TempDir {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("src/utils")).unwrap();
+    fs::write(temp.path().join("src/index.ts"), "import { format } from '@/utils/format';\n")
+        .unwrap();
+    fs::write(temp.path().join("src/utils/format.ts"), "export const format = 1;").unwrap();
+    temp
+}
+
+#[test]
+fn test_verbose_lists_unresolved_imports() {
+    let temp = setup();
+
+    let output = muri(temp.path(), &["-e", "src/index.ts", "--verbose", "--quiet"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.starts_with("Could not resolve (1):\n  src/index.ts: @/utils/format\n\n"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("Unused files (1):\n  src/utils/format.ts"), "{stdout}");
+
+    // Configuring the alias fixes the resolution and the false positive
+    fs::write(
+        temp.path().join("tsconfig.json"),
+        r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@/*": ["src/*"] } } }"#,
+    )
+    .unwrap();
+    let output = muri(temp.path(), &["-e", "src/index.ts", "--verbose", "--quiet"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout, "No unused files found.\n");
+}
+
+#[test]
+fn test_unresolved_imports_hidden_without_verbose() {
+    let temp = setup();
+
+    let output = muri(temp.path(), &["-e", "src/index.ts", "--quiet"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("Could not resolve"), "{stdout}");
+
+    let output = muri(temp.path(), &["-e", "src/index.ts", "--quiet", "--format", "json"]);
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(report.get("unresolved_imports").is_none());
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::muri;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_why_prints_chain() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("a.ts"), "import './b';").unwrap();
+    fs::write(temp.path().join("b.ts"), "import './c';").unwrap();
+    fs::write(temp.path().join("c.ts"), "export {};").unwrap();
+
+    let output = muri(temp.path(), &["--entry", "a.ts", "--why", "c.ts"]);
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "a.ts\n  -> b.ts\n    -> c.ts\n");
+}
+
+#[test]
+fn test_why_unreachable() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("a.ts"), "export {};").unwrap();
+    fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+    let output = muri(temp.path(), &["--entry", "a.ts", "--why", "orphan.ts"]);
+
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "orphan.ts is unreachable\n");
+}
+
+#[test]
+fn test_why_json_prints_chain() {
+    let temp = tempdir().unwrap();
+    fs::create_dir_all(temp.path().join("src")).unwrap();
+    fs::write(temp.path().join("src/a.ts"), "import './b';").unwrap();
+    fs::write(temp.path().join("src/b.ts"), "export {};").unwrap();
+
+    let output = muri(
+        temp.path(),
+        &["--entry", "src/a.ts", "--why", "src/b.ts", "--format", "json", "--compact"],
+    );
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.lines().count(), 1);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "target": "src/b.ts",
+            "chain": ["src/a.ts", "src/b.ts"],
+            "reachable": true
+        })
+    );
+}
+
+#[test]
+fn test_why_json_unreachable() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("a.ts"), "export {};").unwrap();
+    fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+    let output = muri(temp.path(), &["--entry", "a.ts", "--why", "orphan.ts", "--format", "json"]);
+
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json, serde_json::json!({ "reachable": false }));
+}
@@ -1,7 +1,10 @@
-use muri::{MuriConfig, find_reachable_files, find_unused_files};
+use muri::{MuriConfig, build_dependency_graph, find_reachable_files, find_unused_files};
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Options for finding unused files
 #[napi(object)]
@@ -32,6 +35,34 @@ pub struct UnusedFilesReport {
     pub unused_count: u32,
 }
 
+/// Token for cancelling a running analysis, e.g. from an `AbortSignal`:
+/// `signal.addEventListener('abort', () => token.cancel())`
+#[napi]
+pub struct CancelToken {
+    flag: Arc<AtomicBool>,
+}
+
+#[napi]
+impl CancelToken {
+    #[napi(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self { flag: Arc::new(AtomicBool::new(false)) }
+    }
+
+    /// Stop the analysis; it rejects with "Analysis was cancelled"
+    #[napi]
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether `cancel` has been called
+    #[napi(getter)]
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+    }
+}
+
 impl From<UnusedFilesOptions> for MuriConfig {
     fn from(opts: UnusedFilesOptions) -> Self {
         MuriConfig {
@@ -41,8 +72,7 @@ impl From<UnusedFilesOptions> for MuriConfig {
                 .unwrap_or_else(|| vec!["**/*.{ts,tsx,js,jsx,mjs,cjs}".to_string()]),
             cwd: opts.cwd.map(PathBuf::from).unwrap_or_else(|| PathBuf::from(".")),
             ignore: opts.ignore.unwrap_or_default(),
-            plugins: Default::default(),
-            verbose: false,
+            ..Default::default()
         }
     }
 }
@@ -50,10 +80,15 @@ impl From<UnusedFilesOptions> for MuriConfig {
 /// Find unused files in a JavaScript/TypeScript project (async)
 ///
 /// @param options - Configuration options
+/// @param token - Optional token to cancel the analysis
 /// @returns Promise with report containing unused files and statistics
 #[napi]
-pub async fn find_unused(options: UnusedFilesOptions) -> Result<UnusedFilesReport> {
-    let config: MuriConfig = options.into();
+pub async fn find_unused(
+    options: UnusedFilesOptions,
+    token: Option<&CancelToken>,
+) -> Result<UnusedFilesReport> {
+    let mut config: MuriConfig = options.into();
+    config.cancel = token.map(|token| Arc::clone(&token.flag));
     let cwd = config.cwd.clone();
 
     // Run CPU-bound work in blocking thread pool
@@ -109,10 +144,15 @@ pub fn find_unused_sync(options: UnusedFilesOptions) -> Result<UnusedFilesReport
 /// Find all files reachable from entry points (async)
 ///
 /// @param options - Configuration options
+/// @param token - Optional token to cancel the analysis
 /// @returns Promise with array of reachable file paths
 #[napi]
-pub async fn find_reachable(options: UnusedFilesOptions) -> Result<Vec<String>> {
-    let config: MuriConfig = options.into();
+pub async fn find_reachable(
+    options: UnusedFilesOptions,
+    token: Option<&CancelToken>,
+) -> Result<Vec<String>> {
+    let mut config: MuriConfig = options.into();
+    config.cancel = token.map(|token| Arc::clone(&token.flag));
     let cwd = config.cwd.clone();
 
     let result = tokio::task::spawn_blocking(move || find_reachable_files(config))
@@ -128,3 +168,25 @@ pub async fn find_reachable(options: UnusedFilesOptions) -> Result<Vec<String>>
         .map(|p| p.strip_prefix(&cwd_canonical).unwrap_or(p).to_string_lossy().to_string())
         .collect())
 }
+
+/// Build the dependency graph of a project
+///
+/// @param options - Configuration options
+/// @returns Object mapping each file (relative to cwd) to the files it imports
+#[napi]
+pub fn build_graph(options: UnusedFilesOptions) -> Result<BTreeMap<String, Vec<String>>> {
+    let config: MuriConfig = options.into();
+    let cwd = config.cwd.clone();
+
+    let edges = build_dependency_graph(config).map_err(|e| Error::from_reason(e.to_string()))?;
+
+    let cwd_canonical =
+        cwd.canonicalize().map_err(|e| Error::from_reason(format!("Invalid cwd: {e}")))?;
+    let relative =
+        |p: &PathBuf| p.strip_prefix(&cwd_canonical).unwrap_or(p).to_string_lossy().to_string();
+
+    Ok(edges
+        .iter()
+        .map(|(file, imports)| (relative(file), imports.iter().map(relative).collect()))
+        .collect())
+}
@@ -0,0 +1,141 @@
+use crate::types::FOREIGN_FILE_EXTENSIONS;
+use clap::ValueEnum;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Broad category of a project file, used to restrict which unused files are reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileClass {
+    /// Production source code (anything not matching another class)
+    #[value(name = "src")]
+    #[serde(rename = "src")]
+    Source,
+    /// Test files (`*.test.*`, `*.spec.*`, `__tests__/`, etc.)
+    Test,
+    /// Storybook stories (`*.stories.*`, `*.story.*`)
+    Story,
+    /// Tool configuration (`*.config.*`, `.*rc.*`, or discovered by a plugin as a config path)
+    Config,
+    /// Non-code assets (styles, images, fonts, etc.)
+    Asset,
+}
+
+/// Directory names whose contents are treated as tests
+const TEST_DIRS: &[&str] = &["__tests__", "__mocks__", "test", "tests", "e2e", "cypress"];
+
+/// Classifies files by plugin provenance first, then by naming conventions
+pub struct FileClassifier {
+    cwd: PathBuf,
+    plugin_paths: FxHashSet<PathBuf>,
+}
+
+impl FileClassifier {
+    /// Create a classifier that labels plugin-discovered paths (config files) as `Config`
+    pub fn new(cwd: &Path, plugin_paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self { cwd: cwd.to_path_buf(), plugin_paths: plugin_paths.into_iter().collect() }
+    }
+
+    /// Classify a single file
+    pub fn classify(&self, path: &Path) -> FileClass {
+        if self.plugin_paths.contains(path) {
+            return FileClass::Config;
+        }
+        // Only directories inside the project count towards naming conventions
+        classify_by_name(path.strip_prefix(&self.cwd).unwrap_or(path))
+    }
+}
+
+/// Classify a file using only its name and parent directories.
+/// Paths should be relative to the project root.
+pub fn classify_by_name(path: &Path) -> FileClass {
+    let file_name = match path.file_name().and_then(|n| n.to_str()) {
+        Some(name) => name,
+        None => return FileClass::Source,
+    };
+
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        let ext_with_dot = format!(".{ext}");
+        if FOREIGN_FILE_EXTENSIONS.contains(&ext_with_dot.as_str()) {
+            return FileClass::Asset;
+        }
+    }
+
+    // Inner segments of the file name, e.g. `Button.stories.tsx` -> ["stories"]
+    let segments: Vec<&str> = file_name.split('.').collect();
+    let inner = if segments.len() > 2 { &segments[1..segments.len() - 1] } else { &[][..] };
+
+    if inner.iter().any(|s| matches!(*s, "stories" | "story")) {
+        return FileClass::Story;
+    }
+
+    if inner.iter().any(|s| matches!(*s, "test" | "spec" | "e2e" | "cy")) {
+        return FileClass::Test;
+    }
+
+    let in_test_dir = path.parent().is_some_and(|parent| {
+        parent.components().any(|c| c.as_os_str().to_str().is_some_and(|s| TEST_DIRS.contains(&s)))
+    });
+    if in_test_dir {
+        return FileClass::Test;
+    }
+
+    let is_rc_file =
+        file_name.starts_with('.') && segments.get(1).is_some_and(|s| s.ends_with("rc"));
+    if inner.contains(&"config") || is_rc_file {
+        return FileClass::Config;
+    }
+
+    FileClass::Source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_source() {
+        assert_eq!(classify_by_name(Path::new("src/utils.ts")), FileClass::Source);
+        assert_eq!(classify_by_name(Path::new("src/index.tsx")), FileClass::Source);
+    }
+
+    #[test]
+    fn test_classify_tests() {
+        assert_eq!(classify_by_name(Path::new("src/utils.test.ts")), FileClass::Test);
+        assert_eq!(classify_by_name(Path::new("src/utils.spec.js")), FileClass::Test);
+        assert_eq!(classify_by_name(Path::new("src/__tests__/utils.ts")), FileClass::Test);
+        assert_eq!(classify_by_name(Path::new("cypress/e2e/login.cy.ts")), FileClass::Test);
+    }
+
+    #[test]
+    fn test_classify_stories() {
+        assert_eq!(classify_by_name(Path::new("src/Button.stories.tsx")), FileClass::Story);
+        assert_eq!(classify_by_name(Path::new("src/Button.story.tsx")), FileClass::Story);
+    }
+
+    #[test]
+    fn test_classify_config() {
+        assert_eq!(classify_by_name(Path::new("vite.config.ts")), FileClass::Config);
+        assert_eq!(classify_by_name(Path::new(".eslintrc.js")), FileClass::Config);
+    }
+
+    #[test]
+    fn test_classify_asset() {
+        assert_eq!(classify_by_name(Path::new("src/styles.css")), FileClass::Asset);
+        assert_eq!(classify_by_name(Path::new("src/logo.svg")), FileClass::Asset);
+    }
+
+    #[test]
+    fn test_plugin_paths_are_config() {
+        let classifier = FileClassifier::new(Path::new("/p"), [PathBuf::from("/p/setup.ts")]);
+        assert_eq!(classifier.classify(Path::new("/p/setup.ts")), FileClass::Config);
+        assert_eq!(classifier.classify(Path::new("/p/other.ts")), FileClass::Source);
+    }
+
+    #[test]
+    fn test_directories_outside_cwd_are_ignored() {
+        let classifier = FileClassifier::new(Path::new("/home/tests/app"), []);
+        assert_eq!(classifier.classify(Path::new("/home/tests/app/src/a.ts")), FileClass::Source);
+    }
+}
@@ -1,11 +1,14 @@
+use crate::classify::FileClass;
+use crate::types::DEFAULT_MAX_FILES;
 use clap::{Parser, ValueEnum};
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "muri")]
 #[command(about = "Find unused files in JS/TS projects")]
 pub struct Cli {
-    /// Path to config file (muri.json or muri.jsonc)
+    /// Path to config file (muri.json, muri.jsonc, muri.yaml or muri.yml)
     #[arg(long)]
     pub config: Option<PathBuf>,
 
@@ -25,13 +28,146 @@ pub struct Cli {
     #[arg(long, default_value = "text")]
     pub format: OutputFormat,
 
+    /// Print JSON reports on a single line instead of pretty-printing them
+    #[arg(long)]
+    pub compact: bool,
+
     /// Patterns to ignore
     #[arg(long)]
     pub ignore: Vec<String>,
 
-    /// Enable verbose output (warnings about foreign files, etc.)
+    /// Never report project files matching these patterns as unused (they are still collected)
+    #[arg(long, value_name = "GLOB")]
+    pub allow: Vec<String>,
+
+    /// Enable verbose output (warnings about foreign files, imports that could not be resolved, etc.)
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Group unused files in text output (owner: by CODEOWNERS)
+    #[arg(long, value_name = "KEY")]
+    pub group_by: Option<GroupBy>,
+
+    /// In text output, follow the unused files with a count per extension
+    #[arg(long)]
+    pub summary: bool,
+
+    /// Don't print progress to stderr
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Only report unused files of these classes (src, test, story, config, asset)
+    #[arg(long, value_delimiter = ',')]
+    pub report_classes: Vec<FileClass>,
+
+    /// Delete unused files after reporting them
+    #[arg(long)]
+    pub fix: bool,
+
+    /// With --fix, print the files that would be deleted without deleting them
+    #[arg(long, requires = "fix")]
+    pub dry_run: bool,
+
+    /// Explain why a file is reachable by printing the import chain from an entry point
+    #[arg(long, value_name = "FILE")]
+    pub why: Option<PathBuf>,
+
+    /// List the files reachable from the entry points instead of the unused ones
+    #[arg(long, conflicts_with_all = ["fix", "why", "watch", "workspaces", "baseline", "since"])]
+    pub report_used: bool,
+
+    /// Report groups of project files with identical content instead of unused files
+    #[arg(
+        long,
+        conflicts_with_all = ["fix", "why", "watch", "workspaces", "baseline", "since", "report_used"]
+    )]
+    pub duplicates: bool,
+
+    /// Compare against a saved `--format json` report and print only the changes
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["fix", "watch", "workspaces"])]
+    pub baseline: Option<PathBuf>,
+
+    /// Abort if more than this many files are found (guards against scanning huge trees)
+    #[arg(long, default_value_t = DEFAULT_MAX_FILES)]
+    pub max_files: usize,
+
+    /// Only collect files at most N directory levels below cwd (1 = cwd itself)
+    #[arg(long, value_name = "N")]
+    pub max_depth: Option<usize>,
+
+    /// Number of threads to analyze with; 1 runs sequentially [default: one per CPU]
+    #[arg(long, visible_alias = "threads", value_name = "N")]
+    pub concurrency: Option<NonZeroUsize>,
+
+    /// Only exit with an error when more than this many unused files are found
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    pub max_unused: usize,
+
+    /// Cache parsed modules in .muri-cache/ and reuse them for unchanged files
+    #[arg(long, conflicts_with = "no_cache")]
+    pub cache: bool,
+
+    /// Disable the module cache, even if enabled in the config file
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Also report exports of reachable files that are never imported
+    #[arg(long)]
+    pub unused_exports: bool,
+
+    /// Read newline-separated project files from stdin instead of globbing `--project`
+    #[arg(long, conflicts_with = "project")]
+    pub stdin: bool,
+
+    /// Only report unused files among those changed between REF and HEAD (`git diff REF...HEAD`)
+    #[arg(long, value_name = "REF", conflicts_with_all = ["stdin", "watch"])]
+    pub since: Option<String>,
+
+    /// Stay running and print an updated report whenever files change
+    #[arg(long, conflicts_with_all = ["fix", "why", "workspaces"])]
+    pub watch: bool,
+
+    /// Analyze each package from package.json `workspaces` / pnpm-workspace.yaml separately
+    #[arg(long)]
+    pub workspaces: bool,
+
+    /// Don't skip files excluded by .gitignore
+    #[arg(long)]
+    pub no_gitignore: bool,
+
+    /// Follow symlinked directories while collecting files
+    #[arg(long)]
+    pub follow_symlinks: bool,
+
+    /// Also check `.d.ts` declaration files, tracing `/// <reference path>` directives
+    #[arg(long)]
+    pub include_types: bool,
+
+    /// Use package.json `main`/`module`/`types`/`exports`/`bin` as entries when no entry matches
+    #[arg(long)]
+    pub auto_entry: bool,
+
+    /// Print the enabled plugins and what enabled each, then exit
+    #[arg(
+        long,
+        conflicts_with_all = ["fix", "why", "watch", "workspaces", "baseline", "since", "report_used", "duplicates"]
+    )]
+    pub list_plugins: bool,
+
+    /// Write a starter muri.json to the working directory and exit
+    #[arg(long)]
+    pub init: bool,
+
+    /// With --init, overwrite an existing muri.json
+    #[arg(long, requires = "init")]
+    pub force: bool,
+}
+
+/// How to group unused files in text output
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum GroupBy {
+    /// Group by owner from the CODEOWNERS file (unmatched files are "unowned")
+    Owner,
 }
 
 #[derive(Clone, ValueEnum, Default)]
@@ -39,4 +175,8 @@ pub enum OutputFormat {
     #[default]
     Text,
     Json,
+    Sarif,
+    Markdown,
+    /// Unused files only, one relative path per line, for scripts
+    Paths,
 }
@@ -0,0 +1,185 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Locations GitHub checks for a CODEOWNERS file, in priority order
+const CODEOWNERS_LOCATIONS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// A single `pattern owner...` line
+struct Rule {
+    matcher: GlobSet,
+    owners: Vec<String>,
+}
+
+/// Ownership rules parsed from a GitHub `CODEOWNERS` file
+pub struct CodeOwners {
+    rules: Vec<Rule>,
+}
+
+impl CodeOwners {
+    /// Load the CODEOWNERS file from the first location GitHub would use
+    pub fn find(cwd: &Path) -> Option<Self> {
+        CODEOWNERS_LOCATIONS
+            .iter()
+            .map(|location| cwd.join(location))
+            .find_map(|path| fs::read_to_string(path).ok())
+            .map(|content| Self::parse(&content))
+    }
+
+    /// Parse CODEOWNERS content. Comments, blank lines, and invalid patterns are skipped.
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let matcher = compile_pattern(parts.next()?)?;
+                let owners = parts.take_while(|part| !part.starts_with('#')).map(String::from);
+                Some(Rule { matcher, owners: owners.collect() })
+            })
+            .collect();
+
+        Self { rules }
+    }
+
+    /// Owners of a path relative to the repository root. The last matching rule
+    /// wins; a matching rule without owners leaves the path unowned.
+    pub fn owners(&self, relative: &Path) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matcher.is_match(relative))
+            .map_or(&[], |rule| rule.owners.as_slice())
+    }
+
+    /// Group paths by their owners, joined with a space (e.g. `@org/web @alice`).
+    /// Paths without owners are grouped under `None`.
+    pub fn group<'a>(
+        &self,
+        files: &'a [PathBuf],
+        cwd: &Path,
+    ) -> Vec<(Option<String>, Vec<&'a PathBuf>)> {
+        let mut groups: Vec<(Option<String>, Vec<&PathBuf>)> = Vec::new();
+
+        for file in files {
+            let owners = self.owners(file.strip_prefix(cwd).unwrap_or(file));
+            let key = (!owners.is_empty()).then(|| owners.join(" "));
+            match groups.iter_mut().find(|(owner, _)| *owner == key) {
+                Some((_, group)) => group.push(file),
+                None => groups.push((key, vec![file])),
+            }
+        }
+
+        // Owned groups alphabetically, unowned last
+        groups.sort_by_key(|(owner, _)| (owner.is_none(), owner.clone()));
+        groups
+    }
+}
+
+/// Compile a CODEOWNERS pattern using gitignore-style semantics:
+/// a leading `/` or an inner `/` anchors the pattern to the root, otherwise
+/// it matches at any depth; a pattern also matches everything under a
+/// matching directory, except `dir/*`, which only matches direct children;
+/// `*` does not cross directories.
+fn compile_pattern(pattern: &str) -> Option<GlobSet> {
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.starts_with('/') || trimmed.contains('/');
+    let trimmed = trimmed.trim_start_matches('/');
+
+    let base = match (trimmed, anchored) {
+        ("" | "*", _) => "**".to_string(),
+        (_, true) => trimmed.to_string(),
+        (_, false) => format!("**/{trimmed}"),
+    };
+
+    let mut builder = GlobSetBuilder::new();
+    builder.add(GlobBuilder::new(&base).literal_separator(true).build().ok()?);
+    if !base.ends_with("/*") {
+        builder.add(GlobBuilder::new(&format!("{base}/**")).literal_separator(true).build().ok()?);
+    }
+    builder.build().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const CODEOWNERS: &str = r#"
+# Default owners
+*                   @org/core
+
+*.css               @org/design
+/src/api/           @org/backend @alice
+docs/*              @org/docs
+src/api/generated/
+"#;
+
+    fn owners(codeowners: &CodeOwners, path: &str) -> Vec<String> {
+        codeowners.owners(Path::new(path)).to_vec()
+    }
+
+    #[test]
+    fn test_last_match_wins() {
+        let codeowners = CodeOwners::parse(CODEOWNERS);
+
+        assert_eq!(owners(&codeowners, "README.md"), vec!["@org/core"]);
+        assert_eq!(owners(&codeowners, "src/styles/app.css"), vec!["@org/design"]);
+        assert_eq!(owners(&codeowners, "src/api/client.ts"), vec!["@org/backend", "@alice"]);
+        assert_eq!(owners(&codeowners, "src/api/v2/users.ts"), vec!["@org/backend", "@alice"]);
+        // A later rule without owners clears ownership
+        assert!(owners(&codeowners, "src/api/generated/types.ts").is_empty());
+    }
+
+    #[test]
+    fn test_anchoring_and_single_star() {
+        let codeowners = CodeOwners::parse("docs/* @org/docs\nbuild @org/ci\n");
+
+        assert_eq!(owners(&codeowners, "docs/intro.md"), vec!["@org/docs"]);
+        // `*` does not cross directories, and inner slashes anchor to the root
+        assert!(owners(&codeowners, "docs/guides/setup.md").is_empty());
+        assert!(owners(&codeowners, "packages/docs/intro.md").is_empty());
+        // Unanchored names match at any depth, including directory contents
+        assert_eq!(owners(&codeowners, "packages/app/build/out.js"), vec!["@org/ci"]);
+    }
+
+    #[test]
+    fn test_group_by_owner() {
+        let codeowners = CodeOwners::parse("/src/ @org/web\n/src/api/ @org/backend\n");
+        let cwd = Path::new("/repo");
+        let files: Vec<PathBuf> = ["/repo/scripts/old.js", "/repo/src/api/a.ts", "/repo/src/b.ts"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+
+        let groups: Vec<(Option<String>, Vec<&str>)> = codeowners
+            .group(&files, cwd)
+            .into_iter()
+            .map(|(owner, files)| {
+                (owner, files.iter().map(|f| f.to_str().unwrap()).collect::<Vec<_>>())
+            })
+            .collect();
+
+        assert_eq!(
+            groups,
+            vec![
+                (Some("@org/backend".to_string()), vec!["/repo/src/api/a.ts"]),
+                (Some("@org/web".to_string()), vec!["/repo/src/b.ts"]),
+                (None, vec!["/repo/scripts/old.js"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_prefers_github_directory() {
+        let temp = tempdir().unwrap();
+        fs::create_dir(temp.path().join(".github")).unwrap();
+        fs::write(temp.path().join(".github/CODEOWNERS"), "* @github").unwrap();
+        fs::write(temp.path().join("CODEOWNERS"), "* @root").unwrap();
+
+        let codeowners = CodeOwners::find(temp.path()).unwrap();
+        assert_eq!(owners(&codeowners, "a.ts"), vec!["@github"]);
+        assert!(CodeOwners::find(&temp.path().join("missing")).is_none());
+    }
+}
@@ -1,10 +1,12 @@
 use crate::plugin::EntryPattern;
-use crate::types::DEFAULT_EXTENSIONS;
-use globset::{Glob, GlobSet, GlobSetBuilder};
+use crate::types::{DEFAULT_EXTENSIONS, DEFAULT_MAX_FILES, MuriError, is_cancelled};
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
 use ignore::WalkBuilder;
 use ignore::overrides::OverrideBuilder;
 use rustc_hash::FxHashSet;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 /// Result of a single filesystem walk that collects both entry and project files
 pub struct ProjectIndex {
@@ -18,12 +20,41 @@ struct CompiledPluginPattern {
     base: PathBuf,
 }
 
+/// Ordered ignore rules with gitignore-style negation: the last matching
+/// pattern wins, and a leading `!` re-includes a previously ignored path
+struct IgnoreMatcher {
+    rules: Vec<(GlobSet, bool)>,
+}
+
+impl IgnoreMatcher {
+    fn new(patterns: &[String]) -> Self {
+        let rules = patterns
+            .iter()
+            .map(|pattern| match pattern.strip_prefix('!') {
+                Some(negated) => (compile_globset(&[negated.to_string()]), true),
+                None => (compile_globset(std::slice::from_ref(pattern)), false),
+            })
+            .collect();
+        Self { rules }
+    }
+
+    fn is_ignored(&self, relative: &str) -> bool {
+        self.rules
+            .iter()
+            .rev()
+            .find(|(matcher, _)| matcher.is_match(relative))
+            .is_some_and(|(_, negated)| !negated)
+    }
+}
+
 /// Precompiled glob matchers for efficient file matching
 struct CompiledMatchers {
     entry: GlobSet,
     project: GlobSet,
-    ignore: GlobSet,
+    ignore: IgnoreMatcher,
     plugin_patterns: Vec<CompiledPluginPattern>,
+    /// Matches excluded from `plugin_patterns`
+    plugin_ignore: Vec<CompiledPluginPattern>,
 }
 
 impl CompiledMatchers {
@@ -34,80 +65,177 @@ impl CompiledMatchers {
         plugin_patterns: &[EntryPattern],
         cwd: &Path,
     ) -> Self {
-        // Compile plugin patterns, grouping by base directory
-        let mut compiled_plugins = Vec::new();
-        for pattern in plugin_patterns {
-            let base = match &pattern.base {
-                Some(b) => cwd.join(b),
-                None => cwd.to_path_buf(),
-            };
-
-            // Skip if base doesn't exist
-            let canonical_base = match base.canonicalize() {
-                Ok(p) => p,
-                Err(_) => continue,
-            };
-
-            let matcher = compile_globset(std::slice::from_ref(&pattern.pattern));
-            compiled_plugins.push(CompiledPluginPattern { matcher, base: canonical_base });
-        }
-
         Self {
             entry: compile_globset(entry_patterns),
             project: compile_globset(project_patterns),
-            ignore: compile_globset(ignore_patterns),
-            plugin_patterns: compiled_plugins,
+            ignore: IgnoreMatcher::new(ignore_patterns),
+            plugin_patterns: compile_plugin_patterns(plugin_patterns, cwd),
+            plugin_ignore: Vec::new(),
         }
     }
 }
 
-/// Check if a file has a parseable extension (JS/TS only)
-fn has_parseable_extension(path: &Path) -> bool {
+/// Compile plugin patterns against their base directories, skipping bases that don't exist
+fn compile_plugin_patterns(patterns: &[EntryPattern], cwd: &Path) -> Vec<CompiledPluginPattern> {
+    let mut compiled = Vec::new();
+    for pattern in patterns {
+        let base = match &pattern.base {
+            Some(b) => cwd.join(b),
+            None => cwd.to_path_buf(),
+        };
+
+        let canonical_base = match base.canonicalize() {
+            Ok(p) => p,
+            Err(_) => continue,
+        };
+
+        let matcher = compile_globset(std::slice::from_ref(&pattern.pattern));
+        compiled.push(CompiledPluginPattern { matcher, base: canonical_base });
+    }
+    compiled
+}
+
+/// Check if a file matches any of the compiled plugin patterns
+fn matches_plugin_pattern(compiled: &[CompiledPluginPattern], canonical_path: &Path) -> bool {
+    compiled.iter().any(|pattern| {
+        // Check if path is under this pattern's base
+        canonical_path
+            .strip_prefix(&pattern.base)
+            .is_ok_and(|relative| pattern.matcher.is_match(&*relative.to_string_lossy()))
+    })
+}
+
+/// Check if a file has a parseable extension (JS/TS, or one of `extra_extensions`)
+fn has_parseable_extension(path: &Path, extra_extensions: &[String]) -> bool {
     let ext = match path.extension().and_then(|e| e.to_str()) {
         Some(e) => format!(".{e}"),
         None => return false,
     };
 
     DEFAULT_EXTENSIONS.iter().any(|&default_ext| default_ext == ext)
+        || extra_extensions.contains(&ext)
 }
 
-/// Expand brace patterns like `**/*.{ts,tsx}` into multiple patterns
-fn expand_brace_pattern(pattern: &str) -> Vec<String> {
-    if let Some(start) = pattern.find('{') {
-        if let Some(end) = pattern[start..].find('}') {
-            let end = start + end;
-            let prefix = &pattern[..start];
-            let suffix = &pattern[end + 1..];
-            let alternatives = &pattern[start + 1..end];
+/// Check if a file is a TypeScript declaration file (`.d.ts`, `.d.mts`, `.d.cts`)
+fn is_declaration_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| [".d.ts", ".d.mts", ".d.cts"].iter().any(|ext| name.ends_with(ext)))
+}
 
-            return alternatives
-                .split(',')
-                .flat_map(|alt| {
-                    let expanded = format!("{prefix}{alt}{suffix}");
-                    expand_brace_pattern(&expanded)
-                })
-                .collect();
-        }
-    }
-    vec![pattern.to_string()]
+/// Build a glob with the matching semantics shared by every project, entry,
+/// ignore, and plugin pattern:
+///
+/// - `*` and `?` never match `/`, so `src/*.ts` only matches files directly in `src/`
+/// - `**` matches any number of directories, including none (`**/*.ts` matches `index.ts`)
+/// - `{a,b}` matches either alternative (`**/*.{ts,tsx}`)
+/// - `[abc]` and `[!abc]` match a single character from (or not in) the class
+///
+/// Patterns are matched against `/`-separated paths relative to the project root,
+/// or to the plugin pattern's base directory.
+pub(crate) fn build_glob(pattern: &str) -> Result<Glob, globset::Error> {
+    GlobBuilder::new(pattern).literal_separator(true).build()
 }
 
 /// Compile a list of glob patterns into a GlobSet for efficient matching
-fn compile_globset(patterns: &[String]) -> GlobSet {
+pub(crate) fn compile_globset(patterns: &[String]) -> GlobSet {
     let mut builder = GlobSetBuilder::new();
     for pattern in patterns {
-        for expanded in expand_brace_pattern(pattern) {
-            if let Ok(glob) = Glob::new(&expanded) {
-                builder.add(glob);
-            }
+        if let Ok(glob) = build_glob(pattern) {
+            builder.add(glob);
         }
     }
     builder.build().unwrap_or_else(|_| GlobSetBuilder::new().build().unwrap())
 }
 
+/// Check that user-supplied glob patterns compile. A leading `!` (negated
+/// ignore pattern) is allowed.
+pub fn validate_patterns(patterns: &[String]) -> Result<(), MuriError> {
+    for pattern in patterns {
+        let glob = pattern.strip_prefix('!').unwrap_or(pattern);
+        if build_glob(glob).is_err() {
+            return Err(MuriError::InvalidPattern(pattern.clone()));
+        }
+    }
+    Ok(())
+}
+
+/// Check if an entry pattern is a plain path rather than a glob
+fn is_literal_pattern(pattern: &str) -> bool {
+    !pattern.contains(['*', '?', '[', '{'])
+}
+
+/// Check if an entry pattern points outside cwd (absolute, or starting with `../`)
+fn is_external_pattern(pattern: &str) -> bool {
+    Path::new(pattern).is_absolute() || pattern == ".." || pattern.starts_with("../")
+}
+
+/// An entry pattern outside cwd, split into the static base directory to walk
+/// (`../shared`) and a glob relative to it (`**/*.config.ts`)
+struct ExternalEntry {
+    base: PathBuf,
+    matcher: GlobSet,
+    /// Directory levels to walk below `base` (None = unlimited, for `**`)
+    max_depth: Option<usize>,
+}
+
+impl ExternalEntry {
+    /// Compile a pattern resolved against `cwd`; `None` if its base doesn't exist
+    fn new(pattern: &str, cwd: &Path) -> Option<Self> {
+        let mut base = PathBuf::new();
+        let mut glob = Vec::new();
+        for component in cwd.join(pattern).components() {
+            let part = component.as_os_str().to_string_lossy();
+            match component {
+                _ if !glob.is_empty() || !is_literal_pattern(&part) => glob.push(part.to_string()),
+                Component::ParentDir => {
+                    base.pop();
+                }
+                Component::CurDir => {}
+                _ => base.push(component),
+            }
+        }
+        // A plain path is matched by its file name in the parent directory
+        if glob.is_empty() {
+            glob.push(base.file_name()?.to_string_lossy().to_string());
+            base.pop();
+        }
+
+        let max_depth = (!glob.iter().any(|part| part == "**")).then_some(glob.len());
+        Some(Self {
+            base: base.canonicalize().ok()?,
+            matcher: compile_globset(&[glob.join("/")]),
+            max_depth,
+        })
+    }
+
+    fn matches(&self, canonical_path: &Path) -> bool {
+        canonical_path
+            .strip_prefix(&self.base)
+            .is_ok_and(|relative| self.matcher.is_match(&*relative.to_string_lossy()))
+    }
+}
+
 pub struct Collector {
     cwd: PathBuf,
     matchers: CompiledMatchers,
+    max_files: usize,
+    /// Directory levels below cwd to descend (None = unlimited)
+    max_depth: Option<usize>,
+    respect_gitignore: bool,
+    follow_symlinks: bool,
+    /// Entry patterns that name a single file
+    literal_entries: Vec<PathBuf>,
+    /// Entry patterns outside cwd, walked separately from the project
+    external_entries: Vec<ExternalEntry>,
+    /// Explicit project files; when set, the filesystem walk is skipped
+    project_files: Option<Vec<PathBuf>>,
+    /// Stop token checked while walking
+    cancel: Option<Arc<AtomicBool>>,
+    /// Non-JS/TS extensions (e.g. `.vue`) collected as project files
+    extra_extensions: Vec<String>,
+    /// Collect `.d.ts` files as project files
+    include_dts: bool,
 }
 
 impl Collector {
@@ -118,25 +246,127 @@ impl Collector {
         ignore_patterns: &[String],
         plugin_patterns: &[EntryPattern],
     ) -> Self {
+        let (external, internal): (Vec<&String>, Vec<&String>) =
+            entry_patterns.iter().partition(|pattern| is_external_pattern(pattern));
+        let internal: Vec<String> = internal.into_iter().cloned().collect();
+
         Self {
             cwd: cwd.to_path_buf(),
             matchers: CompiledMatchers::new(
-                entry_patterns,
+                &internal,
                 project_patterns,
                 ignore_patterns,
                 plugin_patterns,
                 cwd,
             ),
+            max_files: DEFAULT_MAX_FILES,
+            max_depth: None,
+            respect_gitignore: true,
+            follow_symlinks: false,
+            literal_entries: entry_patterns
+                .iter()
+                .filter(|p| is_literal_pattern(p))
+                .map(|p| cwd.join(p))
+                .collect(),
+            external_entries: external
+                .into_iter()
+                .filter_map(|pattern| ExternalEntry::new(pattern, cwd))
+                .collect(),
+            project_files: None,
+            cancel: None,
+            extra_extensions: Vec::new(),
+            include_dts: false,
         }
     }
 
-    /// Collect all files in a single walk, categorizing them as entry/project files
-    pub fn collect(&self) -> ProjectIndex {
+    /// Set the maximum number of files to visit before aborting
+    pub fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Limit how deep the walk descends: `Some(1)` only visits files directly in cwd.
+    /// Deeper files are skipped, not reported as errors.
+    pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Set whether `.gitignore` files (including nested ones) exclude paths from the walk.
+    /// Explicit ignore patterns apply either way.
+    pub fn with_gitignore(mut self, respect_gitignore: bool) -> Self {
+        self.respect_gitignore = respect_gitignore;
+        self
+    }
+
+    /// Set whether symlinked directories are descended into. Symlinked files are always
+    /// collected under their canonical path; a file reachable through several links
+    /// (or a directory cycle) is only visited once.
+    pub fn with_follow_symlinks(mut self, follow_symlinks: bool) -> Self {
+        self.follow_symlinks = follow_symlinks;
+        self
+    }
+
+    /// Use an explicit list of project files (relative to cwd or absolute) instead of
+    /// walking the filesystem. Entries are the listed files matching entry or plugin
+    /// patterns, plus entry patterns that name an existing file.
+    pub fn with_project_files(mut self, files: Vec<PathBuf>) -> Self {
+        self.project_files = Some(files);
+        self
+    }
+
+    /// Also collect project files with these extensions (including the dot, e.g. `.vue`),
+    /// for file types handled by a compiler
+    pub fn with_extra_extensions(mut self, extensions: Vec<String>) -> Self {
+        self.extra_extensions = extensions;
+        self
+    }
+
+    /// Exclude files matching these patterns from plugin pattern matches. User entry
+    /// patterns and project files are unaffected.
+    pub fn with_plugin_ignore(mut self, ignore: &[EntryPattern]) -> Self {
+        self.matchers.plugin_ignore = compile_plugin_patterns(ignore, &self.cwd);
+        self
+    }
+
+    /// Set whether `.d.ts` declaration files are collected as project files. They can
+    /// still be entries either way.
+    pub fn with_include_dts(mut self, include_dts: bool) -> Self {
+        self.include_dts = include_dts;
+        self
+    }
+
+    /// Abort the walk with `MuriError::Cancelled` once `cancel` is set
+    pub fn with_cancel(mut self, cancel: Option<Arc<AtomicBool>>) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Collect all files in a single walk, categorizing them as entry/project files.
+    ///
+    /// Fails with `MuriError::TooManyFiles` once more than `max_files` files are visited,
+    /// so accidentally scanning a huge tree (e.g. `$HOME`) aborts early.
+    pub fn collect(&self) -> Result<ProjectIndex, MuriError> {
+        if let Some(files) = &self.project_files {
+            return Ok(self.collect_listed(files));
+        }
+
         let mut entry_files = FxHashSet::default();
         let mut project_files = FxHashSet::default();
+        let mut visited = 0usize;
+        // Canonical paths already seen, so a file reached through several links is handled once
+        let mut seen = FxHashSet::default();
 
         let mut walker_builder = WalkBuilder::new(&self.cwd);
-        walker_builder.hidden(false).git_ignore(true);
+        // Honor .gitignore files even when cwd is not inside a git repository
+        walker_builder
+            .hidden(false)
+            .git_ignore(self.respect_gitignore)
+            .git_exclude(self.respect_gitignore)
+            .git_global(self.respect_gitignore)
+            .follow_links(self.follow_symlinks)
+            .max_depth(self.max_depth)
+            .require_git(false);
 
         // Always exclude node_modules directories during traversal
         let mut overrides = OverrideBuilder::new(&self.cwd);
@@ -154,11 +384,23 @@ impl Collector {
                 continue;
             }
 
+            if is_cancelled(self.cancel.as_ref()) {
+                return Err(MuriError::Cancelled);
+            }
+
+            visited += 1;
+            if visited > self.max_files {
+                return Err(MuriError::TooManyFiles {
+                    limit: self.max_files,
+                    cwd: self.cwd.clone(),
+                });
+            }
+
             let relative = path.strip_prefix(&self.cwd).unwrap_or(path);
             let relative_str = relative.to_string_lossy();
 
-            // Check ignore patterns (precompiled)
-            if self.matchers.ignore.is_match(&*relative_str) {
+            // Check ignore patterns (precompiled, applies to both entry and project files)
+            if self.matchers.ignore.is_ignored(&relative_str) {
                 continue;
             }
 
@@ -167,12 +409,15 @@ impl Collector {
                 Ok(c) => c,
                 Err(_) => continue,
             };
+            if !seen.insert(canonical.clone()) {
+                continue;
+            }
 
             // Check if file matches project patterns AND has a parseable extension
             // This filters out foreign files (images, fonts, etc.) from project_files
             // while still allowing them to be resolved when imported
             let is_project =
-                self.matchers.project.is_match(&*relative_str) && has_parseable_extension(path);
+                self.matchers.project.is_match(&*relative_str) && self.is_project_file(path);
 
             // Check if file matches entry patterns
             let is_entry = self.matchers.entry.is_match(&*relative_str);
@@ -189,20 +434,470 @@ impl Collector {
             }
         }
 
+        entry_files.extend(self.collect_external_entries());
+
+        Ok(ProjectIndex { entry_files, project_files })
+    }
+
+    /// Find entry files outside cwd matching the external entry patterns. They are
+    /// entries only, never project files, but their imports are traced back into
+    /// the project.
+    fn collect_external_entries(&self) -> Vec<PathBuf> {
+        let mut found = Vec::new();
+        for entry in &self.external_entries {
+            let walker = WalkBuilder::new(&entry.base)
+                .hidden(false)
+                .git_ignore(self.respect_gitignore)
+                .require_git(false)
+                .follow_links(self.follow_symlinks)
+                .max_depth(entry.max_depth)
+                .filter_entry(|dir_entry| dir_entry.file_name() != "node_modules")
+                .build();
+            found.extend(
+                walker
+                    .flatten()
+                    .filter(|dir_entry| dir_entry.file_type().is_some_and(|t| t.is_file()))
+                    .filter_map(|dir_entry| dir_entry.path().canonicalize().ok())
+                    .filter(|path| entry.matches(path)),
+            );
+        }
+        found
+    }
+
+    /// Categorize an explicit file list without walking the filesystem
+    fn collect_listed(&self, files: &[PathBuf]) -> ProjectIndex {
+        let mut entry_files = FxHashSet::default();
+        let mut project_files = FxHashSet::default();
+
+        for file in files {
+            let path = self.cwd.join(file);
+            let Some((canonical, relative_str)) = self.canonical_if_included(&path) else {
+                continue;
+            };
+
+            if self.is_project_file(&canonical) {
+                project_files.insert(canonical.clone());
+            }
+            if self.matchers.entry.is_match(&relative_str) || self.check_plugin_patterns(&canonical)
+            {
+                entry_files.insert(canonical);
+            }
+        }
+
+        for path in &self.literal_entries {
+            if let Some((canonical, _)) = self.canonical_if_included(path) {
+                entry_files.insert(canonical);
+            }
+        }
+
         ProjectIndex { entry_files, project_files }
     }
 
-    /// Check if a file matches any plugin pattern
+    /// Check if a canonical path is matched by the entry patterns (plugin patterns aside)
+    pub fn matches_entry(&self, canonical_path: &Path) -> bool {
+        let is_internal_match = canonical_path
+            .strip_prefix(&self.cwd)
+            .is_ok_and(|relative| self.matchers.entry.is_match(relative));
+        is_internal_match || self.external_entries.iter().any(|entry| entry.matches(canonical_path))
+    }
+
+    /// Check if a file matching the project patterns is checked: parseable, and not a
+    /// declaration file unless those are included
+    fn is_project_file(&self, path: &Path) -> bool {
+        has_parseable_extension(path, &self.extra_extensions)
+            && (self.include_dts || !is_declaration_file(path))
+    }
+
+    /// Canonicalize an existing, non-ignored file, returning it with its cwd-relative path
+    fn canonical_if_included(&self, path: &Path) -> Option<(PathBuf, String)> {
+        if !path.is_file() {
+            return None;
+        }
+        let canonical = path.canonicalize().ok()?;
+        let relative = path.strip_prefix(&self.cwd).unwrap_or(path).to_string_lossy().to_string();
+        if self.matchers.ignore.is_ignored(&relative) {
+            return None;
+        }
+        Some((canonical, relative))
+    }
+
+    /// Check if a file matches any plugin pattern and none of the plugin ignore patterns
     fn check_plugin_patterns(&self, canonical_path: &Path) -> bool {
-        for compiled in &self.matchers.plugin_patterns {
-            // Check if path is under this pattern's base
-            if let Ok(relative) = canonical_path.strip_prefix(&compiled.base) {
-                let relative_str = relative.to_string_lossy();
-                if compiled.matcher.is_match(&*relative_str) {
-                    return true;
-                }
-            }
+        matches_plugin_pattern(&self.matchers.plugin_patterns, canonical_path)
+            && !matches_plugin_pattern(&self.matchers.plugin_ignore, canonical_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn collect_with_ignore(cwd: &Path, ignore: &[&str]) -> ProjectIndex {
+        let ignore: Vec<String> = ignore.iter().map(|p| p.to_string()).collect();
+        Collector::new(cwd, &["**/*.js".to_string()], &["**/*.js".to_string()], &ignore, &[])
+            .collect()
+            .unwrap()
+    }
+
+    fn collect(cwd: &Path, respect_gitignore: bool) -> ProjectIndex {
+        Collector::new(
+            cwd,
+            &["**/*.ts".to_string()],
+            &["**/*.ts".to_string()],
+            &[],
+            &[EntryPattern::new("**/*.ts")],
+        )
+        .with_gitignore(respect_gitignore)
+        .collect()
+        .unwrap()
+    }
+
+    fn setup() -> tempfile::TempDir {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join(".gitignore"), "dist/\n").unwrap();
+        fs::create_dir_all(temp.path().join("dist")).unwrap();
+        fs::create_dir_all(temp.path().join("packages/a/generated")).unwrap();
+        fs::write(temp.path().join("packages/a/.gitignore"), "generated/\n").unwrap();
+        fs::write(temp.path().join("index.ts"), "").unwrap();
+        fs::write(temp.path().join("dist/index.ts"), "").unwrap();
+        fs::write(temp.path().join("packages/a/index.ts"), "").unwrap();
+        fs::write(temp.path().join("packages/a/generated/types.ts"), "").unwrap();
+        temp
+    }
+
+    fn names(files: &FxHashSet<PathBuf>, cwd: &Path) -> Vec<String> {
+        let cwd = cwd.canonicalize().unwrap();
+        let mut names: Vec<_> = files
+            .iter()
+            .map(|f| f.strip_prefix(&cwd).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_gitignored_paths_are_excluded() {
+        let temp = setup();
+        let index = collect(temp.path(), true);
+
+        let expected = vec!["index.ts", "packages/a/index.ts"];
+        assert_eq!(names(&index.project_files, temp.path()), expected);
+        assert_eq!(names(&index.entry_files, temp.path()), expected);
+    }
+
+    #[test]
+    fn test_gitignore_can_be_disabled() {
+        let temp = setup();
+        let index = collect(temp.path(), false);
+
+        let expected = vec![
+            "dist/index.ts",
+            "index.ts",
+            "packages/a/generated/types.ts",
+            "packages/a/index.ts",
+        ];
+        assert_eq!(names(&index.project_files, temp.path()), expected);
+        assert_eq!(names(&index.entry_files, temp.path()), expected);
+    }
+
+    fn setup_dist() -> tempfile::TempDir {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("dist")).unwrap();
+        fs::write(temp.path().join("index.js"), "").unwrap();
+        fs::write(temp.path().join("dist/bundle.js"), "").unwrap();
+        fs::write(temp.path().join("dist/keep.js"), "").unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_negated_ignore_reincludes_file() {
+        let temp = setup_dist();
+        let index = collect_with_ignore(temp.path(), &["**/dist/**", "!dist/keep.js"]);
+
+        let expected = vec!["dist/keep.js", "index.js"];
+        assert_eq!(names(&index.project_files, temp.path()), expected);
+        assert_eq!(names(&index.entry_files, temp.path()), expected);
+    }
+
+    #[test]
+    fn test_ignore_order_matters() {
+        let temp = setup_dist();
+        let index = collect_with_ignore(temp.path(), &["!dist/keep.js", "**/dist/**"]);
+
+        let expected = vec!["index.js"];
+        assert_eq!(names(&index.project_files, temp.path()), expected);
+        assert_eq!(names(&index.entry_files, temp.path()), expected);
+    }
+
+    fn setup_glob_fixture() -> tempfile::TempDir {
+        let temp = tempdir().unwrap();
+        for file in [
+            "a.ts",
+            "ab.ts",
+            "b.tsx",
+            "c.js",
+            "src/d.ts",
+            "src/nested/e.tsx",
+            "src/nested/deep/f.mjs",
+        ] {
+            let path = temp.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
         }
-        false
+        temp
+    }
+
+    /// Files matched by `pattern` as a project pattern and as a plugin pattern
+    fn match_both_ways(cwd: &Path, pattern: &str) -> (Vec<String>, Vec<String>) {
+        let project = Collector::new(cwd, &[], &[pattern.to_string()], &[], &[]).collect().unwrap();
+        let plugin =
+            Collector::new(cwd, &[], &[], &[], &[EntryPattern::new(pattern)]).collect().unwrap();
+        (names(&project.project_files, cwd), names(&plugin.entry_files, cwd))
+    }
+
+    #[test]
+    fn test_glob_semantics_match_between_project_and_plugin_patterns() {
+        let temp = setup_glob_fixture();
+
+        let cases: &[(&str, &[&str])] = &[
+            // Braces, with `**` matching zero or more directories
+            (
+                "**/*.{ts,tsx,mjs}",
+                &[
+                    "a.ts",
+                    "ab.ts",
+                    "b.tsx",
+                    "src/d.ts",
+                    "src/nested/deep/f.mjs",
+                    "src/nested/e.tsx",
+                ],
+            ),
+            // `**` in the middle of a pattern
+            ("src/**/*.tsx", &["src/nested/e.tsx"]),
+            // `*` doesn't cross `/`
+            ("src/*.{ts,tsx}", &["src/d.ts"]),
+            ("*.ts", &["a.ts", "ab.ts"]),
+            // `?` matches exactly one character
+            ("?.ts", &["a.ts"]),
+            ("??.ts", &["ab.ts"]),
+        ];
+
+        for (pattern, expected) in cases {
+            let (project, plugin) = match_both_ways(temp.path(), pattern);
+            assert_eq!(project, *expected, "project pattern {pattern}");
+            assert_eq!(plugin, *expected, "plugin pattern {pattern}");
+        }
+    }
+
+    #[test]
+    fn test_plugin_pattern_with_base_uses_same_semantics() {
+        let temp = setup_glob_fixture();
+
+        let index = Collector::new(
+            temp.path(),
+            &[],
+            &[],
+            &[],
+            &[EntryPattern::with_base("*.{ts,tsx}", "src"), EntryPattern::with_base("?/*", "src")],
+        )
+        .collect()
+        .unwrap();
+
+        assert_eq!(names(&index.entry_files, temp.path()), vec!["src/d.ts"]);
+    }
+
+    #[test]
+    fn test_plugin_ignore_excludes_plugin_matches() {
+        let temp = tempdir().unwrap();
+        for file in ["cypress/support/e2e.ts", "cypress/support/index.d.ts", "types/env.d.ts"] {
+            let path = temp.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let collector = Collector::new(
+            temp.path(),
+            &["types/*.ts".to_string()],
+            &["**/*.ts".to_string()],
+            &[],
+            &[EntryPattern::with_base("**/*.ts", "cypress/support")],
+        )
+        .with_plugin_ignore(&[EntryPattern::new("**/*.d.ts")])
+        .with_include_dts(true);
+        let index = collector.collect().unwrap();
+
+        // User entry patterns and project files are unaffected by plugin ignores
+        assert_eq!(
+            names(&index.entry_files, temp.path()),
+            vec!["cypress/support/e2e.ts", "types/env.d.ts"]
+        );
+        assert_eq!(index.project_files.len(), 3);
+
+        // Listed project files go through the same check
+        let listed = collector
+            .with_project_files(vec![
+                PathBuf::from("cypress/support/e2e.ts"),
+                PathBuf::from("cypress/support/index.d.ts"),
+            ])
+            .collect()
+            .unwrap();
+        assert_eq!(names(&listed.entry_files, temp.path()), vec!["cypress/support/e2e.ts"]);
+    }
+
+    #[test]
+    fn test_entries_outside_cwd() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        for file in [
+            "app/src/index.ts",
+            "shared/setup.ts",
+            "shared/configs/vite.config.ts",
+            "shared/configs/nested/jest.config.ts",
+            "shared/node_modules/pkg/index.config.ts",
+            "shared/readme.md",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        let cwd = root.join("app");
+
+        let collector = Collector::new(
+            &cwd,
+            &[
+                "src/index.ts".to_string(),
+                "../shared/setup.ts".to_string(),
+                "../shared/**/*.config.ts".to_string(),
+                root.join("shared/*.md").to_string_lossy().to_string(),
+                "../missing/*.ts".to_string(),
+            ],
+            &["**/*.ts".to_string()],
+            &[],
+            &[],
+        );
+        let index = collector.collect().unwrap();
+
+        // External entries are traced from, but are not project files
+        assert_eq!(
+            names(&index.entry_files, &root),
+            vec![
+                "app/src/index.ts",
+                "shared/configs/nested/jest.config.ts",
+                "shared/configs/vite.config.ts",
+                "shared/readme.md",
+                "shared/setup.ts",
+            ]
+        );
+        assert_eq!(names(&index.project_files, &root), vec!["app/src/index.ts"]);
+        assert!(collector.matches_entry(&root.join("shared/setup.ts")));
+        assert!(!collector.matches_entry(&root.join("shared/configs/other.ts")));
+    }
+
+    #[test]
+    fn test_ignore_patterns_use_same_semantics() {
+        let temp = setup_glob_fixture();
+
+        let index = Collector::new(
+            temp.path(),
+            &[],
+            &["**/*.{ts,tsx,js,mjs}".to_string()],
+            &["src/*.ts".to_string(), "?.{ts,tsx}".to_string()],
+            &[],
+        )
+        .collect()
+        .unwrap();
+
+        assert_eq!(
+            names(&index.project_files, temp.path()),
+            vec!["ab.ts", "c.js", "src/nested/deep/f.mjs", "src/nested/e.tsx"]
+        );
+    }
+
+    #[test]
+    fn test_validate_patterns() {
+        let valid = ["**/*.{ts,tsx}", "src/?.ts", "!dist/keep.js", "[!_]*.ts"];
+        assert!(validate_patterns(&valid.map(String::from)).is_ok());
+
+        let invalid = vec!["src/{a,b".to_string()];
+        assert!(
+            matches!(validate_patterns(&invalid), Err(MuriError::InvalidPattern(p)) if p == "src/{a,b")
+        );
+    }
+
+    /// `app/` links to a package outside the project root, and `shared/loop` links back to
+    /// its own parent
+    #[cfg(unix)]
+    fn setup_symlinks() -> (tempfile::TempDir, PathBuf) {
+        use std::os::unix::fs::symlink;
+
+        let temp = tempdir().unwrap();
+        let root = temp.path().join("root");
+        let linked = temp.path().join("linked");
+        fs::create_dir_all(root.join("shared")).unwrap();
+        fs::create_dir_all(&linked).unwrap();
+        fs::write(root.join("index.ts"), "").unwrap();
+        fs::write(root.join("shared/util.ts"), "").unwrap();
+        fs::write(linked.join("app.ts"), "").unwrap();
+        symlink(&linked, root.join("app")).unwrap();
+        symlink(root.join("shared"), root.join("shared/loop")).unwrap();
+        (temp, root)
+    }
+
+    #[cfg(unix)]
+    fn collect_symlinks(root: &Path, follow_symlinks: bool) -> ProjectIndex {
+        Collector::new(root, &[], &["**/*.ts".to_string()], &[], &[])
+            .with_follow_symlinks(follow_symlinks)
+            .collect()
+            .unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinked_dirs_are_not_followed_by_default() {
+        let (_temp, root) = setup_symlinks();
+        let index = collect_symlinks(&root, false);
+
+        assert_eq!(names(&index.project_files, &root), vec!["index.ts", "shared/util.ts"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_follow_symlinks_collects_canonical_paths_once() {
+        let (temp, root) = setup_symlinks();
+        let index = collect_symlinks(&root, true);
+
+        // The linked package is collected under its real path, and the cycle through
+        // `shared/loop` doesn't produce duplicates
+        let linked = temp.path().join("linked/app.ts").canonicalize().unwrap();
+        assert_eq!(index.project_files.len(), 3);
+        assert!(index.project_files.contains(&linked));
+        assert!(index.project_files.contains(&root.join("shared/util.ts").canonicalize().unwrap()));
+    }
+
+    #[test]
+    fn test_explicit_project_files_skip_walk() {
+        let temp = setup_dist();
+        fs::write(temp.path().join("other.js"), "").unwrap();
+
+        let index = Collector::new(
+            temp.path(),
+            &["index.js".to_string()],
+            &["**/*.js".to_string()],
+            &["dist/bundle.js".to_string()],
+            &[],
+        )
+        .with_project_files(vec![
+            PathBuf::from("dist/keep.js"),
+            PathBuf::from("dist/bundle.js"),
+            PathBuf::from("missing.js"),
+        ])
+        .collect()
+        .unwrap();
+
+        assert_eq!(names(&index.project_files, temp.path()), vec!["dist/keep.js"]);
+        // Literal entry paths are honored even when not listed
+        assert_eq!(names(&index.entry_files, temp.path()), vec!["index.js"]);
     }
 }
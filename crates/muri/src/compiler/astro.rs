@@ -0,0 +1,92 @@
+use super::{Compiler, CompilerOutput, component_imports};
+use crate::parser::{ParseError, extract_imports_from_source};
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+
+/// Compiler to extract imports from Astro components.
+///
+/// Imports are collected from the `---` frontmatter (TypeScript), from
+/// `<script>` blocks, and from `<style>` blocks.
+pub struct AstroCompiler;
+
+impl AstroCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for AstroCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split an Astro file into its frontmatter script and the remaining template
+fn split_frontmatter(source: &str) -> (Option<&str>, &str) {
+    let trimmed = source.trim_start();
+    if let Some(rest) = trimmed.strip_prefix("---") {
+        if let Some(end) = rest.find("\n---") {
+            return (Some(&rest[..end]), &rest[end + 4..]);
+        }
+    }
+    (None, source)
+}
+
+impl Compiler for AstroCompiler {
+    fn name(&self) -> &str {
+        "astro"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".astro"]
+    }
+
+    fn should_enable(&self, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("astro")
+    }
+
+    fn compile(&self, source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        let (frontmatter, template) = split_frontmatter(source);
+
+        let mut imports = Vec::new();
+        if let Some(frontmatter) = frontmatter {
+            imports.extend(extract_imports_from_source(frontmatter, SourceType::ts())?);
+        }
+        imports.extend(component_imports(template)?);
+
+        Ok(CompilerOutput { imports })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(source: &str) -> Vec<String> {
+        let output = AstroCompiler::new().compile(source, Path::new("index.astro")).unwrap();
+        output.imports.into_iter().map(|i| i.source).collect()
+    }
+
+    #[test]
+    fn test_frontmatter_script_and_style() {
+        let source = r#"---
+import Layout from "../layouts/Layout.astro";
+const title: string = "Home";
+---
+<Layout title={title} />
+<script>
+import "./client.ts";
+</script>
+<style lang="scss">
+@import "./home.scss";
+</style>
+"#;
+        assert_eq!(sources(source), vec!["../layouts/Layout.astro", "./client.ts", "./home.scss"]);
+    }
+
+    #[test]
+    fn test_without_frontmatter() {
+        assert!(sources("<h1>Hello</h1>").is_empty());
+    }
+}
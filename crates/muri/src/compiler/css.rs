@@ -0,0 +1,157 @@
+use super::{Compiler, CompilerOutput};
+use crate::parser::{ImportInfo, ImportKind, ParseError};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Compiler to extract `@import` and `url(...)` references from plain CSS stylesheets
+pub struct CssCompiler;
+
+impl CssCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CssCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler for CssCompiler {
+    fn name(&self) -> &str {
+        "css"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".css"]
+    }
+
+    /// CSS needs no tooling to reference other files, so this is always on
+    fn should_enable(&self, _dependencies: &FxHashSet<String>) -> bool {
+        true
+    }
+
+    fn compile(&self, source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        Ok(CompilerOutput { imports: extract_css_imports(source) })
+    }
+}
+
+/// Extract local `@import` and `url(...)` targets from CSS source.
+///
+/// Comments are skipped. CSS resolves bare references relative to the
+/// stylesheet, so `url(bg.png)` becomes `./bg.png`; a leading `~` (webpack)
+/// refers to a package instead. Remote, `data:`, root-absolute, and
+/// fragment-only references are skipped, and query strings and fragments
+/// are stripped.
+pub(super) fn extract_css_imports(source: &str) -> Vec<ImportInfo> {
+    static REFERENCE: OnceLock<Regex> = OnceLock::new();
+    static COMMENT: OnceLock<Regex> = OnceLock::new();
+
+    let comment_re = COMMENT.get_or_init(|| Regex::new(r"(?s)/\*.*?\*/").expect("valid regex"));
+    let reference_re = REFERENCE.get_or_init(|| {
+        Regex::new(
+            r#"(?x)
+            @import\s+(?:"([^"]*)"|'([^']*)')             # @import "a.css"
+            | url\(\s*(?:"([^"]*)"|'([^']*)'|([^)\s]*))\s*\)  # url(a.png), url("a.png")
+            "#,
+        )
+        .expect("valid regex")
+    });
+
+    let source = comment_re.replace_all(source, "");
+
+    reference_re
+        .captures_iter(&source)
+        .filter_map(|c| c.iter().skip(1).flatten().next().map(|m| m.as_str()))
+        .filter_map(local_specifier)
+        .map(|source| ImportInfo { source, kind: ImportKind::SideEffect })
+        .collect()
+}
+
+/// Normalize a CSS reference to a resolvable specifier, or `None` if it is not a local file
+fn local_specifier(reference: &str) -> Option<String> {
+    let reference = reference.trim();
+    let path = reference.split(['?', '#']).next().unwrap_or_default();
+
+    if path.is_empty()
+        || path.starts_with('/')
+        || reference.starts_with("http://")
+        || reference.starts_with("https://")
+        || reference.starts_with("data:")
+    {
+        return None;
+    }
+
+    if let Some(package) = path.strip_prefix('~') {
+        return Some(package.to_string());
+    }
+    if path.starts_with("./") || path.starts_with("../") {
+        return Some(path.to_string());
+    }
+    Some(format!("./{path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(source: &str) -> Vec<String> {
+        extract_css_imports(source).into_iter().map(|i| i.source).collect()
+    }
+
+    #[test]
+    fn test_extract_quoted_imports() {
+        let source = r#"
+@import "./base.css";
+@import 'theme/dark.css' screen;
+"#;
+        assert_eq!(sources(source), vec!["./base.css", "./theme/dark.css"]);
+    }
+
+    #[test]
+    fn test_extract_url_imports() {
+        let source = r#"
+@import url(./reset.css);
+@import url("print.css") print;
+"#;
+        assert_eq!(sources(source), vec!["./reset.css", "./print.css"]);
+    }
+
+    #[test]
+    fn test_extract_url_references() {
+        let source = r#"
+.hero { background: url(./images/bg.png) no-repeat; }
+.logo { background-image: url( "../logo.svg" ); }
+@font-face { src: url('fonts/inter.woff2?v=3') format("woff2"), url(fonts/inter.woff#iefix); }
+"#;
+        assert_eq!(
+            sources(source),
+            vec!["./images/bg.png", "../logo.svg", "./fonts/inter.woff2", "./fonts/inter.woff"]
+        );
+    }
+
+    #[test]
+    fn test_skip_remote_data_and_absolute_urls() {
+        let source = r#"
+@import "https://fonts.googleapis.com/css?family=Roboto";
+@import url(//cdn.example.com/x.css);
+.a { background: url(data:image/png;base64,iVBORw0KGgo=); }
+.b { background: url(/static/bg.png); }
+.c { filter: url(#blur); }
+.d { background: url(./local.png); }
+"#;
+        assert_eq!(sources(source), vec!["./local.png"]);
+    }
+
+    #[test]
+    fn test_package_references_and_comments() {
+        let source = r#"
+/* @import "./commented.css"; url(./commented.png) */
+@import "~normalize.css/normalize.css";
+"#;
+        assert_eq!(sources(source), vec!["normalize.css/normalize.css"]);
+    }
+}
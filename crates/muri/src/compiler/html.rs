@@ -0,0 +1,140 @@
+use super::{Block, Compiler, CompilerOutput, extract_blocks};
+use crate::parser::{ImportInfo, ImportKind, ParseError, extract_imports_from_source};
+use oxc_span::SourceType;
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Compiler to extract the scripts and stylesheets an HTML page loads.
+///
+/// Vite and other web tooling start from `index.html`: `<script src>` and
+/// `<link href>` references are collected, along with the imports of inline
+/// `<script type="module">` blocks. Root-relative references (`/src/main.ts`)
+/// resolve against the project root.
+pub struct HtmlCompiler;
+
+impl HtmlCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for HtmlCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler for HtmlCompiler {
+    fn name(&self) -> &str {
+        "html"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".html"]
+    }
+
+    /// HTML pages need no tooling to reference other files, so this is always on
+    fn should_enable(&self, _dependencies: &FxHashSet<String>) -> bool {
+        true
+    }
+
+    fn compile(&self, source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        static LINK: OnceLock<Regex> = OnceLock::new();
+        let link_re = LINK.get_or_init(|| Regex::new(r"<link\b([^>]*)>").expect("valid regex"));
+
+        let mut imports = Vec::new();
+
+        for block in extract_blocks(source, "script") {
+            if let Some(src) = block.attr("src").and_then(local_specifier) {
+                imports.push(ImportInfo { source: src, kind: ImportKind::Static });
+            } else if block.attr("type") == Some("module") {
+                imports.extend(extract_imports_from_source(block.content, SourceType::mjs())?);
+            }
+        }
+
+        for link in link_re.captures_iter(source) {
+            let block = Block { attrs: link.get(1).map_or("", |m| m.as_str()), content: "" };
+            if let Some(href) = block.attr("href").and_then(local_specifier) {
+                imports.push(ImportInfo { source: href, kind: ImportKind::SideEffect });
+            }
+        }
+
+        Ok(CompilerOutput { imports })
+    }
+}
+
+/// Normalize a `src`/`href` to a resolvable specifier, or `None` if it is not a local file.
+///
+/// Remote (`https://`, protocol-relative `//`), `data:` and fragment-only references
+/// are skipped; query strings and fragments are stripped. Bare paths are relative
+/// to the page, so `main.js` becomes `./main.js`.
+fn local_specifier(reference: &str) -> Option<String> {
+    let reference = reference.trim();
+    let path = reference.split(['?', '#']).next().unwrap_or_default();
+
+    if path.is_empty() || path.starts_with("//") || path.contains(':') {
+        return None;
+    }
+    if path.starts_with('/') || path.starts_with("./") || path.starts_with("../") {
+        return Some(path.to_string());
+    }
+    Some(format!("./{path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn imports(source: &str) -> Vec<(String, ImportKind)> {
+        let output = HtmlCompiler::new().compile(source, Path::new("index.html")).unwrap();
+        output.imports.into_iter().map(|i| (i.source, i.kind)).collect()
+    }
+
+    #[test]
+    fn test_module_script_and_stylesheet() {
+        let source = r#"<!doctype html>
+<html>
+  <head>
+    <link rel="icon" href="/favicon.svg" />
+    <link rel="stylesheet" href="styles/main.css?v=2">
+    <link rel="preconnect" href="https://fonts.googleapis.com">
+    <script type="module" src="/src/main.ts"></script>
+    <script src="https://cdn.example.com/analytics.js"></script>
+  </head>
+  <body><div id="app"></div></body>
+</html>"#;
+        assert_eq!(
+            imports(source),
+            vec![
+                ("/src/main.ts".to_string(), ImportKind::Static),
+                ("/favicon.svg".to_string(), ImportKind::SideEffect),
+                ("./styles/main.css".to_string(), ImportKind::SideEffect),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_module_script() {
+        let source = r#"
+<script type="module">
+  import { mount } from './app.js';
+  mount(document.body);
+</script>
+<script>window.dataLayer = [];</script>
+"#;
+        assert_eq!(imports(source), vec![("./app.js".to_string(), ImportKind::Static)]);
+    }
+
+    #[test]
+    fn test_skips_non_local_references() {
+        let source = r##"
+<script src="//cdn.example.com/lib.js"></script>
+<script src="data:text/javascript,void 0"></script>
+<link rel="canonical" href="#top">
+<link rel="manifest" href="mailto:hi@example.com">
+"##;
+        assert!(imports(source).is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+use super::{Compiler, CompilerOutput};
+use crate::parser::ParseError;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+
+/// Compiler for imported JSON modules (`import config from "./config.json"`).
+///
+/// JSON can't import anything, so files are leaves: they are marked reachable
+/// when imported without being parsed as JavaScript.
+pub struct JsonCompiler;
+
+impl JsonCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for JsonCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler for JsonCompiler {
+    fn name(&self) -> &str {
+        "json"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".json"]
+    }
+
+    /// JSON imports are supported by every bundler and Node.js, so this is always on
+    fn should_enable(&self, _dependencies: &FxHashSet<String>) -> bool {
+        true
+    }
+
+    fn compile(&self, _source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        Ok(CompilerOutput::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_is_a_leaf() {
+        let source = r#"{ "import": "./other.json", "main": "./index.js" }"#;
+        let output = JsonCompiler::new().compile(source, Path::new("config.json")).unwrap();
+        assert!(output.imports.is_empty());
+    }
+}
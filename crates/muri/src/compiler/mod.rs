@@ -0,0 +1,142 @@
+mod astro;
+mod css;
+mod html;
+mod json;
+mod registry;
+mod scss;
+mod stylus;
+mod svelte;
+mod vue;
+
+pub use astro::AstroCompiler;
+pub use css::CssCompiler;
+pub use html::HtmlCompiler;
+pub use json::JsonCompiler;
+pub use registry::CompilerRegistry;
+pub use scss::ScssCompiler;
+pub use stylus::StylusCompiler;
+pub use svelte::SvelteCompiler;
+pub use vue::VueCompiler;
+
+use crate::parser::{ImportInfo, ImportKind, ParseError, extract_imports_from_source};
+use oxc_span::SourceType;
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Imports extracted from a non-JS/TS file by a compiler
+#[derive(Debug, Clone, Default)]
+pub struct CompilerOutput {
+    /// Import specifiers found in the file (scripts, styles, `src` attributes, etc.)
+    pub imports: Vec<ImportInfo>,
+}
+
+/// A compiler turns a non-JS/TS file (component, stylesheet, etc.) into a list of imports,
+/// so that files referenced only from such files can be traced.
+pub trait Compiler: Send + Sync {
+    /// Compiler identifier (e.g., "vue", "scss")
+    fn name(&self) -> &str;
+
+    /// File extensions handled by this compiler, including the dot (e.g., `[".vue"]`)
+    fn extensions(&self) -> &[&str];
+
+    /// Check if this compiler should be enabled based on project dependencies
+    fn should_enable(&self, dependencies: &FxHashSet<String>) -> bool;
+
+    /// Extract imports from the file's source text
+    fn compile(&self, source: &str, path: &Path) -> Result<CompilerOutput, ParseError>;
+}
+
+/// A `<tag ...>...</tag>` block extracted from a component file
+struct Block<'a> {
+    attrs: &'a str,
+    content: &'a str,
+}
+
+impl Block<'_> {
+    /// Get the value of an attribute (e.g. `lang="ts"`)
+    fn attr(&self, name: &str) -> Option<&str> {
+        static ATTR: OnceLock<Regex> = OnceLock::new();
+        let re = ATTR.get_or_init(|| {
+            Regex::new(r#"([\w:-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("valid regex")
+        });
+
+        re.captures_iter(self.attrs)
+            .find(|c| &c[1] == name)
+            .and_then(|c| c.get(2).or_else(|| c.get(3)).map(|m| m.as_str()))
+    }
+}
+
+/// Extract all `<script>` or `<style>` blocks from a component file
+fn extract_blocks<'a>(source: &'a str, tag: &str) -> Vec<Block<'a>> {
+    static SCRIPT: OnceLock<Regex> = OnceLock::new();
+    static STYLE: OnceLock<Regex> = OnceLock::new();
+
+    let re = match tag {
+        "script" => SCRIPT.get_or_init(|| {
+            Regex::new(r"(?s)<script\b([^>]*)>(.*?)</script>").expect("valid regex")
+        }),
+        _ => STYLE
+            .get_or_init(|| Regex::new(r"(?s)<style\b([^>]*)>(.*?)</style>").expect("valid regex")),
+    };
+
+    re.captures_iter(source)
+        .map(|c| Block {
+            attrs: c.get(1).map_or("", |m| m.as_str()),
+            content: c.get(2).map_or("", |m| m.as_str()),
+        })
+        .collect()
+}
+
+/// Extract imports from a `<script>` block, honoring its `lang` attribute and `src` reference
+fn script_block_imports(block: &Block) -> Result<Vec<ImportInfo>, ParseError> {
+    let mut imports = Vec::new();
+
+    if let Some(src) = block.attr("src") {
+        imports.push(ImportInfo { source: src.to_string(), kind: ImportKind::Static });
+    }
+
+    let source_type = match block.attr("lang") {
+        Some("ts") => SourceType::ts(),
+        Some("tsx") => SourceType::tsx(),
+        Some("jsx") => SourceType::jsx(),
+        _ => SourceType::mjs(),
+    };
+    imports.extend(extract_imports_from_source(block.content, source_type)?);
+
+    Ok(imports)
+}
+
+/// Extract imports from a `<style>` block, using its `lang` attribute to pick the dialect
+fn style_block_imports(block: &Block) -> Vec<ImportInfo> {
+    let mut imports = Vec::new();
+
+    if let Some(src) = block.attr("src") {
+        imports.push(ImportInfo { source: src.to_string(), kind: ImportKind::SideEffect });
+    }
+
+    match block.attr("lang") {
+        Some("scss" | "sass") => imports.extend(scss::extract_scss_imports(block.content)),
+        Some("stylus" | "styl") => imports.extend(stylus::extract_stylus_imports(block.content)),
+        None | Some("css") => imports.extend(css::extract_css_imports(block.content)),
+        _ => {}
+    }
+
+    imports
+}
+
+/// Extract imports from all `<script>` and `<style>` blocks of a component file
+fn component_imports(source: &str) -> Result<Vec<ImportInfo>, ParseError> {
+    let mut imports = Vec::new();
+
+    for block in extract_blocks(source, "script") {
+        imports.extend(script_block_imports(&block)?);
+    }
+
+    for block in extract_blocks(source, "style") {
+        imports.extend(style_block_imports(&block));
+    }
+
+    Ok(imports)
+}
@@ -0,0 +1,63 @@
+use super::{Compiler, CompilerOutput};
+use crate::parser::ParseError;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Registry of compilers for extracting imports from non-JS/TS files
+pub struct CompilerRegistry {
+    compilers: Vec<Arc<dyn Compiler>>,
+}
+
+impl CompilerRegistry {
+    /// Create a new empty registry
+    pub fn new() -> Self {
+        Self { compilers: Vec::new() }
+    }
+
+    /// Register a compiler
+    pub fn register(&mut self, compiler: Arc<dyn Compiler>) {
+        self.compilers.push(compiler);
+    }
+
+    /// Find the compiler handling the given file, based on its extension
+    pub fn find(&self, path: &Path) -> Option<&dyn Compiler> {
+        let ext = path.extension().and_then(|e| e.to_str())?;
+        let ext_with_dot = format!(".{ext}");
+
+        self.compilers
+            .iter()
+            .find(|c| c.extensions().contains(&ext_with_dot.as_str()))
+            .map(|c| c.as_ref())
+    }
+
+    /// Check if any registered compiler handles the given file
+    pub fn handles(&self, path: &Path) -> bool {
+        self.find(path).is_some()
+    }
+
+    /// Compile a file with its compiler, or `None` if no compiler handles it
+    pub fn compile(&self, path: &Path) -> Option<Result<CompilerOutput, ParseError>> {
+        let compiler = self.find(path)?;
+        Some(
+            std::fs::read_to_string(path)
+                .map_err(ParseError::from)
+                .and_then(|source| compiler.compile(&source, path)),
+        )
+    }
+
+    /// Get names of all registered compilers
+    pub fn compiler_names(&self) -> Vec<&str> {
+        self.compilers.iter().map(|c| c.name()).collect()
+    }
+
+    /// Get all file extensions handled by registered compilers
+    pub fn extensions(&self) -> Vec<&str> {
+        self.compilers.iter().flat_map(|c| c.extensions().iter().copied()).collect()
+    }
+}
+
+impl Default for CompilerRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,157 @@
+use super::{Compiler, CompilerOutput};
+use crate::parser::{ImportInfo, ImportKind, ParseError};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Compiler to extract `@import`, `@use` and `@forward` references from SCSS/Sass stylesheets
+pub struct ScssCompiler;
+
+impl ScssCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ScssCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler for ScssCompiler {
+    fn name(&self) -> &str {
+        "scss"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".scss", ".sass"]
+    }
+
+    fn should_enable(&self, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("sass")
+            || dependencies.contains("sass-embedded")
+            || dependencies.contains("node-sass")
+    }
+
+    fn compile(&self, source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        Ok(CompilerOutput { imports: extract_scss_imports(source) })
+    }
+}
+
+/// Extract local `@import`, `@use` and `@forward` targets from SCSS source.
+///
+/// Handles comma-separated imports (`@import "a", "b";`) and skips remote URLs
+/// and `url(...)` imports, which are not resolvable files. `@use`/`@forward`
+/// load a single stylesheet; their `as`/`with`/`show` clauses are ignored.
+/// Built-in modules (`sass:math`) and webpack-style `~package` loads are
+/// package imports and skipped.
+pub(super) fn extract_scss_imports(source: &str) -> Vec<ImportInfo> {
+    static IMPORT: OnceLock<Regex> = OnceLock::new();
+    static QUOTED: OnceLock<Regex> = OnceLock::new();
+
+    let import_re = IMPORT
+        .get_or_init(|| Regex::new(r"@(import|use|forward)\s+([^;\n]+)").expect("valid regex"));
+    let quoted_re =
+        QUOTED.get_or_init(|| Regex::new(r#""([^"]+)"|'([^']+)'"#).expect("valid regex"));
+
+    let mut imports = Vec::new();
+
+    for rule in import_re.captures_iter(source) {
+        let targets = &rule[2];
+        if targets.trim_start().starts_with("url(") {
+            continue;
+        }
+        // Only `@import` takes a list; `@use "a" with ($b: "c")` loads just `a`
+        let limit = if &rule[1] == "import" { usize::MAX } else { 1 };
+
+        for target in quoted_re.captures_iter(targets).take(limit) {
+            let specifier = target.get(1).or_else(|| target.get(2)).map_or("", |m| m.as_str());
+            if is_remote(specifier) || is_package(specifier) {
+                continue;
+            }
+            imports.push(ImportInfo { source: specifier.to_string(), kind: ImportKind::UseSass });
+        }
+    }
+
+    imports
+}
+
+/// Check if a stylesheet reference is a built-in module or a `~`-prefixed package
+fn is_package(specifier: &str) -> bool {
+    specifier.starts_with('~') || specifier.starts_with("sass:")
+}
+
+/// Check if a stylesheet reference points outside the project
+fn is_remote(specifier: &str) -> bool {
+    specifier.starts_with("http://")
+        || specifier.starts_with("https://")
+        || specifier.starts_with("//")
+        || specifier.starts_with("data:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(source: &str) -> Vec<String> {
+        extract_scss_imports(source).into_iter().map(|i| i.source).collect()
+    }
+
+    #[test]
+    fn test_extract_quoted_imports() {
+        let source = r#"
+@import "./variables";
+@import 'mixins/buttons';
+"#;
+        assert_eq!(sources(source), vec!["./variables", "mixins/buttons"]);
+    }
+
+    #[test]
+    fn test_extract_comma_separated_imports() {
+        assert_eq!(sources(r#"@import "a", "b";"#), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_skip_remote_and_url_imports() {
+        let source = r#"
+@import "https://fonts.googleapis.com/css?family=Roboto";
+@import url("./print.css");
+@import "./local";
+"#;
+        assert_eq!(sources(source), vec!["./local"]);
+    }
+
+    #[test]
+    fn test_should_enable_with_sass() {
+        let compiler = ScssCompiler::new();
+        let mut deps = FxHashSet::default();
+        assert!(!compiler.should_enable(&deps));
+
+        deps.insert("sass".to_string());
+        assert!(compiler.should_enable(&deps));
+    }
+
+    #[test]
+    fn test_extract_use_and_forward() {
+        let source = r#"
+@use "sass:math";
+@use "./variables" as vars;
+@use 'theme' with ($primary: "blue", $font: 'Inter');
+@forward "./mixins/buttons" show button-base;
+@forward "src/list" hide list-reset, $horizontal-list-gap;
+"#;
+        assert_eq!(sources(source), vec!["./variables", "theme", "./mixins/buttons", "src/list"]);
+    }
+
+    #[test]
+    fn test_skip_tilde_package_imports() {
+        let source = r#"
+@import "~bootstrap/scss/bootstrap";
+@use "~@angular/material" as mat;
+@import "./overrides", "~normalize.css/normalize";
+"#;
+        assert_eq!(sources(source), vec!["./overrides"]);
+    }
+}
@@ -0,0 +1,177 @@
+use super::{Compiler, CompilerOutput};
+use crate::parser::{ImportInfo, ImportKind, ParseError};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Compiler to extract `@import`/`@require` references from Stylus stylesheets
+pub struct StylusCompiler;
+
+impl StylusCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for StylusCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler for StylusCompiler {
+    fn name(&self) -> &str {
+        "stylus"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".styl"]
+    }
+
+    fn should_enable(&self, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("stylus")
+    }
+
+    fn compile(&self, source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        Ok(CompilerOutput { imports: extract_stylus_imports(source) })
+    }
+}
+
+/// Extract local `@import`/`@require` targets from Stylus source.
+///
+/// Targets may be quoted or bare and are resolved relative to the stylesheet.
+/// Targets without an extension get `.styl`, and glob targets
+/// (`@import 'mixins/*'`) become glob imports. Remote URLs and `url(...)`
+/// imports are skipped.
+pub(super) fn extract_stylus_imports(source: &str) -> Vec<ImportInfo> {
+    static IMPORT: OnceLock<Regex> = OnceLock::new();
+    static QUOTED: OnceLock<Regex> = OnceLock::new();
+
+    let import_re = IMPORT.get_or_init(|| {
+        Regex::new(r"(?m)^\s*@(?:import|require)\s+([^;\n]+)").expect("valid regex")
+    });
+    let quoted_re =
+        QUOTED.get_or_init(|| Regex::new(r#""([^"]+)"|'([^']+)'"#).expect("valid regex"));
+
+    let mut imports = Vec::new();
+
+    for rule in import_re.captures_iter(source) {
+        let targets = rule[1].trim();
+        if targets.starts_with("url(") {
+            continue;
+        }
+
+        let specifiers: Vec<&str> = if targets.starts_with(['"', '\'']) {
+            quoted_re
+                .captures_iter(targets)
+                .filter_map(|c| c.get(1).or_else(|| c.get(2)).map(|m| m.as_str()))
+                .collect()
+        } else {
+            targets.split(',').map(str::trim).collect()
+        };
+
+        for specifier in specifiers {
+            if specifier.is_empty()
+                || specifier.starts_with("http://")
+                || specifier.starts_with("https://")
+                || specifier.starts_with("//")
+            {
+                continue;
+            }
+            imports.push(stylus_import(specifier));
+        }
+    }
+
+    imports
+}
+
+/// Build the import for a Stylus target, adding `./` and the default `.styl` extension
+fn stylus_import(specifier: &str) -> ImportInfo {
+    let mut source = if specifier.starts_with("./") || specifier.starts_with("../") {
+        specifier.to_string()
+    } else {
+        format!("./{specifier}")
+    };
+
+    let file_name = source.rsplit('/').next().unwrap_or_default();
+    if !file_name.contains('.') {
+        source.push_str(".styl");
+    }
+
+    let kind = if source.contains(['*', '?', '[', '{']) {
+        ImportKind::DynamicGlob
+    } else {
+        ImportKind::SideEffect
+    };
+    ImportInfo { source, kind }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn imports(source: &str) -> Vec<(String, ImportKind)> {
+        extract_stylus_imports(source).into_iter().map(|i| (i.source, i.kind)).collect()
+    }
+
+    #[test]
+    fn test_quoted_and_bare_imports() {
+        let source = r#"
+@import 'variables'
+@import "./theme.styl";
+@import mixins/buttons
+@import ../shared/reset.css
+"#;
+        assert_eq!(
+            imports(source),
+            vec![
+                ("./variables.styl".to_string(), ImportKind::SideEffect),
+                ("./theme.styl".to_string(), ImportKind::SideEffect),
+                ("./mixins/buttons.styl".to_string(), ImportKind::SideEffect),
+                ("../shared/reset.css".to_string(), ImportKind::SideEffect),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_glob_imports() {
+        assert_eq!(
+            imports("@import 'mixins/*'"),
+            vec![("./mixins/*.styl".to_string(), ImportKind::DynamicGlob)]
+        );
+    }
+
+    #[test]
+    fn test_require_keyword() {
+        let source = "@require 'config'\n  @require \"base\", \"grid\"\n";
+        assert_eq!(
+            imports(source),
+            vec![
+                ("./config.styl".to_string(), ImportKind::SideEffect),
+                ("./base.styl".to_string(), ImportKind::SideEffect),
+                ("./grid.styl".to_string(), ImportKind::SideEffect),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skip_remote_and_url_imports() {
+        let source = r#"
+@import 'https://fonts.googleapis.com/css?family=Roboto'
+@import url('./print.css')
+@import 'local'
+"#;
+        assert_eq!(imports(source), vec![("./local.styl".to_string(), ImportKind::SideEffect)]);
+    }
+
+    #[test]
+    fn test_should_enable_with_stylus() {
+        let compiler = StylusCompiler::new();
+        let mut deps = FxHashSet::default();
+        assert!(!compiler.should_enable(&deps));
+
+        deps.insert("stylus".to_string());
+        assert!(compiler.should_enable(&deps));
+    }
+}
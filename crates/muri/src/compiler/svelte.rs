@@ -0,0 +1,75 @@
+use super::{Compiler, CompilerOutput, component_imports};
+use crate::parser::ParseError;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+
+/// Compiler to extract imports from Svelte components.
+///
+/// Imports are collected from instance and module `<script>` blocks and from
+/// `<style>` blocks (using the block's `lang` to pick the stylesheet dialect).
+pub struct SvelteCompiler;
+
+impl SvelteCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SvelteCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler for SvelteCompiler {
+    fn name(&self) -> &str {
+        "svelte"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".svelte"]
+    }
+
+    fn should_enable(&self, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("svelte")
+    }
+
+    fn compile(&self, source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        Ok(CompilerOutput { imports: component_imports(source)? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(source: &str) -> Vec<String> {
+        let output = SvelteCompiler::new().compile(source, Path::new("App.svelte")).unwrap();
+        output.imports.into_iter().map(|i| i.source).collect()
+    }
+
+    #[test]
+    fn test_module_and_instance_scripts() {
+        let source = r#"
+<script context="module">
+import { preload } from "./preload.js";
+</script>
+<script lang="ts">
+import Button from "./Button.svelte";
+</script>
+<Button />
+"#;
+        assert_eq!(sources(source), vec!["./preload.js", "./Button.svelte"]);
+    }
+
+    #[test]
+    fn test_scss_style_block() {
+        let source = r#"
+<div />
+<style lang="scss">
+@import "./theme.scss";
+</style>
+"#;
+        assert_eq!(sources(source), vec!["./theme.scss"]);
+    }
+}
@@ -0,0 +1,103 @@
+use super::{Compiler, CompilerOutput, component_imports};
+use crate::parser::ParseError;
+use rustc_hash::FxHashSet;
+use std::path::Path;
+
+/// Compiler to extract imports from Vue single-file components.
+///
+/// Imports are collected from `<script>`/`<script setup>` blocks and from
+/// `<style>` blocks (using the block's `lang` to pick the stylesheet dialect).
+pub struct VueCompiler;
+
+impl VueCompiler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for VueCompiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Compiler for VueCompiler {
+    fn name(&self) -> &str {
+        "vue"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[".vue"]
+    }
+
+    fn should_enable(&self, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("vue") || dependencies.contains("nuxt")
+    }
+
+    fn compile(&self, source: &str, _path: &Path) -> Result<CompilerOutput, ParseError> {
+        Ok(CompilerOutput { imports: component_imports(source)? })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(source: &str) -> Vec<String> {
+        let output = VueCompiler::new().compile(source, Path::new("App.vue")).unwrap();
+        output.imports.into_iter().map(|i| i.source).collect()
+    }
+
+    #[test]
+    fn test_script_and_script_setup() {
+        let source = r#"
+<script lang="ts">
+import { helper } from "./helper";
+</script>
+<script setup lang="ts">
+import Child from "./Child.vue";
+</script>
+<template><Child /></template>
+"#;
+        assert_eq!(sources(source), vec!["./helper", "./Child.vue"]);
+    }
+
+    #[test]
+    fn test_scss_style_block() {
+        let source = r#"
+<template><div /></template>
+<style lang="scss" scoped>
+@import "./styles/theme.scss";
+</style>
+"#;
+        assert_eq!(sources(source), vec!["./styles/theme.scss"]);
+    }
+
+    #[test]
+    fn test_css_style_block() {
+        let source = r#"
+<template><div /></template>
+<style scoped>
+@import "./base.css";
+.hero { background: url(./hero.png); }
+</style>
+"#;
+        assert_eq!(sources(source), vec!["./base.css", "./hero.png"]);
+    }
+
+    #[test]
+    fn test_src_attributes() {
+        let source = r#"
+<script src="./logic.ts"></script>
+<style src="./styles.css"></style>
+"#;
+        assert_eq!(sources(source), vec!["./logic.ts", "./styles.css"]);
+    }
+
+    #[test]
+    fn test_should_enable_with_vue() {
+        let mut deps = FxHashSet::default();
+        deps.insert("vue".to_string());
+        assert!(VueCompiler::new().should_enable(&deps));
+    }
+}
@@ -1,34 +1,101 @@
+use crate::workspace::workspace_patterns;
 use rustc_hash::FxHashSet;
 use std::path::Path;
 
-/// Detect dependencies from package.json in the given directory
-pub fn detect_dependencies(cwd: &Path) -> FxHashSet<String> {
-    let pkg_path = cwd.join("package.json");
-    if !pkg_path.exists() {
-        return FxHashSet::default();
+/// Dependency names from package.json, by the field that declares them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyInfo {
+    /// `dependencies`
+    pub deps: FxHashSet<String>,
+    /// `devDependencies`
+    pub dev_deps: FxHashSet<String>,
+    /// `peerDependencies`
+    pub peer_deps: FxHashSet<String>,
+    /// `optionalDependencies`
+    pub optional_deps: FxHashSet<String>,
+}
+
+impl DependencyInfo {
+    /// Every dependency name, whatever field declares it
+    pub fn all(&self) -> FxHashSet<String> {
+        self.fields().flat_map(|field| field.iter().cloned()).collect()
     }
 
-    let content = match std::fs::read_to_string(&pkg_path) {
-        Ok(c) => c,
-        Err(_) => return FxHashSet::default(),
-    };
+    /// Whether any field declares `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.fields().any(|field| field.contains(name))
+    }
 
-    let pkg: serde_json::Value = match serde_json::from_str(&content) {
-        Ok(v) => v,
-        Err(_) => return FxHashSet::default(),
-    };
+    /// Whether installing the package installs `name`: it is a runtime, dev or
+    /// optional dependency. Peer dependencies are left to the consumer.
+    pub fn is_installed(&self, name: &str) -> bool {
+        self.deps.contains(name)
+            || self.dev_deps.contains(name)
+            || self.optional_deps.contains(name)
+    }
 
-    let mut deps = FxHashSet::default();
+    /// Add every dependency of `other`, keeping its field
+    pub fn extend(&mut self, other: &DependencyInfo) {
+        self.deps.extend(other.deps.iter().cloned());
+        self.dev_deps.extend(other.dev_deps.iter().cloned());
+        self.peer_deps.extend(other.peer_deps.iter().cloned());
+        self.optional_deps.extend(other.optional_deps.iter().cloned());
+    }
 
-    for field in ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"] {
-        if let Some(obj) = pkg.get(field).and_then(|v| v.as_object()) {
-            for key in obj.keys() {
-                deps.insert(key.clone());
+    fn fields(&self) -> impl Iterator<Item = &FxHashSet<String>> {
+        [&self.deps, &self.dev_deps, &self.peer_deps, &self.optional_deps].into_iter()
+    }
+}
+
+/// Detect dependencies from package.json in the given directory, keeping the
+/// field each is declared in.
+///
+/// Inside a workspace, the package.json files of every ancestor up to the
+/// workspace root are merged in too: dependencies declared at the root (often
+/// the shared dev tooling) are installed for every package. Outside a
+/// workspace only `cwd` itself is read.
+///
+/// Only dependency names are collected, so any version-spec form works
+/// (semver ranges, `workspace:*`, `catalog:`, `link:`, git URLs, etc.).
+pub fn detect_dependency_map(cwd: &Path) -> DependencyInfo {
+    let cwd = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
+    let mut info = read_dependency_map(&cwd).unwrap_or_default();
+
+    let ancestors: Vec<&Path> = cwd.ancestors().skip(1).collect();
+    if let Some(root) = ancestors.iter().position(|dir| !workspace_patterns(dir).is_empty()) {
+        for dir in &ancestors[..=root] {
+            if let Some(ancestor) = read_dependency_map(dir) {
+                info.extend(&ancestor);
             }
         }
     }
+    info
+}
+
+/// Dependencies declared in `dir/package.json`, if it exists and parses
+fn read_dependency_map(dir: &Path) -> Option<DependencyInfo> {
+    let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    let pkg: serde_json::Value = serde_json::from_str(&content).ok()?;
 
-    deps
+    let names = |field: &str| -> FxHashSet<String> {
+        pkg.get(field)
+            .and_then(|v| v.as_object())
+            .map(|obj| obj.keys().cloned().collect())
+            .unwrap_or_default()
+    };
+
+    Some(DependencyInfo {
+        deps: names("dependencies"),
+        dev_deps: names("devDependencies"),
+        peer_deps: names("peerDependencies"),
+        optional_deps: names("optionalDependencies"),
+    })
+}
+
+/// Detect dependencies from package.json in the given directory, from all
+/// dependency fields.
+pub fn detect_dependencies(cwd: &Path) -> FxHashSet<String> {
+    detect_dependency_map(cwd).all()
 }
 
 #[cfg(test)]
@@ -79,4 +146,106 @@ mod tests {
         let deps = detect_dependencies(dir.path());
         assert!(deps.is_empty());
     }
+
+    #[test]
+    fn test_detect_dependencies_with_protocol_versions() {
+        let dir = tempdir().unwrap();
+        let pkg_json = r#"{
+            "dependencies": {
+                "@acme/ui": "workspace:*",
+                "react": "catalog:",
+                "vite": "catalog:build"
+            },
+            "devDependencies": {
+                "local-tool": "link:../local-tool",
+                "forked-lib": "git+https://github.com/acme/forked-lib.git#main"
+            },
+            "peerDependencies": {
+                "@acme/core": "workspace:^"
+            },
+            "optionalDependencies": {
+                "fsevents": "npm:fsevents@^2.3.0"
+            }
+        }"#;
+        fs::write(dir.path().join("package.json"), pkg_json).unwrap();
+
+        let deps = detect_dependencies(dir.path());
+
+        assert!(deps.contains("@acme/ui"));
+        assert!(deps.contains("react"));
+        assert!(deps.contains("vite"));
+        assert!(deps.contains("local-tool"));
+        assert!(deps.contains("forked-lib"));
+        assert!(deps.contains("@acme/core"));
+        assert!(deps.contains("fsevents"));
+        assert_eq!(deps.len(), 7);
+    }
+
+    #[test]
+    fn test_detect_dependency_map_keeps_fields() {
+        let dir = tempdir().unwrap();
+        let pkg_json = r#"{
+            "dependencies": { "react": "^18" },
+            "devDependencies": { "typescript": "^5", "jest": "^29" },
+            "peerDependencies": { "react-dom": "^18" },
+            "optionalDependencies": { "fsevents": "^2" }
+        }"#;
+        fs::write(dir.path().join("package.json"), pkg_json).unwrap();
+
+        let info = detect_dependency_map(dir.path());
+        assert!(info.deps.contains("react"));
+        assert!(info.dev_deps.contains("typescript"));
+        assert!(!info.deps.contains("typescript"));
+        assert!(info.peer_deps.contains("react-dom"));
+        assert!(info.optional_deps.contains("fsevents"));
+
+        assert!(info.is_installed("jest"));
+        assert!(info.is_installed("fsevents"));
+        assert!(!info.is_installed("react-dom"));
+        assert!(info.contains("react-dom"));
+        assert_eq!(info.all(), detect_dependencies(dir.path()));
+    }
+
+    #[test]
+    fn test_workspace_root_dependencies_are_merged() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("packages/app/src")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "workspaces": ["packages/*"], "devDependencies": { "jest": "^29" } }"#,
+        )
+        .unwrap();
+        fs::write(root.join("packages/package.json"), r#"{ "dependencies": { "tslib": "^2" } }"#)
+            .unwrap();
+        fs::write(
+            root.join("packages/app/package.json"),
+            r#"{ "dependencies": { "react": "catalog:" } }"#,
+        )
+        .unwrap();
+
+        let info = detect_dependency_map(&root.join("packages/app"));
+        assert!(info.deps.contains("react"));
+        assert!(info.deps.contains("tslib"));
+        assert!(info.dev_deps.contains("jest"));
+
+        // Without a package.json of its own, a directory inside the workspace
+        // still sees the root's dependencies
+        assert!(detect_dependencies(&root.join("packages/app/src")).contains("jest"));
+    }
+
+    #[test]
+    fn test_ancestors_outside_a_workspace_are_ignored() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("app")).unwrap();
+        fs::write(root.join("package.json"), r#"{ "devDependencies": { "jest": "^29" } }"#)
+            .unwrap();
+        fs::write(root.join("app/package.json"), r#"{ "dependencies": { "react": "^18" } }"#)
+            .unwrap();
+
+        let deps = detect_dependencies(&root.join("app"));
+        assert!(deps.contains("react"));
+        assert!(!deps.contains("jest"));
+    }
 }
@@ -0,0 +1,75 @@
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+use std::path::PathBuf;
+use xxhash_rust::xxh3::xxh3_128;
+
+/// Group files with byte-identical content.
+///
+/// Files are keyed by size and an xxh3 hash of their content; empty and
+/// unreadable files are skipped. Only groups of two or more files are returned,
+/// each sorted, with the groups ordered by their first file.
+pub fn duplicate_groups(files: &[PathBuf]) -> Vec<Vec<PathBuf>> {
+    let hashed: Vec<((usize, u128), &PathBuf)> = files
+        .par_iter()
+        .filter_map(|file| {
+            let content = std::fs::read(file).ok()?;
+            (!content.is_empty()).then(|| ((content.len(), xxh3_128(&content)), file))
+        })
+        .collect();
+
+    let mut by_content: FxHashMap<(usize, u128), Vec<PathBuf>> = FxHashMap::default();
+    for (key, file) in hashed {
+        by_content.entry(key).or_default().push(file.clone());
+    }
+
+    let mut groups: Vec<Vec<PathBuf>> =
+        by_content.into_values().filter(|group| group.len() > 1).collect();
+    groups.iter_mut().for_each(|group| group.sort());
+    groups.sort();
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_identical_files_are_grouped() {
+        let temp = tempdir().unwrap();
+        let files: Vec<PathBuf> = [
+            ("b/format.ts", "export const format = 1;"),
+            ("a/format.ts", "export const format = 1;"),
+            ("c/format.ts", "export const format = 2;"),
+            ("d/math.ts", "export const add = 1;"),
+            ("e/math.ts", "export const add = 1;"),
+            ("empty.ts", ""),
+            ("also-empty.ts", ""),
+        ]
+        .into_iter()
+        .map(|(name, content)| {
+            let path = temp.path().join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, content).unwrap();
+            path
+        })
+        .collect();
+
+        let root = temp.path();
+        assert_eq!(
+            duplicate_groups(&files),
+            vec![
+                vec![root.join("a/format.ts"), root.join("b/format.ts")],
+                vec![root.join("d/math.ts"), root.join("e/math.ts")],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_files_are_skipped() {
+        let temp = tempdir().unwrap();
+        let files = vec![temp.path().join("missing.ts"), temp.path().join("missing.ts")];
+        assert!(duplicate_groups(&files).is_empty());
+    }
+}
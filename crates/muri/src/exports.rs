@@ -0,0 +1,93 @@
+use crate::module_cache::ModuleCache;
+use crate::parser::{ImportKind, ModuleSymbols, UsedNames, extract_symbols};
+use crate::resolver::ModuleResolver;
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A named export that no reachable module imports
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct UnusedExport {
+    pub file: PathBuf,
+    pub name: String,
+}
+
+/// Find exports of reachable project files that are never imported by another reachable file.
+///
+/// Exports of entry files are treated as used (they are the project's public surface).
+/// Namespace imports, dynamic imports, `require`, `export *`, and imports from
+/// compiled files (e.g. `.vue`) mark every export of the target as used.
+pub fn find_unused_exports(
+    reachable: &FxHashSet<PathBuf>,
+    entries: &[PathBuf],
+    project_files: &FxHashSet<PathBuf>,
+    resolver: &ModuleResolver,
+    module_cache: &ModuleCache,
+) -> Vec<UnusedExport> {
+    let (compiled, modules): (Vec<&PathBuf>, Vec<&PathBuf>) = reachable
+        .iter()
+        .filter(|path| project_files.contains(*path) || module_cache.has_compiler(path))
+        .partition(|path| module_cache.has_compiler(path));
+
+    let symbols: Vec<(&PathBuf, Option<ModuleSymbols>)> =
+        modules.par_iter().map(|path| (*path, extract_symbols(path).ok())).collect();
+
+    let mut used: FxHashMap<PathBuf, UsedNames> = FxHashMap::default();
+    let mut mark = |from: &Path, source: &str, kind: &ImportKind, names: UsedNames| {
+        for target in resolver.resolve_import(from, source, kind) {
+            match used.get_mut(&target) {
+                Some(existing) => existing.merge(names.clone()),
+                None => {
+                    used.insert(target, names.clone());
+                }
+            }
+        }
+    };
+
+    for (path, module) in &symbols {
+        match module {
+            Some(module) => {
+                for symbol_use in &module.uses {
+                    mark(path, &symbol_use.source, &symbol_use.kind, symbol_use.names.clone());
+                }
+            }
+            // Unparseable files: assume they use everything they import
+            None => {
+                for import in &module_cache.get_or_parse(path).imports {
+                    mark(path, &import.source, &import.kind, UsedNames::All);
+                }
+            }
+        }
+    }
+
+    // Compilers only report specifiers, not names
+    for path in compiled {
+        for import in &module_cache.get_or_parse(path).imports {
+            mark(path, &import.source, &import.kind, UsedNames::All);
+        }
+    }
+
+    let entries: FxHashSet<&PathBuf> = entries.iter().collect();
+    let mut unused: Vec<UnusedExport> = symbols
+        .iter()
+        .filter(|(path, _)| !entries.contains(path))
+        .filter_map(|(path, module)| module.as_ref().map(|m| (*path, m)))
+        .flat_map(|(path, module)| {
+            let used = used.get(path);
+            module
+                .exports
+                .iter()
+                .filter(move |name| match used {
+                    Some(UsedNames::All) => false,
+                    Some(UsedNames::Names(names)) => !names.contains(name),
+                    None => true,
+                })
+                .map(move |name| UnusedExport { file: path.clone(), name: name.clone() })
+        })
+        .collect();
+
+    unused.sort();
+    unused.dedup();
+    unused
+}
@@ -1,11 +1,18 @@
 use crate::module_cache::ModuleCache;
+use crate::parser::ImportKind;
 use crate::resolver::ModuleResolver;
-use crate::types::FOREIGN_FILE_EXTENSIONS;
-use dashmap::DashSet;
+use crate::types::{
+    FOREIGN_FILE_EXTENSIONS, ProgressEvent, ProgressHandler, REACHABLE_ASSET_EXTENSIONS,
+    is_cancelled,
+};
+use dashmap::{DashMap, DashSet};
 use rayon::prelude::*;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
 
 /// Check if a file has a foreign file extension (CSS, images, etc.)
 fn is_foreign_file(path: &Path) -> bool {
@@ -15,11 +22,40 @@ fn is_foreign_file(path: &Path) -> bool {
     })
 }
 
+/// Check if a file is an asset that becomes reachable when imported (e.g. SVG components)
+fn is_reachable_asset(path: &Path) -> bool {
+    path.extension().and_then(|e| e.to_str()).is_some_and(|ext| {
+        let ext_with_dot = format!(".{ext}");
+        REACHABLE_ASSET_EXTENSIONS.contains(&ext_with_dot.as_str())
+    })
+}
+
+/// An import specifier that could not be resolved to a file
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct UnresolvedImport {
+    /// File containing the import
+    pub importer: PathBuf,
+    /// The specifier as written (e.g. `@/utils/format`)
+    pub specifier: String,
+}
+
 pub struct DependencyGraph {
     project_files: FxHashSet<PathBuf>,
     resolver: Arc<ModuleResolver>,
     module_cache: Arc<ModuleCache>,
     verbose: bool,
+    /// SVGs are imported as React components (SVGR), so don't warn about them
+    svgr: bool,
+    /// Adjacency: traversable files imported by each visited file
+    edges: DashMap<PathBuf, Vec<PathBuf>>,
+    /// Foreign files already warned about (verbose mode)
+    warned_foreign: DashSet<PathBuf>,
+    /// Imports of visited files that failed to resolve
+    unresolved: DashSet<UnresolvedImport>,
+    /// Receives `Parsed` events after each traversal frontier
+    progress: Option<ProgressHandler>,
+    /// Stop token checked during traversal
+    cancel: Option<Arc<AtomicBool>>,
 }
 
 impl DependencyGraph {
@@ -29,60 +65,356 @@ impl DependencyGraph {
         module_cache: Arc<ModuleCache>,
         verbose: bool,
     ) -> Self {
-        Self { project_files, resolver, module_cache, verbose }
+        Self {
+            project_files,
+            resolver,
+            module_cache,
+            verbose,
+            svgr: false,
+            edges: DashMap::new(),
+            warned_foreign: DashSet::new(),
+            unresolved: DashSet::new(),
+            progress: None,
+            cancel: None,
+        }
     }
 
-    pub fn find_reachable(&self, entry_points: &[PathBuf]) -> FxHashSet<PathBuf> {
-        let reachable: DashSet<PathBuf> = DashSet::new();
-        let queue: DashSet<PathBuf> = DashSet::new();
-        let warned_foreign: DashSet<PathBuf> = DashSet::new();
+    /// Project files known to the graph
+    pub fn project_files(&self) -> &FxHashSet<PathBuf> {
+        &self.project_files
+    }
+
+    /// Consume the graph, keeping only its project files
+    pub fn into_project_files(self) -> FxHashSet<PathBuf> {
+        self.project_files
+    }
+
+    /// Treat imported SVGs as components rather than foreign files
+    pub fn with_svgr(mut self, svgr: bool) -> Self {
+        self.svgr = svgr;
+        self
+    }
+
+    /// Report traversal progress to `progress`
+    pub fn with_progress(mut self, progress: Option<ProgressHandler>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Stop traversal early once `cancel` is set (see [`Self::is_cancelled`])
+    pub fn with_cancel(mut self, cancel: Option<Arc<AtomicBool>>) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Whether the stop token has been set. Results computed after cancellation
+    /// are incomplete and must be discarded.
+    pub fn is_cancelled(&self) -> bool {
+        is_cancelled(self.cancel.as_ref())
+    }
 
-        // Seed with entry points
-        for entry in entry_points {
-            queue.insert(entry.clone());
+    /// Resolve a file's imports to the files the graph traverses: project files,
+    /// compiled files, and reachable assets. Results are cached as the graph's adjacency.
+    fn dependencies(&self, file: &Path) -> Vec<PathBuf> {
+        if let Some(deps) = self.edges.get(file) {
+            return deps.clone();
         }
 
-        // Process in waves for parallelism
-        while !queue.is_empty() {
-            let current_wave: Vec<_> = queue.iter().map(|r| r.clone()).collect();
-            queue.clear();
+        let mut deps = Vec::new();
 
-            current_wave.par_iter().for_each(|file| {
-                if !reachable.insert(file.clone()) {
-                    return; // Already processed
+        // Use cached module info instead of re-parsing
+        let module_info = self.module_cache.get_or_parse(file);
+        let mut resolved_imports = Vec::new();
+        for import in &module_info.imports {
+            let resolved = self.resolver.resolve_import(file, &import.source, &import.kind);
+            // A glob matching no file is not a resolution failure
+            if resolved.is_empty()
+                && import.kind != ImportKind::DynamicGlob
+                && self.resolver.is_unresolved(file, &import.source, &import.kind)
+            {
+                self.unresolved.insert(UnresolvedImport {
+                    importer: file.to_path_buf(),
+                    specifier: import.source.clone(),
+                });
+            }
+            resolved_imports.extend(resolved);
+        }
+        for resolved in resolved_imports {
+            if self.project_files.contains(&resolved) || self.module_cache.has_compiler(&resolved) {
+                deps.push(resolved);
+            } else if is_reachable_asset(&resolved) {
+                // Leaf asset: reachable but never parsed
+                if self.verbose && !self.svgr {
+                    self.warn_foreign(&resolved);
                 }
+                deps.push(resolved);
+            } else if self.verbose && is_foreign_file(&resolved) {
+                self.warn_foreign(&resolved);
+            }
+        }
+
+        self.edges.insert(file.to_path_buf(), deps.clone());
+        deps
+    }
 
-                // Use cached module info instead of re-parsing
-                let module_info = self.module_cache.get_or_parse(file);
-                for import in &module_info.imports {
-                    if let Some(resolved) = self.resolver.resolve(file, &import.source) {
-                        if self.project_files.contains(&resolved) {
-                            if !reachable.contains(&resolved) {
-                                queue.insert(resolved);
-                            }
-                        } else if self.verbose
-                            && is_foreign_file(&resolved)
-                            && warned_foreign.insert(resolved.clone())
-                        {
-                            eprintln!(
-                                "Warning: Foreign file '{}' will not be analyzed",
-                                resolved.display()
-                            );
-                        }
-                    }
+    /// Resolve the imports of every project file, so `edges` also covers files
+    /// that are not reachable from an entry point
+    pub fn resolve_all(&self) {
+        self.project_files.par_iter().for_each(|file| {
+            if !self.is_cancelled() {
+                self.dependencies(file);
+            }
+        });
+    }
+
+    /// Snapshot of the resolved adjacency: each visited file mapped to the files it
+    /// imports, in import order. Only files traversed so far (by `find_reachable`,
+    /// `trace_path`, or `resolve_all`) are included.
+    pub fn edges(&self) -> BTreeMap<PathBuf, Vec<PathBuf>> {
+        self.edges.iter().map(|entry| (entry.key().clone(), entry.value().clone())).collect()
+    }
+
+    /// Imports that failed to resolve, sorted. Only files traversed so far are
+    /// included, as with [`Self::edges`].
+    pub fn unresolved_imports(&self) -> Vec<UnresolvedImport> {
+        let mut unresolved: Vec<_> = self.unresolved.iter().map(|entry| entry.clone()).collect();
+        unresolved.sort();
+        unresolved
+    }
+
+    /// Print a warning about an unanalyzed foreign file, once per file
+    fn warn_foreign(&self, path: &Path) {
+        if self.warned_foreign.insert(path.to_path_buf()) {
+            eprintln!("Warning: Foreign file '{}' will not be analyzed", path.display());
+        }
+    }
+
+    /// Find all files reachable from the entry points.
+    ///
+    /// Traversal is breadth-first by frontier: every newly discovered file in a
+    /// frontier is parsed and resolved in parallel through the shared
+    /// `ModuleCache`, then the results are merged on the calling thread before
+    /// the next frontier starts. Traversal stops early (with a partial set)
+    /// when the stop token is set. Parallelism uses the current rayon pool: the
+    /// dedicated pool sized by `MuriConfig::concurrency` when set, otherwise the
+    /// global pool, which defaults to one thread per CPU and honors `RAYON_NUM_THREADS`.
+    ///
+    /// After each frontier, a `Parsed` progress event reports the number of
+    /// files traversed so far against the number of project files.
+    pub fn find_reachable(&self, entry_points: &[PathBuf]) -> FxHashSet<PathBuf> {
+        let mut reachable: FxHashSet<PathBuf> = FxHashSet::default();
+        let mut frontier: Vec<PathBuf> = entry_points
+            .iter()
+            .filter(|entry| reachable.insert((*entry).clone()))
+            .cloned()
+            .collect();
+        let mut parsed = 0;
+
+        while !frontier.is_empty() {
+            // Skip remaining work in this frontier once cancelled
+            let discovered: Vec<Vec<PathBuf>> = frontier
+                .par_iter()
+                .map(|file| if self.is_cancelled() { Vec::new() } else { self.dependencies(file) })
+                .collect();
+            if self.is_cancelled() {
+                break;
+            }
+
+            parsed += frontier.len();
+            if let Some(progress) = &self.progress {
+                // Entries and compiled files may lie outside the project set
+                let total = self.project_files.len().max(parsed);
+                progress.emit(ProgressEvent::Parsed(parsed, total));
+            }
+
+            frontier = Vec::new();
+            for dep in discovered.into_iter().flatten() {
+                // Assets are leaves: reachable but never parsed
+                if reachable.insert(dep.clone()) && !is_reachable_asset(&dep) {
+                    frontier.push(dep);
                 }
-            });
+            }
         }
 
-        reachable.into_iter().collect()
+        reachable
     }
 
     pub fn find_unused(&self, entry_points: &[PathBuf]) -> Vec<PathBuf> {
-        let reachable = self.find_reachable(entry_points);
+        self.unused_files(&self.find_reachable(entry_points))
+    }
 
-        let mut unused: Vec<_> = self.project_files.difference(&reachable).cloned().collect();
+    /// Project files not in an already computed reachable set, sorted
+    pub fn unused_files(&self, reachable: &FxHashSet<PathBuf>) -> Vec<PathBuf> {
+        let mut unused: Vec<_> = self.project_files.difference(reachable).cloned().collect();
 
         unused.sort();
         unused
     }
+
+    /// Find the shortest import chain from any entry point to `target`.
+    ///
+    /// Returns the chain starting with the entry and ending with the target,
+    /// or `None` if the target is not reachable.
+    pub fn trace_path(&self, target: &Path, entries: &[PathBuf]) -> Option<Vec<PathBuf>> {
+        let mut parents: FxHashMap<PathBuf, Option<PathBuf>> = FxHashMap::default();
+        let mut queue = VecDeque::new();
+
+        for entry in entries {
+            if !parents.contains_key(entry) {
+                parents.insert(entry.clone(), None);
+                queue.push_back(entry.clone());
+            }
+        }
+
+        while let Some(file) = queue.pop_front() {
+            if file == target {
+                let mut chain = vec![file];
+                while let Some(Some(parent)) = parents.get(chain.last()?) {
+                    chain.push(parent.clone());
+                }
+                chain.reverse();
+                return Some(chain);
+            }
+
+            // Assets are leaves and are never parsed
+            if is_reachable_asset(&file) {
+                continue;
+            }
+
+            for dep in self.dependencies(&file) {
+                if !parents.contains_key(&dep) {
+                    parents.insert(dep.clone(), Some(file.clone()));
+                    queue.push_back(dep);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_trace_path_returns_shortest_chain() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("a.ts"), "import './b';\nimport './d';").unwrap();
+        fs::write(cwd.join("b.ts"), "import './c';").unwrap();
+        fs::write(cwd.join("c.ts"), "export {};").unwrap();
+        fs::write(cwd.join("d.ts"), "import './b';").unwrap();
+        fs::write(cwd.join("orphan.ts"), "import './c';").unwrap();
+
+        let project_files: FxHashSet<PathBuf> =
+            ["a.ts", "b.ts", "c.ts", "d.ts", "orphan.ts"].iter().map(|f| cwd.join(f)).collect();
+        let graph = DependencyGraph::new(
+            project_files,
+            Arc::new(ModuleResolver::new(&cwd)),
+            Arc::new(ModuleCache::new()),
+            false,
+        );
+        let entries = vec![cwd.join("a.ts")];
+
+        let chain = graph.trace_path(&cwd.join("c.ts"), &entries).unwrap();
+        assert_eq!(chain, vec![cwd.join("a.ts"), cwd.join("b.ts"), cwd.join("c.ts")]);
+
+        let chain = graph.trace_path(&cwd.join("a.ts"), &entries).unwrap();
+        assert_eq!(chain, vec![cwd.join("a.ts")]);
+
+        assert!(graph.trace_path(&cwd.join("orphan.ts"), &entries).is_none());
+    }
+
+    #[test]
+    fn test_edges_snapshot() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("a.ts"), "import './b';").unwrap();
+        fs::write(cwd.join("b.ts"), "export {};").unwrap();
+        fs::write(cwd.join("orphan.ts"), "import './b';").unwrap();
+
+        let project_files: FxHashSet<PathBuf> =
+            ["a.ts", "b.ts", "orphan.ts"].iter().map(|f| cwd.join(f)).collect();
+        let graph = DependencyGraph::new(
+            project_files,
+            Arc::new(ModuleResolver::new(&cwd)),
+            Arc::new(ModuleCache::new()),
+            false,
+        );
+
+        graph.find_reachable(&[cwd.join("a.ts")]);
+        let edges = graph.edges();
+        assert_eq!(edges.len(), 2);
+        assert_eq!(edges[&cwd.join("a.ts")], vec![cwd.join("b.ts")]);
+        assert!(edges[&cwd.join("b.ts")].is_empty());
+
+        graph.resolve_all();
+        assert_eq!(graph.edges()[&cwd.join("orphan.ts")], vec![cwd.join("b.ts")]);
+    }
+
+    #[test]
+    fn test_parallel_traversal_matches_sequential_on_large_graph() {
+        const FILES: usize = 5_000;
+
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+
+        // Binary-tree imports from file 0, plus every 7th file also importing
+        // an earlier one (cycles/shared deps). Files >= FILES/2 that aren't
+        // reached through the tree stay orphaned.
+        let mut project_files = FxHashSet::default();
+        for i in 0..FILES {
+            let mut content = String::new();
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < FILES / 2 {
+                    content.push_str(&format!("import './f{child}';\n"));
+                }
+            }
+            if i % 7 == 0 && i > 0 {
+                content.push_str(&format!("import './f{}';\n", i / 3));
+            }
+            content.push_str("export {};\n");
+
+            let path = cwd.join(format!("f{i}.ts"));
+            fs::write(&path, content).unwrap();
+            project_files.insert(path);
+        }
+
+        // Separate graphs, so the reference shares no cached edges with the parallel run
+        let new_graph = || {
+            DependencyGraph::new(
+                project_files.clone(),
+                Arc::new(ModuleResolver::new(&cwd)),
+                Arc::new(ModuleCache::new()),
+                false,
+            )
+        };
+        let entries = vec![cwd.join("f0.ts")];
+
+        let parallel = new_graph().find_reachable(&entries);
+
+        // Reference: the same traversal on a single thread
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+        let sequential = pool.install(|| new_graph().find_reachable(&entries));
+
+        // And the reachable set implied by how the files were generated
+        let mut expected = FxHashSet::default();
+        let mut queue: VecDeque<usize> = VecDeque::from([0]);
+        while let Some(i) = queue.pop_front() {
+            if !expected.insert(cwd.join(format!("f{i}.ts"))) {
+                continue;
+            }
+            queue.extend([2 * i + 1, 2 * i + 2].into_iter().filter(|child| *child < FILES / 2));
+            if i % 7 == 0 && i > 0 {
+                queue.push_back(i / 3);
+            }
+        }
+
+        assert_eq!(parallel, expected);
+        assert_eq!(parallel, sequential);
+        assert!(parallel.len() > 1 && parallel.len() < FILES);
+    }
 }
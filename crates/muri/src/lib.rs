@@ -1,159 +1,451 @@
+pub mod classify;
 pub mod cli;
+pub mod codeowners;
 pub mod collector;
+pub mod compiler;
 pub mod dependencies;
+pub mod duplicates;
+pub mod exports;
 pub mod graph;
 pub mod module_cache;
+pub mod package_entries;
 pub mod parser;
 pub mod plugin;
 pub mod reporter;
 pub mod resolver;
 pub mod types;
+pub mod workspace;
 
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 
-pub use plugin::{EntryPattern, PluginEntries, PluginRegistry};
-pub use reporter::Report;
+pub use classify::FileClass;
+pub use compiler::{Compiler, CompilerOutput, CompilerRegistry};
+pub use exports::UnusedExport;
+pub use graph::UnresolvedImport;
+pub use plugin::{
+    ActivePlugin, DetectedEntries, EntryPattern, ModuleAlias, PluginEntries, PluginRegistry,
+    PluginTrigger,
+};
+pub use reporter::{FULL_REPORT_SCHEMA_VERSION, FullReport, REPORT_SCHEMA_VERSION, Report};
 pub use types::{
-    DEFAULT_EXTENSIONS, FOREIGN_FILE_EXTENSIONS, FileConfig, MuriConfig, MuriError, PluginConfig,
+    COMPONENT_EXTENSIONS, CompilerConfig, DEFAULT_CACHE_DIR, DEFAULT_EXTENSIONS, DEFAULT_MAX_FILES,
+    FOREIGN_FILE_EXTENSIONS, FileConfig, MuriConfig, MuriError, PluginConfig, PluginSettings,
+    ProgressEvent, ProgressHandler, REACHABLE_ASSET_EXTENSIONS,
 };
 
-use collector::Collector;
-use dependencies::detect_dependencies;
+use classify::FileClassifier;
+use collector::{Collector, ProjectIndex};
+use compiler::{
+    AstroCompiler, CssCompiler, HtmlCompiler, JsonCompiler, ScssCompiler, StylusCompiler,
+    SvelteCompiler, VueCompiler,
+};
+use dependencies::{DependencyInfo, detect_dependencies, detect_dependency_map};
 use graph::DependencyGraph;
 use module_cache::ModuleCache;
 use plugin::{
-    CypressPlugin, EslintPlugin, HuskyPlugin, JestPlugin, LintStagedPlugin, NextjsPlugin,
-    PlaywrightPlugin, Plugin, PostcssPlugin, StorybookPlugin, TailwindPlugin, TypescriptPlugin,
-    VitePlugin, VitestPlugin,
+    AngularPlugin, AvaPlugin, BabelPlugin, CapacitorPlugin, CypressPlugin, ElectronPlugin,
+    EsbuildPlugin, EslintPlugin, GatsbyPlugin, HuskyPlugin, JestPlugin, KarmaPlugin,
+    LintStagedPlugin, MochaPlugin, MswPlugin, NextjsPlugin, NxPlugin, PlaywrightPlugin, Plugin,
+    PostcssPlugin, QwikPlugin, RollupPlugin, SolidStartPlugin, StencilPlugin, StorybookPlugin,
+    TailwindPlugin, TurboPlugin, TypescriptPlugin, VitePlugin, VitestPlugin, WdioPlugin,
 };
 use resolver::ModuleResolver;
 use rustc_hash::FxHashSet;
 
+/// Register a plugin if enabled by its settings or, when not set, by auto-detection.
+/// Settings are applied to the plugin before it is registered.
+fn register_plugin<P: Plugin + 'static>(
+    registry: &mut PluginRegistry,
+    mut plugin: P,
+    settings: Option<&PluginSettings>,
+    cwd: &Path,
+    deps: &DependencyInfo,
+) {
+    let configured = settings.and_then(|s| s.enabled);
+    let enabled = configured.unwrap_or_else(|| plugin.should_enable_for(cwd, deps));
+
+    if !enabled {
+        return;
+    }
+
+    if let Some(settings) = settings {
+        plugin.configure(settings);
+        registry.add_patterns(settings.entry.iter().map(EntryPattern::new));
+    }
+
+    if configured.is_some() {
+        registry.register(Arc::new(plugin));
+    } else {
+        registry.register_detected(Arc::new(plugin));
+    }
+}
+
 /// Create a plugin registry with built-in plugins enabled based on detected dependencies
 /// and user configuration
 fn create_plugin_registry(
-    cwd: &std::path::Path,
+    cwd: &Path,
     plugin_config: &types::PluginConfig,
-    deps: &FxHashSet<String>,
+    deps: &DependencyInfo,
 ) -> PluginRegistry {
     let mut registry = PluginRegistry::new();
 
-    // Storybook plugin: check config override, then fall back to auto-detection
-    let storybook_plugin = StorybookPlugin::new();
-    let storybook_enabled =
-        plugin_config.storybook.unwrap_or_else(|| storybook_plugin.should_enable(cwd, deps));
+    // Each plugin: check config override, then fall back to auto-detection
+    register_plugin(
+        &mut registry,
+        StorybookPlugin::new(),
+        plugin_config.storybook.as_ref(),
+        cwd,
+        deps,
+    );
 
-    if storybook_enabled {
-        registry.register(Arc::new(storybook_plugin));
-    }
+    register_plugin(
+        &mut registry,
+        TailwindPlugin::new(),
+        plugin_config.tailwind.as_ref(),
+        cwd,
+        deps,
+    );
 
-    // Tailwind plugin: check config override, then fall back to auto-detection
-    let tailwind_plugin = TailwindPlugin::new();
-    let tailwind_enabled =
-        plugin_config.tailwind.unwrap_or_else(|| tailwind_plugin.should_enable(cwd, deps));
+    register_plugin(&mut registry, PostcssPlugin::new(), plugin_config.postcss.as_ref(), cwd, deps);
 
-    if tailwind_enabled {
-        registry.register(Arc::new(tailwind_plugin));
-    }
+    register_plugin(&mut registry, JestPlugin::new(), plugin_config.jest.as_ref(), cwd, deps);
 
-    // PostCSS plugin: check config override, then fall back to auto-detection
-    let postcss_plugin = PostcssPlugin::new();
-    let postcss_enabled =
-        plugin_config.postcss.unwrap_or_else(|| postcss_plugin.should_enable(cwd, deps));
+    register_plugin(&mut registry, VitestPlugin::new(), plugin_config.vitest.as_ref(), cwd, deps);
 
-    if postcss_enabled {
-        registry.register(Arc::new(postcss_plugin));
-    }
+    register_plugin(&mut registry, EslintPlugin::new(), plugin_config.eslint.as_ref(), cwd, deps);
 
-    // Jest plugin: check config override, then fall back to auto-detection
-    let jest_plugin = JestPlugin::new();
-    let jest_enabled = plugin_config.jest.unwrap_or_else(|| jest_plugin.should_enable(cwd, deps));
+    register_plugin(&mut registry, NextjsPlugin::new(), plugin_config.nextjs.as_ref(), cwd, deps);
 
-    if jest_enabled {
-        registry.register(Arc::new(jest_plugin));
-    }
+    register_plugin(&mut registry, VitePlugin::new(), plugin_config.vite.as_ref(), cwd, deps);
 
-    // Vitest plugin: check config override, then fall back to auto-detection
-    let vitest_plugin = VitestPlugin::new();
-    let vitest_enabled =
-        plugin_config.vitest.unwrap_or_else(|| vitest_plugin.should_enable(cwd, deps));
+    register_plugin(
+        &mut registry,
+        TypescriptPlugin::new(),
+        plugin_config.typescript.as_ref(),
+        cwd,
+        deps,
+    );
 
-    if vitest_enabled {
-        registry.register(Arc::new(vitest_plugin));
-    }
+    register_plugin(&mut registry, CypressPlugin::new(), plugin_config.cypress.as_ref(), cwd, deps);
+
+    register_plugin(
+        &mut registry,
+        PlaywrightPlugin::new(),
+        plugin_config.playwright.as_ref(),
+        cwd,
+        deps,
+    );
+
+    register_plugin(&mut registry, HuskyPlugin::new(), plugin_config.husky.as_ref(), cwd, deps);
+
+    register_plugin(
+        &mut registry,
+        LintStagedPlugin::new(),
+        plugin_config.lint_staged.as_ref(),
+        cwd,
+        deps,
+    );
+
+    register_plugin(
+        &mut registry,
+        CapacitorPlugin::new(),
+        plugin_config.capacitor.as_ref(),
+        cwd,
+        deps,
+    );
+
+    register_plugin(
+        &mut registry,
+        ElectronPlugin::new(),
+        plugin_config.electron.as_ref(),
+        cwd,
+        deps,
+    );
+
+    register_plugin(&mut registry, StencilPlugin::new(), plugin_config.stencil.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, AvaPlugin::new(), plugin_config.ava.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, MochaPlugin::new(), plugin_config.mocha.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, WdioPlugin::new(), plugin_config.wdio.as_ref(), cwd, deps);
 
-    // ESLint plugin: check config override, then fall back to auto-detection
-    let eslint_plugin = EslintPlugin::new();
-    let eslint_enabled =
-        plugin_config.eslint.unwrap_or_else(|| eslint_plugin.should_enable(cwd, deps));
+    register_plugin(&mut registry, KarmaPlugin::new(), plugin_config.karma.as_ref(), cwd, deps);
 
-    if eslint_enabled {
-        registry.register(Arc::new(eslint_plugin));
+    register_plugin(&mut registry, GatsbyPlugin::new(), plugin_config.gatsby.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, AngularPlugin::new(), plugin_config.angular.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, EsbuildPlugin::new(), plugin_config.esbuild.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, RollupPlugin::new(), plugin_config.rollup.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, TurboPlugin::new(), plugin_config.turbo.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, NxPlugin::new(), plugin_config.nx.as_ref(), cwd, deps);
+
+    register_plugin(
+        &mut registry,
+        SolidStartPlugin::new(),
+        plugin_config.solid_start.as_ref(),
+        cwd,
+        deps,
+    );
+
+    register_plugin(&mut registry, QwikPlugin::new(), plugin_config.qwik.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, MswPlugin::new(), plugin_config.msw.as_ref(), cwd, deps);
+
+    register_plugin(&mut registry, BabelPlugin::new(), plugin_config.babel.as_ref(), cwd, deps);
+
+    registry
+}
+
+/// Create a compiler registry with built-in compilers enabled by the config overrides or,
+/// when not set, based on detected dependencies
+fn create_compiler_registry(
+    compiler_config: &CompilerConfig,
+    deps: &FxHashSet<String>,
+) -> CompilerRegistry {
+    let mut registry = CompilerRegistry::new();
+
+    let compilers: Vec<Arc<dyn Compiler>> = vec![
+        Arc::new(CssCompiler::new()),
+        Arc::new(HtmlCompiler::new()),
+        Arc::new(JsonCompiler::new()),
+        Arc::new(ScssCompiler::new()),
+        Arc::new(StylusCompiler::new()),
+        Arc::new(VueCompiler::new()),
+        Arc::new(SvelteCompiler::new()),
+        Arc::new(AstroCompiler::new()),
+    ];
+
+    for compiler in compilers {
+        let enabled = compiler_config
+            .enabled(compiler.name())
+            .unwrap_or_else(|| compiler.should_enable(deps));
+        if enabled {
+            registry.register(compiler);
+        }
     }
 
-    // Next.js plugin: check config override, then fall back to auto-detection
-    let nextjs_plugin = NextjsPlugin::new();
-    let nextjs_enabled =
-        plugin_config.nextjs.unwrap_or_else(|| nextjs_plugin.should_enable(cwd, deps));
+    registry
+}
+
+/// Component extensions (e.g. `.vue`) handled by an active compiler
+fn component_extensions(compilers: &CompilerRegistry) -> Vec<String> {
+    compilers
+        .extensions()
+        .into_iter()
+        .filter(|ext| COMPONENT_EXTENSIONS.contains(ext))
+        .map(str::to_string)
+        .collect()
+}
 
-    if nextjs_enabled {
-        registry.register(Arc::new(nextjs_plugin));
+/// Extend project patterns with component files in the same scope: a pattern whose
+/// file name part is `*.<ext>` or `*.{...}` (e.g. `src/**/*.{ts,tsx}`) gets a copy
+/// for each extension (`src/**/*.vue`). Other patterns are kept as they are.
+fn with_component_patterns(project: &[String], extensions: &[String]) -> Vec<String> {
+    let mut patterns = project.to_vec();
+    for pattern in project {
+        let (dir, file) = match pattern.rfind('/') {
+            Some(idx) => pattern.split_at(idx + 1),
+            None => ("", pattern.as_str()),
+        };
+        if !file.starts_with("*.") {
+            continue;
+        }
+        for ext in extensions {
+            let extended = format!("{dir}*{ext}");
+            if !patterns.contains(&extended) {
+                patterns.push(extended);
+            }
+        }
     }
+    patterns
+}
+
+/// Check whether SVGs are imported as components (SVGR)
+fn has_svgr(deps: &FxHashSet<String>) -> bool {
+    deps.contains("@svgr/webpack") || deps.contains("vite-plugin-svgr")
+}
+
+/// A configured project ready for graph queries
+struct Analysis {
+    cwd: PathBuf,
+    graph: DependencyGraph,
+    entry_files: Vec<PathBuf>,
+    project_file_count: usize,
+    classifier: FileClassifier,
+    module_cache: Arc<ModuleCache>,
+    resolver: Arc<ModuleResolver>,
+    /// Absolute cache directory, if the disk cache is enabled
+    cache_dir: Option<PathBuf>,
+    /// Names of the active plugins
+    plugins: Vec<String>,
+    verbose: bool,
+}
 
-    // Vite plugin: check config override, then fall back to auto-detection
-    let vite_plugin = VitePlugin::new();
-    let vite_enabled = plugin_config.vite.unwrap_or_else(|| vite_plugin.should_enable(cwd, deps));
+impl Analysis {
+    /// Find exports of reachable files that no reachable file imports
+    fn unused_exports(&self, reachable: &FxHashSet<PathBuf>) -> Vec<UnusedExport> {
+        exports::find_unused_exports(
+            reachable,
+            &self.entry_files,
+            self.graph.project_files(),
+            &self.resolver,
+            &self.module_cache,
+        )
+    }
 
-    if vite_enabled {
-        registry.register(Arc::new(vite_plugin));
+    /// Write parsed modules to the disk cache (if enabled). Failures only warn.
+    fn persist_cache(&self) {
+        let Some(cache_dir) = &self.cache_dir else {
+            return;
+        };
+        if let Err(e) = self.module_cache.persist_to(cache_dir) {
+            if self.verbose {
+                eprintln!("Warning: Failed to write cache to '{}': {e}", cache_dir.display());
+            }
+        }
     }
+}
 
-    // TypeScript plugin: check config override, then fall back to auto-detection
-    let typescript_plugin = TypescriptPlugin::new();
-    let typescript_enabled =
-        plugin_config.typescript.unwrap_or_else(|| typescript_plugin.should_enable(cwd, deps));
+/// Files collected for one package directory
+struct CollectedPackage {
+    index: ProjectIndex,
+    classifier: FileClassifier,
+    /// Module aliases from plugin configs (e.g. Jest's `moduleNameMapper`)
+    aliases: Vec<ModuleAlias>,
+    /// Names of the active plugins
+    plugins: Vec<String>,
+}
 
-    if typescript_enabled {
-        registry.register(Arc::new(typescript_plugin));
+/// Run plugins and a single collector walk for `cwd`, merging plugin-discovered paths into entries
+fn collect_package(
+    config: &MuriConfig,
+    cwd: &Path,
+    deps: &DependencyInfo,
+) -> Result<CollectedPackage, MuriError> {
+    for patterns in [&config.entry, &config.project, &config.ignore, &config.allowlist] {
+        collector::validate_patterns(patterns)?;
     }
 
-    // Cypress plugin: check config override, then fall back to auto-detection
-    let cypress_plugin = CypressPlugin::new();
-    let cypress_enabled =
-        plugin_config.cypress.unwrap_or_else(|| cypress_plugin.should_enable(cwd, deps));
+    // Create plugin registry and collect patterns and paths in a single pass
+    let plugin_registry = create_plugin_registry(cwd, &config.plugins, deps);
+    let detected = plugin_registry.collect_all(cwd);
 
-    if cypress_enabled {
-        registry.register(Arc::new(cypress_plugin));
+    // Component files (e.g. `.vue`) are project files while their compiler is active,
+    // so unused components are reported alongside JS/TS files
+    let extensions =
+        component_extensions(&create_compiler_registry(&config.compilers, &deps.all()));
+    let project = with_component_patterns(&config.project, &extensions);
+
+    // Single walk to collect both entry and project files, including plugin patterns
+    let mut collector =
+        Collector::new(cwd, &config.entry, &project, &config.ignore, &detected.patterns)
+            .with_plugin_ignore(&detected.ignore)
+            .with_max_files(config.max_files)
+            .with_max_depth(config.max_depth)
+            .with_gitignore(config.respect_gitignore)
+            .with_follow_symlinks(config.follow_symlinks)
+            .with_include_dts(config.include_dts)
+            .with_cancel(config.cancel.clone())
+            .with_extra_extensions(extensions);
+    if let Some(files) = &config.project_files {
+        collector = collector.with_project_files(files.clone());
     }
+    let mut index = collector.collect()?;
 
-    // Playwright plugin: check config override, then fall back to auto-detection
-    let playwright_plugin = PlaywrightPlugin::new();
-    let playwright_enabled =
-        plugin_config.playwright.unwrap_or_else(|| playwright_plugin.should_enable(cwd, deps));
+    // Fall back to the entry points declared in package.json when no entry pattern matched
+    if config.auto_entry && !index.entry_files.iter().any(|file| collector.matches_entry(file)) {
+        index.entry_files.extend(package_entries::package_entries(cwd));
+    }
 
-    if playwright_enabled {
-        registry.register(Arc::new(playwright_plugin));
+    // Merge plugin-discovered paths into index.
+    // Plugin entries (like config files) may be outside the project directory,
+    // but we still need to trace their imports to mark project files as reachable.
+    let classifier = FileClassifier::new(cwd, detected.paths.iter().cloned());
+    for path in detected.paths {
+        index.entry_files.insert(path);
     }
 
-    // husky plugin: check config override, then fall back to auto-detection
-    let husky_plugin = HuskyPlugin::new();
-    let husky_enabled =
-        plugin_config.husky.unwrap_or_else(|| husky_plugin.should_enable(cwd, deps));
+    let plugins = plugin_registry.plugin_names().into_iter().map(String::from).collect();
+    Ok(CollectedPackage { index, classifier, aliases: detected.aliases, plugins })
+}
 
-    if husky_enabled {
-        registry.register(Arc::new(husky_plugin));
+/// Create a module cache with the compilers enabled for the project, loading the
+/// disk cache if configured
+fn create_module_cache(
+    config: &MuriConfig,
+    cwd: &Path,
+    deps: &FxHashSet<String>,
+) -> Arc<ModuleCache> {
+    let compilers = Arc::new(create_compiler_registry(&config.compilers, deps));
+    let module_cache = Arc::new(ModuleCache::with_compilers(compilers));
+    if let Some(dir) = &config.cache_dir {
+        module_cache.load_from(&cwd.join(dir));
     }
+    module_cache
+}
+
+/// Collect entry and project files and build the dependency graph for a config.
+/// An existing module cache (e.g. from a [`Session`]) is reused if given.
+fn prepare_analysis(
+    config: &MuriConfig,
+    module_cache: Option<Arc<ModuleCache>>,
+) -> Result<Analysis, MuriError> {
+    let cwd = config.cwd.canonicalize()?;
 
-    // lint-staged plugin: check config override, then fall back to auto-detection
-    let lint_staged_plugin = LintStagedPlugin::new();
-    let lint_staged_enabled =
-        plugin_config.lint_staged.unwrap_or_else(|| lint_staged_plugin.should_enable(cwd, deps));
+    // Detect dependencies for plugins
+    let dep_info = detect_dependency_map(&cwd);
+    let deps = dep_info.all();
 
-    if lint_staged_enabled {
-        registry.register(Arc::new(lint_staged_plugin));
+    let CollectedPackage { index, classifier, aliases, plugins } =
+        collect_package(config, &cwd, &dep_info)?;
+    if config.is_cancelled() {
+        return Err(MuriError::Cancelled);
     }
 
-    registry
+    if index.entry_files.is_empty() {
+        return Err(MuriError::NoEntryFiles(config.entry.clone()));
+    }
+
+    // Build graph (with shared module cache for parsing)
+    let project_file_count = index.project_files.len();
+    emit_progress(config, ProgressEvent::FilesCollected(project_file_count));
+    let resolver = Arc::new(ModuleResolver::new(&cwd).with_aliases(&aliases));
+    let module_cache = module_cache.unwrap_or_else(|| create_module_cache(config, &cwd, &deps));
+    let cache_dir = config.cache_dir.as_ref().map(|dir| cwd.join(dir));
+    let graph = DependencyGraph::new(
+        index.project_files,
+        Arc::clone(&resolver),
+        Arc::clone(&module_cache),
+        config.verbose,
+    )
+    .with_svgr(has_svgr(&deps))
+    .with_progress(config.progress.clone())
+    .with_cancel(config.cancel.clone());
+
+    // Sort entries so traversal order (and traced chains) are deterministic
+    let mut entry_files: Vec<_> = index.entry_files.into_iter().collect();
+    entry_files.sort();
+
+    Ok(Analysis {
+        cwd,
+        graph,
+        entry_files,
+        project_file_count,
+        classifier,
+        module_cache,
+        resolver,
+        cache_dir,
+        plugins,
+        verbose: config.verbose,
+    })
 }
 
 /// Find unused files in a JavaScript/TypeScript project
@@ -180,77 +472,1939 @@ fn create_plugin_registry(
 /// println!("Found {} unused files", report.unused_count);
 /// ```
 pub fn find_unused_files(config: MuriConfig) -> Result<Report, MuriError> {
-    let cwd = config.cwd.canonicalize()?;
+    in_thread_pool(&config, || {
+        let start = Instant::now();
+        let analysis = prepare_analysis(&config, None)?;
+        unused_files_report(&config, analysis, start)
+    })
+}
 
-    // Detect dependencies for plugins
-    let deps = detect_dependencies(&cwd);
+/// Find unused files, yielding them one at a time
+///
+/// The analysis (collection, parsing and reachability) runs up front; the
+/// returned iterator then walks the project files and yields each unused one as
+/// it is confirmed, without building the list. Files come in no particular
+/// order. Applies the same filters as [`find_unused_files`].
+///
+/// # Example
+/// ```no_run
+/// use muri::{find_unused_iter, MuriConfig};
+///
+/// let config = MuriConfig { entry: vec!["src/index.ts".to_string()], ..Default::default() };
+/// for path in find_unused_iter(config).unwrap() {
+///     println!("{}", path.display());
+/// }
+/// ```
+pub fn find_unused_iter(config: MuriConfig) -> Result<UnusedFiles, MuriError> {
+    in_thread_pool(&config, || {
+        let analysis = prepare_analysis(&config, None)?;
+        let reachable = analysis.graph.find_reachable(&analysis.entry_files);
+        if analysis.graph.is_cancelled() {
+            return Err(MuriError::Cancelled);
+        }
+        analysis.persist_cache();
+        emit_progress(&config, ProgressEvent::Done);
+        Ok(UnusedFiles::new(&config, analysis, reachable))
+    })
+}
 
-    // Create plugin registry and collect patterns and paths in a single pass
-    let plugin_registry = create_plugin_registry(&cwd, &config.plugins, &deps);
-    let (plugin_patterns, plugin_paths) = plugin_registry.collect_all(&cwd);
+/// Iterator over unused files, returned by [`find_unused_iter`]
+pub struct UnusedFiles {
+    project_files: std::collections::hash_set::IntoIter<PathBuf>,
+    reachable: FxHashSet<PathBuf>,
+    classifier: FileClassifier,
+    filter: UnusedFilter,
+}
 
-    // Single walk to collect both entry and project files, including plugin patterns
-    let collector =
-        Collector::new(&cwd, &config.entry, &config.project, &config.ignore, &plugin_patterns);
-    let mut index = collector.collect();
+impl UnusedFiles {
+    fn new(config: &MuriConfig, analysis: Analysis, reachable: FxHashSet<PathBuf>) -> Self {
+        let filter = UnusedFilter::new(config, &analysis.cwd, &analysis.cwd);
+        let Analysis { graph, classifier, .. } = analysis;
+        Self {
+            project_files: graph.into_project_files().into_iter(),
+            reachable,
+            classifier,
+            filter,
+        }
+    }
+}
 
-    // Merge plugin-discovered paths into index.
-    // Plugin entries (like config files) may be outside the project directory,
-    // but we still need to trace their imports to mark project files as reachable.
-    for path in plugin_paths {
-        index.entry_files.insert(path);
+impl Iterator for UnusedFiles {
+    type Item = PathBuf;
+
+    fn next(&mut self) -> Option<PathBuf> {
+        self.project_files.by_ref().find(|path| {
+            !self.reachable.contains(path) && self.filter.keeps(&self.classifier, path)
+        })
     }
+}
 
-    if index.entry_files.is_empty() {
-        return Err(MuriError::NoEntryFiles(config.entry));
+/// Run `f` on a dedicated pool of `config.concurrency` threads, so the parallel
+/// parsing and traversal inside it are capped; without a limit, `f` runs on
+/// rayon's global pool
+fn in_thread_pool<T: Send>(
+    config: &MuriConfig,
+    f: impl FnOnce() -> Result<T, MuriError> + Send,
+) -> Result<T, MuriError> {
+    let Some(threads) = config.concurrency else {
+        return f();
+    };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(|e| MuriError::ThreadPool { threads, message: e.to_string() })?;
+    pool.install(f)
+}
+
+/// Build the unused-files report for a prepared analysis
+fn unused_files_report(
+    config: &MuriConfig,
+    analysis: Analysis,
+    start: Instant,
+) -> Result<Report, MuriError> {
+    let reachable = analysis.graph.find_reachable(&analysis.entry_files);
+    if analysis.graph.is_cancelled() {
+        return Err(MuriError::Cancelled);
     }
+    analysis.persist_cache();
+    let project_file_count = analysis.project_file_count;
+    let reachable_count =
+        analysis.graph.project_files().iter().filter(|path| reachable.contains(*path)).count();
+    let entry_count = analysis.entry_files.len();
+    let unresolved_imports =
+        if config.verbose { analysis.graph.unresolved_imports() } else { Vec::new() };
+    let active_plugins = if config.verbose { analysis.plugins.clone() } else { Vec::new() };
+    let mut unused_exports = Vec::new();
+    if config.unused_exports {
+        unused_exports = analysis.unused_exports(&reachable);
+        if config.is_cancelled() {
+            return Err(MuriError::Cancelled);
+        }
+    }
+
+    let mut unused: Vec<_> = UnusedFiles::new(config, analysis, reachable).collect();
+    unused.sort();
 
-    // Build graph and find unused (with shared module cache for parsing)
-    let resolver = Arc::new(ModuleResolver::new(&cwd));
-    let module_cache = Arc::new(ModuleCache::new());
-    let graph =
-        DependencyGraph::new(index.project_files.clone(), resolver, module_cache, config.verbose);
-    let unused = graph.find_unused(&index.entry_files.into_iter().collect::<Vec<_>>());
+    let mut report = Report::new(unused, project_file_count).with_file_sizes();
+    report.reachable_count = reachable_count;
+    report.entry_count = entry_count;
+    report.unresolved_imports = unresolved_imports;
+    report.active_plugins = active_plugins;
+    report.unused_exports = unused_exports;
+    report.duration_ms = start.elapsed().as_millis() as u64;
+    emit_progress(config, ProgressEvent::Done);
+    Ok(report)
+}
 
-    Ok(Report::new(unused, index.project_files.len()))
+/// Narrows unused files down to those the report includes
+struct UnusedFilter {
+    /// File classes to report (empty = report everything)
+    classes: Vec<FileClass>,
+    /// Canonical `changed_files`; when set, only these are reported
+    changed: Option<FxHashSet<PathBuf>>,
+    /// Allowlist patterns, matched relative to `allowlist_root`
+    allowlist: Option<globset::GlobSet>,
+    allowlist_root: PathBuf,
+}
+
+impl UnusedFilter {
+    /// `changed_files` are relative to `cwd`, allowlist patterns to `allowlist_root`.
+    /// Changed files that no longer exist are skipped.
+    fn new(config: &MuriConfig, cwd: &Path, allowlist_root: &Path) -> Self {
+        Self {
+            classes: config.report_classes.clone(),
+            changed: config.changed_files.as_ref().map(|changed| {
+                changed.iter().filter_map(|file| cwd.join(file).canonicalize().ok()).collect()
+            }),
+            allowlist: (!config.allowlist.is_empty())
+                .then(|| collector::compile_globset(&config.allowlist)),
+            allowlist_root: allowlist_root.to_path_buf(),
+        }
+    }
+
+    fn keeps(&self, classifier: &FileClassifier, path: &Path) -> bool {
+        if !self.classes.is_empty() && !self.classes.contains(&classifier.classify(path)) {
+            return false;
+        }
+        if self.changed.as_ref().is_some_and(|changed| !changed.contains(path)) {
+            return false;
+        }
+        self.allowlist.as_ref().is_none_or(|allowlist| {
+            let relative = path.strip_prefix(&self.allowlist_root).unwrap_or(path);
+            !allowlist.is_match(relative.to_string_lossy().replace('\\', "/"))
+        })
+    }
+}
+
+/// Send a progress event to the configured handler, if any
+fn emit_progress(config: &MuriConfig, event: ProgressEvent) {
+    if let Some(progress) = &config.progress {
+        progress.emit(event);
+    }
+}
+
+/// Reusable state for repeated analyses of the same project (e.g. watch mode).
+///
+/// Parse results are kept in memory between runs, so only files passed to
+/// [`Session::invalidate`] are reparsed.
+pub struct Session {
+    config: MuriConfig,
+    module_cache: Arc<ModuleCache>,
+}
+
+impl Session {
+    pub fn new(config: MuriConfig) -> Result<Self, MuriError> {
+        let cwd = config.cwd.canonicalize()?;
+        let deps = detect_dependencies(&cwd);
+        let module_cache = create_module_cache(&config, &cwd, &deps);
+        Ok(Self { config, module_cache })
+    }
+
+    /// Drop cached parse results for changed, created, or deleted files
+    pub fn invalidate(&self, paths: &[PathBuf]) {
+        for path in paths {
+            self.module_cache.invalidate(path);
+        }
+    }
+
+    /// Re-collect files and find unused files, reusing cached parse results
+    pub fn find_unused_files(&self) -> Result<Report, MuriError> {
+        in_thread_pool(&self.config, || {
+            let start = Instant::now();
+            let analysis = prepare_analysis(&self.config, Some(Arc::clone(&self.module_cache)))?;
+            unused_files_report(&self.config, analysis, start)
+        })
+    }
+}
+
+/// Find unused files in each package of a monorepo
+///
+/// Packages are discovered from the root `package.json` `workspaces` globs and
+/// `pnpm-workspace.yaml`; if none are declared, the root is analyzed as a
+/// single package. `entry`/`project`/`ignore` patterns are applied relative to
+/// each package. Reachability is computed across all packages with a shared
+/// resolver and module cache, so a file only imported from another package is
+/// not reported. Reports are keyed by package path relative to the root.
+pub fn find_unused_files_workspace(
+    config: MuriConfig,
+) -> Result<BTreeMap<PathBuf, Report>, MuriError> {
+    in_thread_pool(&config, || workspace_reports(&config))
+}
+
+/// Analyze every workspace package as one graph, reporting each package separately
+fn workspace_reports(config: &MuriConfig) -> Result<BTreeMap<PathBuf, Report>, MuriError> {
+    let start = Instant::now();
+    let root = config.cwd.canonicalize()?;
+
+    let mut package_dirs = workspace::find_workspace_packages(&root);
+    if package_dirs.is_empty() {
+        package_dirs.push(root.clone());
+    }
+
+    // Each package's dependencies include the root's, which are hoisted
+    let mut all_deps = detect_dependencies(&root);
+    let mut packages = Vec::new();
+    for dir in package_dirs {
+        let deps = detect_dependency_map(&dir);
+        all_deps.extend(deps.all());
+        let collected = collect_package(config, &dir, &deps)?;
+        packages.push((dir, collected));
+    }
+
+    let mut entry_files: Vec<PathBuf> =
+        packages.iter().flat_map(|(_, p)| p.index.entry_files.iter().cloned()).collect();
+    if entry_files.is_empty() {
+        return Err(MuriError::NoEntryFiles(config.entry.clone()));
+    }
+    entry_files.sort();
+    entry_files.dedup();
+
+    // One graph over every package's files, so cross-package imports are traversed
+    let project_files: FxHashSet<PathBuf> =
+        packages.iter().flat_map(|(_, p)| p.index.project_files.iter().cloned()).collect();
+    emit_progress(config, ProgressEvent::FilesCollected(project_files.len()));
+    let aliases: Vec<ModuleAlias> =
+        packages.iter().flat_map(|(_, p)| p.aliases.iter().cloned()).collect();
+    let resolver = Arc::new(ModuleResolver::new(&root).with_aliases(&aliases));
+    let module_cache = create_module_cache(config, &root, &all_deps);
+    let cache_dir = config.cache_dir.as_ref().map(|dir| root.join(dir));
+    let graph = DependencyGraph::new(
+        project_files,
+        Arc::clone(&resolver),
+        Arc::clone(&module_cache),
+        config.verbose,
+    )
+    .with_svgr(has_svgr(&all_deps))
+    .with_progress(config.progress.clone())
+    .with_cancel(config.cancel.clone());
+
+    let reachable = graph.find_reachable(&entry_files);
+    if graph.is_cancelled() {
+        return Err(MuriError::Cancelled);
+    }
+    if let Some(dir) = &cache_dir {
+        if let Err(e) = module_cache.persist_to(dir) {
+            if config.verbose {
+                eprintln!("Warning: Failed to write cache to '{}': {e}", dir.display());
+            }
+        }
+    }
+
+    let unresolved = if config.verbose { graph.unresolved_imports() } else { Vec::new() };
+    // Exports are checked across the whole workspace, so an export used only by
+    // another package counts as used
+    let unused_exports = if config.unused_exports {
+        exports::find_unused_exports(
+            &reachable,
+            &entry_files,
+            graph.project_files(),
+            &resolver,
+            &module_cache,
+        )
+    } else {
+        Vec::new()
+    };
+    if config.is_cancelled() {
+        return Err(MuriError::Cancelled);
+    }
+    let mut reports = BTreeMap::new();
+    for (dir, package) in packages {
+        let total = package.index.project_files.len();
+        let unresolved_imports = unresolved
+            .iter()
+            .filter(|import| package.index.project_files.contains(&import.importer))
+            .cloned()
+            .collect();
+        let package_exports = unused_exports
+            .iter()
+            .filter(|export| package.index.project_files.contains(&export.file))
+            .cloned()
+            .collect();
+        let mut unused: Vec<_> =
+            package.index.project_files.into_iter().filter(|f| !reachable.contains(f)).collect();
+        unused.sort();
+        let reachable_count = total - unused.len();
+
+        let filter = UnusedFilter::new(config, &root, &dir);
+        unused.retain(|path| filter.keeps(&package.classifier, path));
+
+        let mut report = Report::new(unused, total).with_file_sizes();
+        report.reachable_count = reachable_count;
+        report.entry_count = package.index.entry_files.len();
+        report.unresolved_imports = unresolved_imports;
+        report.unused_exports = package_exports;
+        if config.verbose {
+            report.active_plugins = package.plugins;
+        }
+        report.duration_ms = start.elapsed().as_millis() as u64;
+
+        let key = match dir.strip_prefix(&root) {
+            Ok(relative) if !relative.as_os_str().is_empty() => relative.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        reports.insert(key, report);
+    }
+
+    emit_progress(config, ProgressEvent::Done);
+    Ok(reports)
+}
+
+/// Find unused exports
+///
+/// Returns `(file, export_name)` pairs for exports of reachable files that
+/// are never imported by any other reachable file. Re-exports and `export *`
+/// are treated as using the re-exported names.
+pub fn find_unused_exports(config: MuriConfig) -> Result<Vec<UnusedExport>, MuriError> {
+    in_thread_pool(&config, || {
+        let analysis = prepare_analysis(&config, None)?;
+        let reachable = analysis.graph.find_reachable(&analysis.entry_files);
+        if analysis.graph.is_cancelled() {
+            return Err(MuriError::Cancelled);
+        }
+        analysis.persist_cache();
+        Ok(analysis.unused_exports(&reachable))
+    })
+}
+
+/// List the plugins enabled for a project, with what enabled each
+///
+/// Runs dependency detection and plugin registration only, so no entry points
+/// are needed.
+pub fn find_active_plugins(config: MuriConfig) -> Result<Vec<ActivePlugin>, MuriError> {
+    let cwd = config.cwd.canonicalize()?;
+    let deps = detect_dependency_map(&cwd);
+    let registry = create_plugin_registry(&cwd, &config.plugins, &deps);
+    Ok(registry.active_plugins(&cwd, &deps.all()))
 }
 
 /// Find all files reachable from entry points
 ///
 /// Returns the set of files that are directly or transitively imported
 /// from the specified entry points.
-pub fn find_reachable_files(config: MuriConfig) -> Result<Vec<std::path::PathBuf>, MuriError> {
-    let cwd = config.cwd.canonicalize()?;
+pub fn find_reachable_files(config: MuriConfig) -> Result<Vec<PathBuf>, MuriError> {
+    in_thread_pool(&config, || {
+        let analysis = prepare_analysis(&config, None)?;
+        let reachable = analysis.graph.find_reachable(&analysis.entry_files);
+        if analysis.graph.is_cancelled() {
+            return Err(MuriError::Cancelled);
+        }
+        analysis.persist_cache();
 
-    // Detect dependencies for plugins
-    let deps = detect_dependencies(&cwd);
+        let mut result: Vec<_> = reachable.into_iter().collect();
+        result.sort();
+        Ok(result)
+    })
+}
 
-    // Create plugin registry and collect patterns and paths in a single pass
-    let plugin_registry = create_plugin_registry(&cwd, &config.plugins, &deps);
-    let (plugin_patterns, plugin_paths) = plugin_registry.collect_all(&cwd);
+/// Build the dependency graph of a project
+///
+/// Maps every project file (and every file reachable from an entry point) to
+/// the files it imports. Unresolved imports and foreign files other than
+/// reachable assets are omitted.
+pub fn build_dependency_graph(
+    config: MuriConfig,
+) -> Result<BTreeMap<PathBuf, Vec<PathBuf>>, MuriError> {
+    in_thread_pool(&config, || {
+        let analysis = prepare_analysis(&config, None)?;
+        analysis.graph.find_reachable(&analysis.entry_files);
+        analysis.graph.resolve_all();
+        if analysis.graph.is_cancelled() {
+            return Err(MuriError::Cancelled);
+        }
+        analysis.persist_cache();
 
-    // Single walk to collect both entry and project files, including plugin patterns
-    let collector =
-        Collector::new(&cwd, &config.entry, &config.project, &config.ignore, &plugin_patterns);
-    let mut index = collector.collect();
+        Ok(analysis.graph.edges())
+    })
+}
 
-    // Merge plugin-discovered paths into index.
-    // Plugin entries (like config files) may be outside the project directory,
-    // but we still need to trace their imports to mark project files as reachable.
-    for path in plugin_paths {
-        index.entry_files.insert(path);
+/// Explain why a file is reachable
+///
+/// Returns the shortest import chain from an entry point to `target`
+/// (both included) as paths relative to cwd, or `None` if the target is
+/// not reachable. `target` may be absolute or relative to cwd.
+pub fn explain_reachable(
+    config: MuriConfig,
+    target: &Path,
+) -> Result<Option<Vec<PathBuf>>, MuriError> {
+    in_thread_pool(&config, || {
+        let analysis = prepare_analysis(&config, None)?;
+
+        let Ok(target) = analysis.cwd.join(target).canonicalize() else {
+            return Ok(None);
+        };
+
+        let chain = analysis.graph.trace_path(&target, &analysis.entry_files);
+        analysis.persist_cache();
+        Ok(chain.map(|chain| {
+            chain
+                .into_iter()
+                .map(|p| p.strip_prefix(&analysis.cwd).map(Path::to_path_buf).unwrap_or(p))
+                .collect()
+        }))
+    })
+}
+
+/// Find groups of project files with identical content
+///
+/// Only the project patterns are used; entries are not needed. Empty files
+/// are skipped. Each group is sorted, and groups are ordered by their first file.
+pub fn find_duplicate_files(config: MuriConfig) -> Result<Vec<Vec<PathBuf>>, MuriError> {
+    in_thread_pool(&config, || {
+        let cwd = config.cwd.canonicalize()?;
+        let deps = detect_dependency_map(&cwd);
+        let CollectedPackage { index, .. } = collect_package(&config, &cwd, &deps)?;
+        if config.is_cancelled() {
+            return Err(MuriError::Cancelled);
+        }
+
+        let files: Vec<PathBuf> = index.project_files.into_iter().collect();
+        Ok(duplicates::duplicate_groups(&files))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_duplicate_project_files() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("src/legacy")).unwrap();
+        fs::write(temp.path().join("src/index.ts"), "import './utils';").unwrap();
+        fs::write(temp.path().join("src/utils.ts"), "export const pad = (s) => s;").unwrap();
+        fs::write(temp.path().join("src/legacy/utils.ts"), "export const pad = (s) => s;").unwrap();
+        fs::write(temp.path().join("src/legacy/other.ts"), "export const trim = (s) => s;")
+            .unwrap();
+
+        let config = MuriConfig { cwd: temp.path().to_path_buf(), ..Default::default() };
+        let groups = find_duplicate_files(config).unwrap();
+
+        let root = temp.path().canonicalize().unwrap();
+        assert_eq!(groups, vec![vec![root.join("src/legacy/utils.ts"), root.join("src/utils.ts")]]);
     }
 
-    if index.entry_files.is_empty() {
-        return Err(MuriError::NoEntryFiles(config.entry));
+    #[test]
+    fn test_namespace_import_is_reachable() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("index.ts"),
+            "namespace App {\n    import Bar = require(\"./bar\");\n}\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("bar.ts"), "export = 1;").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("bar.ts")));
     }
 
-    let resolver = Arc::new(ModuleResolver::new(&cwd));
-    let module_cache = Arc::new(ModuleCache::new());
-    let graph = DependencyGraph::new(index.project_files, resolver, module_cache, config.verbose);
-    let reachable = graph.find_reachable(&index.entry_files.into_iter().collect::<Vec<_>>());
+    #[test]
+    fn test_barrel_re_export_chain_is_reachable() {
+        let temp = tempdir().unwrap();
+        let files = [
+            ("index.ts", "import { Button, Card, icons } from './components';"),
+            (
+                "components/index.ts",
+                "export { default as Button } from './button';\nexport * from './cards';\nexport * as icons from './icons';",
+            ),
+            ("components/button.ts", "export default 1;"),
+            ("components/cards/index.ts", "export { default as Card, size } from './card';"),
+            ("components/cards/card.ts", "export default 1;\nexport const size = 2;"),
+            ("components/icons/index.ts", "export { default } from './arrow';"),
+            ("components/icons/arrow.ts", "export default 1;"),
+            ("components/orphan.ts", "export default 1;"),
+        ];
+        for (file, content) in files {
+            let path = temp.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("components/orphan.ts"));
+    }
+
+    #[test]
+    fn test_imported_svg_is_reachable() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("index.tsx"),
+            "import Logo from \"./logo.svg\";\nimport Icon from \"./icon.svg?react\";\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("logo.svg"), "<svg />").unwrap();
+        fs::write(temp.path().join("icon.svg"), "<svg />").unwrap();
+        fs::write(temp.path().join("unused.svg"), "<svg />").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.tsx".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("logo.svg")));
+        assert!(reachable.iter().any(|p| p.ends_with("icon.svg")));
+        assert!(!reachable.iter().any(|p| p.ends_with("unused.svg")));
+    }
+
+    #[test]
+    fn test_styled_component_background_is_reachable() {
+        let temp = tempdir().unwrap();
+        fs::create_dir(temp.path().join("images")).unwrap();
+        fs::write(
+            temp.path().join("index.tsx"),
+            "import styled from 'styled-components';\n\
+             export const Banner = styled.div`\n  background: url('./images/banner.svg');\n`;\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("images/banner.svg"), "<svg />").unwrap();
+        fs::write(temp.path().join("images/unused.svg"), "<svg />").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.tsx".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("images/banner.svg")));
+        assert!(!reachable.iter().any(|p| p.ends_with("images/unused.svg")));
+    }
+
+    #[test]
+    fn test_unresolved_alias_import_is_reported_in_verbose_mode() {
+        let temp = tempdir().unwrap();
+        fs::create_dir(temp.path().join("src")).unwrap();
+        fs::write(
+            temp.path().join("src/index.ts"),
+            "import { format } from '@/utils/format';\nimport { readFile } from 'node:fs';\nimport './local';\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("src/local.ts"), "").unwrap();
+        fs::create_dir(temp.path().join("src/utils")).unwrap();
+        fs::write(temp.path().join("src/utils/format.ts"), "export const format = 1;").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["src/index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            verbose: true,
+            ..Default::default()
+        };
+        let report = find_unused_files(config.clone()).unwrap();
+
+        // Without `paths` in tsconfig.json the alias can't resolve, so the file it
+        // points at is reported as unused; builtins aren't resolution failures
+        assert_eq!(report.unresolved_imports.len(), 1);
+        let unresolved = &report.unresolved_imports[0];
+        assert!(unresolved.importer.ends_with("src/index.ts"));
+        assert_eq!(unresolved.specifier, "@/utils/format");
+        assert!(report.unused_files[0].ends_with("src/utils/format.ts"));
+
+        let report = find_unused_files(MuriConfig { verbose: false, ..config }).unwrap();
+        assert!(report.unresolved_imports.is_empty());
+    }
+
+    #[test]
+    fn test_single_thread_concurrency_matches_default() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("src/features")).unwrap();
+        let imports: String = (0..20).map(|i| format!("import './features/f{i}';\n")).collect();
+        fs::write(temp.path().join("src/index.ts"), imports).unwrap();
+        for i in 0..30 {
+            let content = if i % 3 == 0 { "import '../shared';" } else { "" };
+            fs::write(temp.path().join(format!("src/features/f{i}.ts")), content).unwrap();
+        }
+        fs::write(temp.path().join("src/shared.ts"), "").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["src/index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let parallel = find_unused_files(config.clone()).unwrap();
+        let sequential =
+            find_unused_files(MuriConfig { concurrency: Some(1), ..config.clone() }).unwrap();
+
+        assert_eq!(sequential.unused_files, parallel.unused_files);
+        assert_eq!(sequential.unused_files.len(), 10);
+        assert_eq!(sequential.reachable_count, parallel.reachable_count);
+        assert_eq!(
+            build_dependency_graph(MuriConfig { concurrency: Some(1), ..config.clone() }).unwrap(),
+            build_dependency_graph(config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_concurrency_sizes_the_thread_pool() {
+        let config = MuriConfig { concurrency: Some(1), ..Default::default() };
+        assert_eq!(in_thread_pool(&config, || Ok(rayon::current_num_threads())).unwrap(), 1);
+
+        let config = MuriConfig { concurrency: Some(3), ..Default::default() };
+        assert_eq!(in_thread_pool(&config, || Ok(rayon::current_num_threads())).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_entry_above_cwd_marks_project_files_reachable() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("app/src")).unwrap();
+        fs::write(temp.path().join("app/src/index.ts"), "import './used';").unwrap();
+        fs::write(temp.path().join("app/src/used.ts"), "").unwrap();
+        fs::write(temp.path().join("app/src/setup-only.ts"), "").unwrap();
+        fs::write(temp.path().join("app/src/orphan.ts"), "").unwrap();
+        // Shared test setup living next to the package imports into it
+        fs::write(temp.path().join("test-setup.ts"), "import './app/src/setup-only';").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["src/index.ts".to_string(), "../test-setup.ts".to_string()],
+            cwd: temp.path().join("app"),
+            ..Default::default()
+        };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.total_files, 4);
+        assert_eq!(report.entry_count, 2);
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("src/orphan.ts"));
+    }
 
-    let mut result: Vec<_> = reachable.into_iter().collect();
-    result.sort();
-    Ok(result)
+    #[test]
+    fn test_declaration_files_with_include_dts() {
+        let temp = tempdir().unwrap();
+        fs::create_dir(temp.path().join("types")).unwrap();
+        fs::write(
+            temp.path().join("index.ts"),
+            "/// <reference path=\"types/globals.d.ts\" />\nimport './api';\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("api.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("types/globals.d.ts"), "declare const VERSION: string;")
+            .unwrap();
+        fs::write(temp.path().join("types/legacy.d.ts"), "declare module 'legacy';").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        // Declaration files are skipped by default
+        let report = find_unused_files(config.clone()).unwrap();
+        assert!(report.unused_files.is_empty());
+        assert_eq!(report.total_files, 2);
+
+        let report = find_unused_files(MuriConfig { include_dts: true, ..config }).unwrap();
+        assert_eq!(report.total_files, 4);
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("types/legacy.d.ts"));
+    }
+
+    #[test]
+    fn test_auto_entry_from_package_exports() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("src/utils")).unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "name": "lib", "exports": { ".": { "import": "./src/index.ts" }, "./utils": "./src/utils" } }"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join("src/index.ts"), "import './helpers';").unwrap();
+        fs::write(temp.path().join("src/helpers.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("src/utils/index.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("src/orphan.ts"), "export {};").unwrap();
+
+        let config =
+            MuriConfig { cwd: temp.path().to_path_buf(), auto_entry: true, ..Default::default() };
+        let report = find_unused_files(config.clone()).unwrap();
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("src/orphan.ts"));
+
+        // Explicit entries that match take precedence over package.json
+        let report =
+            find_unused_files(MuriConfig { entry: vec!["src/orphan.ts".to_string()], ..config })
+                .unwrap();
+        assert_eq!(report.unused_files.len(), 3);
+    }
+
+    #[test]
+    fn test_referenced_declaration_file_is_reachable() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("index.ts"),
+            "// App entry\n/// <reference path=\"globals.d.ts\" />\n/// <reference types=\"node\" />\nexport {};\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("globals.d.ts"), "/// <reference path=\"./env.d.ts\" />\n")
+            .unwrap();
+        fs::write(temp.path().join("env.d.ts"), "declare const ENV: string;").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            include_dts: true,
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("globals.d.ts")));
+        assert!(reachable.iter().any(|p| p.ends_with("env.d.ts")));
+    }
+
+    #[test]
+    fn test_changed_files_limit_the_report() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "import './changed-used';").unwrap();
+        // Unchanged, but the only importer of a changed file
+        fs::write(temp.path().join("changed-used.ts"), "import './helper';").unwrap();
+        fs::write(temp.path().join("helper.ts"), "").unwrap();
+        fs::write(temp.path().join("changed-unused.ts"), "").unwrap();
+        fs::write(temp.path().join("stale.ts"), "").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            changed_files: Some(vec![
+                PathBuf::from("changed-used.ts"),
+                PathBuf::from("helper.ts"),
+                PathBuf::from("changed-unused.ts"),
+                PathBuf::from("deleted.ts"),
+            ]),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("changed-unused.ts"));
+    }
+
+    #[test]
+    fn test_allowlisted_files_are_not_reported() {
+        let temp = tempdir().unwrap();
+        fs::create_dir(temp.path().join("generated")).unwrap();
+        fs::write(temp.path().join("index.ts"), "").unwrap();
+        fs::write(temp.path().join("generated/register.ts"), "").unwrap();
+        fs::write(temp.path().join("generated/routes.ts"), "").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            allowlist: vec!["generated/**".to_string()],
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.total_files, 4);
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("orphan.ts"));
+
+        let config: FileConfig =
+            serde_json::from_str(r#"{ "ignore_unused": ["generated/**"] }"#).unwrap();
+        assert_eq!(config.allowlist, vec!["generated/**"]);
+    }
+
+    #[test]
+    fn test_plugin_settings_from_config_file() {
+        let json = r#"{
+            "plugins": {
+                "storybook": false,
+                "jest": { "config": "config/jest.unit.js", "entry": ["src/**/*.check.ts"] }
+            }
+        }"#;
+        let config: FileConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.plugins.storybook, Some(PluginSettings::enabled(false)));
+        let jest = config.plugins.jest.unwrap();
+        assert_eq!(jest.enabled, None);
+        assert_eq!(jest.config, Some(PathBuf::from("config/jest.unit.js")));
+        assert_eq!(jest.entry, vec!["src/**/*.check.ts"]);
+    }
+
+    #[test]
+    fn test_custom_jest_config_path_is_entry() {
+        let temp = tempdir().unwrap();
+        fs::create_dir(temp.path().join("config")).unwrap();
+        fs::write(temp.path().join("config/jest.unit.js"), "require('./jest-helper');").unwrap();
+        fs::write(temp.path().join("config/jest-helper.js"), "").unwrap();
+        fs::write(temp.path().join("index.ts"), "export {};").unwrap();
+
+        let plugins = PluginConfig {
+            jest: Some(PluginSettings {
+                enabled: Some(true),
+                config: Some("config/jest.unit.js".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            plugins,
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 0);
+    }
+
+    #[test]
+    fn test_plugin_path_also_matched_by_pattern_is_one_entry() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("jest.config.js"), "module.exports = {};").unwrap();
+        fs::write(temp.path().join("index.ts"), "export {};").unwrap();
+
+        // Jest returns its config file as a path; the extra entry pattern matches it too
+        let plugins = PluginConfig {
+            jest: Some(PluginSettings {
+                enabled: Some(true),
+                entry: vec!["*.config.js".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            plugins,
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.entry_count, 2);
+        assert_eq!(report.unused_count, 0);
+    }
+
+    #[test]
+    fn test_max_depth_caps_walk() {
+        let temp = tempdir().unwrap();
+        let mut dir = temp.path().to_path_buf();
+        for level in 0..6 {
+            dir = dir.join(format!("level{level}"));
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("deep.ts"), "export {};").unwrap();
+        }
+        fs::write(temp.path().join("index.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("level0/orphan.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            max_files: 4,
+            ..Default::default()
+        };
+
+        // The whole tree exceeds the file cap...
+        let err = find_unused_files(config.clone()).unwrap_err();
+        assert!(matches!(err, MuriError::TooManyFiles { limit: 4, .. }));
+
+        // ...but only `index.ts` and the `level0` files are within two levels
+        let report = find_unused_files(MuriConfig { max_depth: Some(2), ..config }).unwrap();
+        assert_eq!(report.total_files, 3);
+        assert!(report.unused_files.iter().all(|path| path.parent().unwrap().ends_with("level0")));
+    }
+
+    #[test]
+    fn test_max_files_limit() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("a.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("b.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            max_files: 2,
+            ..Default::default()
+        };
+
+        let err = find_unused_files(config).unwrap_err();
+        assert!(matches!(err, MuriError::TooManyFiles { limit: 2, .. }));
+        assert!(err.to_string().starts_with("Found more than 2 files under"));
+        assert!(err.to_string().contains("Narrow `project`/`cwd` or raise --max-files"));
+    }
+
+    #[test]
+    fn test_scss_partial_from_vue_style_block_is_reachable() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "dependencies": { "vue": "^3.0.0" }, "devDependencies": { "sass": "^1.0.0" } }"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join("main.ts"), "import App from './App.vue';").unwrap();
+        fs::write(
+            temp.path().join("App.vue"),
+            "<template><div /></template>\n<style lang=\"scss\">\n@use \"./styles/partial\";\n</style>\n",
+        )
+        .unwrap();
+        fs::create_dir(temp.path().join("styles")).unwrap();
+        fs::write(temp.path().join("styles/_partial.scss"), "@use 'colors';").unwrap();
+        fs::write(temp.path().join("styles/_colors.scss"), "$primary: red;").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("App.vue")));
+        assert!(reachable.iter().any(|p| p.ends_with("styles/_partial.scss")));
+        assert!(reachable.iter().any(|p| p.ends_with("styles/_colors.scss")));
+    }
+
+    #[test]
+    fn test_sass_partials_from_svelte_style_block_are_reachable() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "dependencies": { "svelte": "^4.0.0" }, "devDependencies": { "sass": "^1.0.0" } }"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join("main.ts"), "import App from './App.svelte';").unwrap();
+        fs::write(
+            temp.path().join("App.svelte"),
+            "<div />\n<style lang=\"scss\">\n@use \"./styles/theme\";\n</style>\n",
+        )
+        .unwrap();
+        fs::create_dir_all(temp.path().join("styles/theme")).unwrap();
+        fs::write(temp.path().join("styles/theme/_index.scss"), "@forward 'colors';").unwrap();
+        fs::write(temp.path().join("styles/theme/_colors.scss"), "$primary: red;").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("styles/theme/_index.scss")));
+        assert!(reachable.iter().any(|p| p.ends_with("styles/theme/_colors.scss")));
+    }
+
+    #[test]
+    fn test_unused_vue_component_is_reported() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("package.json"), r#"{ "dependencies": { "vue": "^3.0.0" } }"#)
+            .unwrap();
+        fs::write(temp.path().join("main.ts"), "import App from './App.vue';").unwrap();
+        fs::write(temp.path().join("App.vue"), "<script setup>\nimport './Used.vue';\n</script>")
+            .unwrap();
+        fs::write(temp.path().join("Used.vue"), "<template><div /></template>").unwrap();
+        fs::write(temp.path().join("Unused.vue"), "<template><div /></template>").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("Unused.vue"));
+        assert_eq!(report.total_files, 4);
+    }
+
+    #[test]
+    fn test_vue_components_not_collected_without_compiler() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("main.ts"), "").unwrap();
+        fs::write(temp.path().join("Unused.vue"), "<template><div /></template>").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert!(report.unused_files.is_empty());
+        assert_eq!(report.total_files, 1);
+    }
+
+    #[test]
+    fn test_vite_index_html_scripts_are_traced() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("src")).unwrap();
+        fs::create_dir_all(temp.path().join("public")).unwrap();
+        fs::write(temp.path().join("package.json"), r#"{ "devDependencies": { "vite": "^5" } }"#)
+            .unwrap();
+        fs::write(
+            temp.path().join("index.html"),
+            r#"<link rel="icon" href="/favicon.svg"><link rel="stylesheet" href="/src/app.css">
+<script type="module" src="/src/main.ts"></script>"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join("public/favicon.svg"), "<svg />").unwrap();
+        fs::write(temp.path().join("src/app.css"), "body { background: url(./bg.png); }").unwrap();
+        fs::write(temp.path().join("src/main.ts"), "import './app';").unwrap();
+        fs::write(temp.path().join("src/app.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("src/orphan.ts"), "export {};").unwrap();
+
+        // The Vite plugin adds `index.html` as the entry, without any `--entry`
+        let config =
+            MuriConfig { cwd: temp.path().to_path_buf(), verbose: true, ..Default::default() };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("src/orphan.ts"));
+        // `/favicon.svg` is served from `public/`; only the missing image is unresolved
+        assert_eq!(report.unresolved_imports.len(), 1);
+        assert_eq!(report.unresolved_imports[0].specifier, "./bg.png");
+    }
+
+    #[test]
+    fn test_imported_json_is_reachable() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("src/data")).unwrap();
+        fs::create_dir_all(temp.path().join("fixtures")).unwrap();
+        fs::write(
+            temp.path().join("src/index.ts"),
+            "import config from './config.json';\nimport data from './data/users';\nimport seed from '../fixtures/seed.json';\n",
+        )
+        .unwrap();
+        fs::write(temp.path().join("src/config.json"), r#"{ "debug": true }"#).unwrap();
+        fs::write(temp.path().join("src/data/users.json"), "[]").unwrap();
+        fs::write(temp.path().join("src/data/stale.json"), "[]").unwrap();
+        fs::write(temp.path().join("fixtures/seed.json"), "{}").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["src/index.ts".to_string()],
+            project: vec!["src/**/*.{ts,json}".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let report = find_unused_files(config.clone()).unwrap();
+        assert_eq!(report.unused_files.len(), 1);
+        assert!(report.unused_files[0].ends_with("src/data/stale.json"));
+
+        // JSON outside the project patterns is a reachable leaf too
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|path| path.ends_with("fixtures/seed.json")));
+        assert_eq!(reachable.len(), 4);
+    }
+
+    #[test]
+    fn test_with_component_patterns_keeps_scope() {
+        let project = vec![
+            "src/**/*.{ts,tsx}".to_string(),
+            "*.ts".to_string(),
+            "scripts/build.ts".to_string(),
+        ];
+        let extensions = vec![".vue".to_string(), ".svelte".to_string()];
+
+        assert_eq!(
+            with_component_patterns(&project, &extensions),
+            vec![
+                "src/**/*.{ts,tsx}",
+                "*.ts",
+                "scripts/build.ts",
+                "src/**/*.vue",
+                "src/**/*.svelte",
+                "*.vue",
+                "*.svelte"
+            ]
+        );
+    }
+
+    /// A project where `main.ts` imports a stylesheet that imports another
+    fn write_scss_chain(root: &Path, package_json: &str) {
+        fs::write(root.join("package.json"), package_json).unwrap();
+        fs::write(root.join("main.ts"), "import './styles/theme.scss';").unwrap();
+        fs::create_dir(root.join("styles")).unwrap();
+        fs::write(root.join("styles/theme.scss"), "@import './colors.scss';").unwrap();
+        fs::write(root.join("styles/colors.scss"), "$primary: red;").unwrap();
+    }
+
+    #[test]
+    fn test_compiler_disabled_by_config() {
+        let temp = tempdir().unwrap();
+        write_scss_chain(temp.path(), r#"{ "devDependencies": { "sass": "^1.0.0" } }"#);
+
+        let file_config: FileConfig =
+            serde_json::from_str(r#"{ "compilers": { "scss": false } }"#).unwrap();
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            compilers: file_config.compilers.unwrap(),
+            ..Default::default()
+        };
+
+        // The stylesheet is not compiled, so its own imports are never traced
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(!reachable.iter().any(|p| p.ends_with("styles/colors.scss")));
+    }
+
+    #[test]
+    fn test_compiler_enabled_by_config_without_dependency() {
+        let temp = tempdir().unwrap();
+        write_scss_chain(temp.path(), "{}");
+
+        let mut compilers = CompilerConfig::default();
+        compilers.0.insert("scss".to_string(), true);
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            compilers,
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("styles/colors.scss")));
+    }
+
+    #[test]
+    fn test_scss_use_and_forward_partials_are_reachable() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("styles/theme")).unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "devDependencies": { "sass": "^1.0.0" } }"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join("main.ts"), "import './styles/app.scss';").unwrap();
+        fs::write(
+            temp.path().join("styles/app.scss"),
+            "@use \"sass:math\";\n@import \"~bootstrap/scss/bootstrap\";\n@use \"./theme\" as t;",
+        )
+        .unwrap();
+        fs::write(temp.path().join("styles/theme/_index.scss"), "@forward \"colors\";").unwrap();
+        fs::write(temp.path().join("styles/theme/_colors.scss"), "$primary: red;").unwrap();
+        fs::write(temp.path().join("styles/_unused.scss"), "$unused: 0;").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            verbose: true,
+            ..Default::default()
+        };
+        let report = find_unused_files(config.clone()).unwrap();
+        assert!(report.unresolved_imports.is_empty());
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("styles/theme/_index.scss")));
+        assert!(reachable.iter().any(|p| p.ends_with("styles/theme/_colors.scss")));
+        assert!(!reachable.iter().any(|p| p.ends_with("styles/_unused.scss")));
+    }
+
+    #[test]
+    fn test_scss_partial_is_reachable_from_bare_use() {
+        let temp = tempdir().unwrap();
+        write_scss_chain(temp.path(), r#"{ "devDependencies": { "sass": "^1.0.0" } }"#);
+        fs::write(temp.path().join("styles/theme.scss"), "@use \"button\";").unwrap();
+        fs::write(temp.path().join("styles/_button.scss"), ".btn { color: red; }").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["main.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("styles/_button.scss")));
+        assert!(!reachable.iter().any(|p| p.ends_with("styles/colors.scss")));
+    }
+
+    #[test]
+    fn test_explain_reachable_chain() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("a.ts"), "import './b';").unwrap();
+        fs::write(temp.path().join("b.ts"), "import './c';").unwrap();
+        fs::write(temp.path().join("c.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["a.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let chain = explain_reachable(config.clone(), Path::new("c.ts")).unwrap();
+        assert_eq!(
+            chain,
+            Some(vec![PathBuf::from("a.ts"), PathBuf::from("b.ts"), PathBuf::from("c.ts")])
+        );
+
+        let chain = explain_reachable(config, Path::new("orphan.ts")).unwrap();
+        assert_eq!(chain, None);
+    }
+
+    #[test]
+    fn test_report_classes_src_excludes_orphan_tests() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan.test.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            report_classes: vec![FileClass::Source],
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("orphan.ts"));
+    }
+
+    #[test]
+    fn test_progress_events() {
+        use std::sync::Mutex;
+
+        let temp = tempdir().unwrap();
+        // A chain produces one traversal frontier per file
+        fs::write(temp.path().join("index.ts"), "import './a';").unwrap();
+        fs::write(temp.path().join("a.ts"), "import './b';").unwrap();
+        fs::write(temp.path().join("b.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            progress: Some(ProgressHandler::new(move |event| recorded.lock().unwrap().push(event))),
+            ..Default::default()
+        };
+
+        find_unused_files(config).unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.first(), Some(&ProgressEvent::FilesCollected(4)));
+        assert_eq!(events.last(), Some(&ProgressEvent::Done));
+
+        let parsed: Vec<usize> = events
+            .iter()
+            .filter_map(|event| match event {
+                ProgressEvent::Parsed(done, total) => {
+                    assert_eq!(*total, 4);
+                    Some(*done)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(parsed, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cancel_during_traversal() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "import './a';").unwrap();
+        fs::write(temp.path().join("a.ts"), "import './b';").unwrap();
+        fs::write(temp.path().join("b.ts"), "export {};").unwrap();
+
+        // Flip the flag once the first frontier has been traversed
+        let cancel = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&cancel);
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            progress: Some(ProgressHandler::new(move |event| {
+                if let ProgressEvent::Parsed(..) = event {
+                    flag.store(true, Ordering::Relaxed);
+                }
+            })),
+            cancel: Some(Arc::clone(&cancel)),
+            ..Default::default()
+        };
+
+        assert!(matches!(find_unused_files(config.clone()), Err(MuriError::Cancelled)));
+
+        // Already cancelled before collection
+        let config = MuriConfig { progress: None, ..config };
+        assert!(matches!(find_reachable_files(config), Err(MuriError::Cancelled)));
+    }
+
+    #[test]
+    fn test_css_imports_are_traced() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "import './app.css';").unwrap();
+        fs::write(temp.path().join("app.css"), "@import 'base.css';").unwrap();
+        fs::write(temp.path().join("base.css"), ".a { background: url(./icon.svg); }").unwrap();
+        fs::write(temp.path().join("icon.svg"), "<svg />").unwrap();
+        fs::write(temp.path().join("orphan.css"), "").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("base.css")));
+        assert!(reachable.iter().any(|p| p.ends_with("icon.svg")));
+        assert!(!reachable.iter().any(|p| p.ends_with("orphan.css")));
+    }
+
+    #[test]
+    fn test_css_named_in_postcss_config_is_traced() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "devDependencies": { "postcss": "^8.0.0", "postcss-import": "^16.0.0" } }"#,
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("postcss.config.js"),
+            "module.exports = { from: 'styles/main.css', plugins: [require('postcss-import')] };",
+        )
+        .unwrap();
+        fs::write(temp.path().join("index.ts"), "").unwrap();
+        fs::create_dir(temp.path().join("styles")).unwrap();
+        fs::write(temp.path().join("styles/main.css"), "@import './base.css';").unwrap();
+        fs::write(temp.path().join("styles/base.css"), "").unwrap();
+        fs::write(temp.path().join("styles/orphan.css"), "").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        assert!(reachable.iter().any(|p| p.ends_with("styles/main.css")));
+        assert!(reachable.iter().any(|p| p.ends_with("styles/base.css")));
+        assert!(!reachable.iter().any(|p| p.ends_with("styles/orphan.css")));
+    }
+
+    #[test]
+    fn test_stylus_imports_are_traced() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("package.json"), r#"{"devDependencies":{"stylus":"^0.63"}}"#)
+            .unwrap();
+        fs::write(temp.path().join("index.ts"), "import './app.styl';").unwrap();
+        fs::write(temp.path().join("app.styl"), "@import 'mixins/*'\n@require variables\n")
+            .unwrap();
+        fs::write(temp.path().join("variables.styl"), "primary = #333").unwrap();
+        fs::create_dir(temp.path().join("mixins")).unwrap();
+        fs::write(temp.path().join("mixins/buttons.styl"), "").unwrap();
+        fs::write(temp.path().join("mixins/grid.styl"), "").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let reachable = find_reachable_files(config).unwrap();
+        for file in ["variables.styl", "mixins/buttons.styl", "mixins/grid.styl"] {
+            assert!(reachable.iter().any(|p| p.ends_with(file)), "{file} not reachable");
+        }
+    }
+
+    #[test]
+    fn test_nodenext_js_imports_reach_ts_sources() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("tsconfig.json"),
+            r#"{"compilerOptions":{"module":"NodeNext","moduleResolution":"NodeNext"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("index.ts"),
+            "import './a.js';\nimport './b.mjs';\nconst c = require('./c.cjs');",
+        )
+        .unwrap();
+        fs::write(temp.path().join("a.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("b.mts"), "export {};").unwrap();
+        fs::write(temp.path().join("c.cts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            project: vec!["**/*.{ts,mts,cts}".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert!(report.unused_files.is_empty(), "{:?}", report.unused_files);
+        assert_eq!(report.total_files, 4);
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_an_error() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "").unwrap();
+
+        for (field, pattern) in [("entry", "src/[abc"), ("project", "src/{a,b"), ("ignore", "!x/[")]
+        {
+            let mut config = MuriConfig {
+                entry: vec!["index.ts".to_string()],
+                cwd: temp.path().to_path_buf(),
+                ..Default::default()
+            };
+            match field {
+                "entry" => config.entry.push(pattern.to_string()),
+                "project" => config.project = vec![pattern.to_string()],
+                _ => config.ignore = vec![pattern.to_string()],
+            }
+
+            match find_unused_files(config) {
+                Err(MuriError::InvalidPattern(p)) => assert_eq!(p, pattern),
+                other => panic!("{field}: expected InvalidPattern, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_report_fields() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "import './used';").unwrap();
+        fs::write(temp.path().join("used.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let json = find_unused_files(config).unwrap().to_json_full();
+        let object = json.as_object().unwrap();
+        let mut keys: Vec<&str> = object.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            vec![
+                "durationMs",
+                "entryCount",
+                "reachableFiles",
+                "schemaVersion",
+                "totalFiles",
+                "unusedCount",
+                "unusedFiles",
+            ]
+        );
+
+        assert_eq!(json["schemaVersion"], FULL_REPORT_SCHEMA_VERSION);
+        assert_eq!(json["totalFiles"], 3);
+        assert_eq!(json["reachableFiles"], 2);
+        assert_eq!(json["unusedCount"], 1);
+        assert_eq!(json["entryCount"], 1);
+        assert_eq!(json["unusedFiles"].as_array().unwrap().len(), 1);
+        assert!(json["unusedFiles"][0].as_str().unwrap().ends_with("orphan.ts"));
+        assert!(json["durationMs"].is_u64());
+    }
+
+    #[test]
+    fn test_json_report_fields() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("index.ts"), "import './used';").unwrap();
+        fs::write(temp.path().join("used.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&reporter::json_report(&report, temp.path(), false)).unwrap();
+        assert_eq!(json["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(json["total_files"], 3);
+        assert_eq!(json["reachable_count"], 2);
+        assert_eq!(json["unused_count"], 1);
+        assert_eq!(json["entry_count"], 1);
+        assert_eq!(
+            json["unused_files"],
+            serde_json::json!([{ "path": "orphan.ts", "bytes": "export {};".len() }])
+        );
+        assert_eq!(json["total_unused_bytes"], "export {};".len());
+        assert!(json["duration_ms"].is_u64());
+    }
+
+    #[test]
+    fn test_unused_exports() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("index.ts"),
+            "import { used } from './utils';\nimport { shared } from './barrel';\nexport const api = 1;",
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("utils.ts"),
+            "export const used = 1;\nexport function unusedHelper() {}",
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("barrel.ts"),
+            "export { shared } from './shared';\nexport * from './star';",
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("shared.ts"),
+            "export const shared = 1;\nexport const other = 2;",
+        )
+        .unwrap();
+        fs::write(temp.path().join("star.ts"), "export const starred = 1;").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let unused = find_unused_exports(config.clone()).unwrap();
+        let names: Vec<_> = unused
+            .iter()
+            .map(|e| (e.file.file_name().unwrap().to_str().unwrap(), e.name.as_str()))
+            .collect();
+        // Entry exports (`api`) and names reached via `export *` are treated as used
+        assert_eq!(names, vec![("shared.ts", "other"), ("utils.ts", "unusedHelper")]);
+
+        let report = find_unused_files(MuriConfig { unused_exports: true, ..config }).unwrap();
+        assert_eq!(report.unused_exports, unused);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_workspace_cross_package_reachability() {
+        let temp = tempdir().unwrap();
+        let root = temp.path();
+        fs::write(root.join("package.json"), r#"{ "workspaces": ["packages/*"] }"#).unwrap();
+
+        fs::create_dir_all(root.join("packages/app")).unwrap();
+        fs::write(root.join("packages/app/package.json"), r#"{ "name": "app" }"#).unwrap();
+        fs::write(root.join("packages/app/index.ts"), "import { util } from 'lib/util';").unwrap();
+        fs::write(root.join("packages/app/orphan.ts"), "export {};").unwrap();
+
+        fs::create_dir_all(root.join("packages/lib")).unwrap();
+        fs::write(root.join("packages/lib/package.json"), r#"{ "name": "lib" }"#).unwrap();
+        fs::write(root.join("packages/lib/index.ts"), "export {};").unwrap();
+        fs::write(root.join("packages/lib/util.ts"), "export const util = 1;").unwrap();
+        fs::write(root.join("packages/lib/dead.ts"), "export {};").unwrap();
+
+        // Package managers link workspace packages into the root node_modules
+        fs::create_dir_all(root.join("node_modules")).unwrap();
+        std::os::unix::fs::symlink(root.join("packages/lib"), root.join("node_modules/lib"))
+            .unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: root.to_path_buf(),
+            ..Default::default()
+        };
+
+        let reports = find_unused_files_workspace(config).unwrap();
+        assert_eq!(
+            reports.keys().collect::<Vec<_>>(),
+            vec![Path::new("packages/app"), Path::new("packages/lib")]
+        );
+
+        let app = &reports[Path::new("packages/app")];
+        assert_eq!(app.unused_count, 1);
+        assert!(app.unused_files[0].ends_with("orphan.ts"));
+
+        // util.ts is only imported from app, but is still reachable
+        let lib = &reports[Path::new("packages/lib")];
+        assert_eq!(lib.unused_count, 1);
+        assert!(lib.unused_files[0].ends_with("dead.ts"));
+        assert_eq!(lib.reachable_count, 2);
+    }
+
+    #[test]
+    fn test_workspace_reports_unused_exports_and_active_plugins() {
+        let temp = tempdir().unwrap();
+        let root = temp.path();
+        fs::write(root.join("package.json"), r#"{ "workspaces": ["packages/*"] }"#).unwrap();
+
+        fs::create_dir_all(root.join("packages/app")).unwrap();
+        fs::write(
+            root.join("packages/app/package.json"),
+            r#"{ "name": "app", "devDependencies": { "jest": "^29" } }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("packages/app/index.ts"),
+            "import { helper } from './helper';\nimport { used } from 'lib/util';",
+        )
+        .unwrap();
+        fs::write(
+            root.join("packages/app/helper.ts"),
+            "export const helper = 1;\nexport const extra = 2;",
+        )
+        .unwrap();
+
+        fs::create_dir_all(root.join("packages/lib")).unwrap();
+        fs::write(root.join("packages/lib/package.json"), r#"{ "name": "lib" }"#).unwrap();
+        fs::write(root.join("packages/lib/index.ts"), "export {};").unwrap();
+        // `used` is only imported from app, which still counts
+        fs::write(
+            root.join("packages/lib/util.ts"),
+            "export const used = 1;\nexport const unused = 2;",
+        )
+        .unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: root.to_path_buf(),
+            unused_exports: true,
+            verbose: true,
+            ..Default::default()
+        };
+
+        let reports = find_unused_files_workspace(config).unwrap();
+        let names = |report: &Report| -> Vec<String> {
+            report.unused_exports.iter().map(|export| export.name.clone()).collect()
+        };
+
+        let app = &reports[Path::new("packages/app")];
+        assert_eq!(names(app), vec!["extra"]);
+        assert!(app.unused_exports[0].file.ends_with("packages/app/helper.ts"));
+        assert!(app.active_plugins.contains(&"jest".to_string()));
+
+        let lib = &reports[Path::new("packages/lib")];
+        assert_eq!(names(lib), vec!["unused"]);
+        assert!(lib.unused_exports[0].file.ends_with("packages/lib/util.ts"));
+        assert!(!lib.active_plugins.contains(&"jest".to_string()));
+    }
+
+    #[test]
+    fn test_workspace_package_import_without_node_modules() {
+        let temp = tempdir().unwrap();
+        let root = temp.path();
+        fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - 'packages/*'\n").unwrap();
+
+        fs::create_dir_all(root.join("packages/app/src")).unwrap();
+        fs::write(root.join("packages/app/package.json"), r#"{ "name": "@acme/app" }"#).unwrap();
+        fs::write(root.join("packages/app/src/index.ts"), "import { pad } from '@acme/utils';")
+            .unwrap();
+
+        fs::create_dir_all(root.join("packages/utils/src")).unwrap();
+        fs::write(
+            root.join("packages/utils/package.json"),
+            r#"{ "name": "@acme/utils", "main": "src/index.ts" }"#,
+        )
+        .unwrap();
+        fs::write(root.join("packages/utils/src/index.ts"), "export * from './pad';").unwrap();
+        fs::write(root.join("packages/utils/src/pad.ts"), "export const pad = 1;").unwrap();
+        fs::write(root.join("packages/utils/src/dead.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["src/index.ts".to_string()],
+            cwd: root.to_path_buf(),
+            ..Default::default()
+        };
+
+        let reports = find_unused_files_workspace(config).unwrap();
+        assert_eq!(reports[Path::new("packages/app")].unused_count, 0);
+        let utils = &reports[Path::new("packages/utils")];
+        assert_eq!(utils.unused_count, 1);
+        assert!(utils.unused_files[0].ends_with("src/dead.ts"));
+    }
+
+    #[test]
+    fn test_jest_mocked_module_is_reachable() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("app.test.ts"), "jest.mock('./service');").unwrap();
+        fs::write(temp.path().join("service.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["app.test.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("orphan.ts"));
+    }
+
+    #[test]
+    fn test_jest_module_name_mapper_is_applied() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("src/utils")).unwrap();
+        fs::write(temp.path().join("package.json"), r#"{ "devDependencies": { "jest": "^29" } }"#)
+            .unwrap();
+        fs::write(
+            temp.path().join("jest.config.js"),
+            "module.exports = { moduleNameMapper: { '^@/(.*)$': '<rootDir>/src/$1' } };",
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("src/format.test.ts"),
+            "import { format } from '@/utils/format';",
+        )
+        .unwrap();
+        fs::write(temp.path().join("src/utils/format.ts"), "export const format = 1;").unwrap();
+        fs::write(temp.path().join("src/orphan.ts"), "export {};").unwrap();
+
+        let config = MuriConfig { cwd: temp.path().to_path_buf(), ..Default::default() };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("src/orphan.ts"));
+    }
+
+    #[test]
+    fn test_jest_roots_discover_tests_under_packages() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("packages/core/__tests__")).unwrap();
+        fs::create_dir_all(temp.path().join("scripts/__tests__")).unwrap();
+        fs::write(temp.path().join("package.json"), r#"{ "devDependencies": { "jest": "^29" } }"#)
+            .unwrap();
+        fs::write(
+            temp.path().join("jest.config.js"),
+            "module.exports = { roots: ['<rootDir>/packages'] };",
+        )
+        .unwrap();
+        fs::write(temp.path().join("packages/core/__tests__/sum.ts"), "import '../sum';").unwrap();
+        fs::write(temp.path().join("packages/core/sum.ts"), "export {};").unwrap();
+        // Outside `roots`, so Jest never runs it
+        fs::write(temp.path().join("scripts/__tests__/build.ts"), "export {};").unwrap();
+
+        let config = MuriConfig { cwd: temp.path().to_path_buf(), ..Default::default() };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("scripts/__tests__/build.ts"));
+    }
+
+    #[test]
+    fn test_unused_iter_matches_report() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("src/legacy")).unwrap();
+        fs::write(temp.path().join("src/index.ts"), "import './used';").unwrap();
+        fs::write(temp.path().join("src/used.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("src/orphan.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("src/orphan.test.ts"), "export {};").unwrap();
+        fs::write(temp.path().join("src/legacy/old.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["src/index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let filtered = MuriConfig {
+            report_classes: vec![FileClass::Source],
+            allowlist: vec!["src/legacy/**".to_string()],
+            ..config.clone()
+        };
+
+        for config in [config, filtered] {
+            let report = find_unused_files(config.clone()).unwrap();
+            let mut streamed: Vec<_> = find_unused_iter(config).unwrap().collect();
+            streamed.sort();
+            assert_eq!(streamed, report.unused_files);
+        }
+    }
+
+    #[test]
+    fn test_feature_gated_requires_are_reachable() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("index.js"),
+            r#"if (process.env.FEATURE) require("./feature");
+process.env.DEBUG && require("./debug");
+switch (process.env.TARGET) { case "web": require("./web"); }"#,
+        )
+        .unwrap();
+        for file in ["feature.js", "debug.js", "web.js", "orphan.js"] {
+            fs::write(temp.path().join(file), "module.exports = {};").unwrap();
+        }
+
+        let config = MuriConfig {
+            entry: vec!["index.js".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("orphan.js"));
+    }
+
+    #[test]
+    fn test_eslint_flat_config_shared_configs_are_reachable() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("config")).unwrap();
+        fs::write(temp.path().join("package.json"), r#"{ "devDependencies": { "eslint": "^9" } }"#)
+            .unwrap();
+        fs::write(
+            temp.path().join("eslint.config.mjs"),
+            r#"import baseConfig from "./base.mjs";
+const { default: react } = await import("./config/react.mjs");
+export default [...baseConfig, ...react, { rules: { "no-console": "warn" } }];"#,
+        )
+        .unwrap();
+        fs::write(
+            temp.path().join("base.mjs"),
+            "import { ignores } from './config/ignores.mjs';\nexport default [{ ignores }];",
+        )
+        .unwrap();
+        fs::write(temp.path().join("config/ignores.mjs"), "export const ignores = [];").unwrap();
+        fs::write(temp.path().join("config/react.mjs"), "export default [];").unwrap();
+        fs::write(temp.path().join("config/unused.mjs"), "export default [];").unwrap();
+
+        let config = MuriConfig { cwd: temp.path().to_path_buf(), ..Default::default() };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("config/unused.mjs"));
+    }
+
+    #[test]
+    fn test_commonjs_barrel_re_exports_are_reachable() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("lib")).unwrap();
+        fs::write(temp.path().join("index.js"), "module.exports = require('./lib');").unwrap();
+        fs::write(
+            temp.path().join("lib/index.js"),
+            r#"Object.assign(module.exports, require("./a"));
+Object.assign(exports, { ...require("./b") });
+module.exports = { ...require("./c") };"#,
+        )
+        .unwrap();
+        for name in ["a", "b", "c", "unused"] {
+            fs::write(temp.path().join(format!("lib/{name}.js")), "exports.x = 1;").unwrap();
+        }
+
+        let config = MuriConfig {
+            entry: vec!["index.js".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("lib/unused.js"));
+    }
+
+    #[test]
+    fn test_verbose_report_lists_active_plugins() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("package.json"), r#"{ "devDependencies": { "jest": "^29" } }"#)
+            .unwrap();
+        fs::write(temp.path().join("index.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let report = find_unused_files(config.clone()).unwrap();
+        assert!(report.active_plugins.is_empty());
+
+        let report = find_unused_files(MuriConfig { verbose: true, ..config }).unwrap();
+        assert_eq!(report.active_plugins, vec!["jest"]);
+    }
+
+    #[test]
+    fn test_root_jest_enables_plugin_in_sub_package() {
+        let temp = tempdir().unwrap();
+        let app = temp.path().join("packages/app");
+        fs::create_dir_all(app.join("src")).unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "workspaces": ["packages/*"], "devDependencies": { "jest": "^29" } }"#,
+        )
+        .unwrap();
+        fs::write(app.join("package.json"), r#"{ "name": "app" }"#).unwrap();
+        fs::write(app.join("src/sum.test.ts"), "import { sum } from './sum';").unwrap();
+        fs::write(app.join("src/sum.ts"), "export const sum = 1;").unwrap();
+        fs::write(app.join("src/orphan.ts"), "export {};").unwrap();
+
+        // No entry points: the test files come from the Jest plugin alone
+        let config = MuriConfig { cwd: app, ..Default::default() };
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("src/orphan.ts"));
+    }
+
+    #[test]
+    fn test_template_import_marks_matching_files_reachable() {
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("locales")).unwrap();
+        fs::write(
+            temp.path().join("index.ts"),
+            "export const load = (lang: string) => import(`./locales/${lang}.js`);",
+        )
+        .unwrap();
+        fs::write(temp.path().join("locales/en.ts"), "export default {};").unwrap();
+        fs::write(temp.path().join("locales/fr.ts"), "export default {};").unwrap();
+        fs::write(temp.path().join("orphan.ts"), "export {};").unwrap();
+
+        let config = MuriConfig {
+            entry: vec!["index.ts".to_string()],
+            cwd: temp.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let report = find_unused_files(config).unwrap();
+        assert_eq!(report.unused_count, 1);
+        assert!(report.unused_files[0].ends_with("orphan.ts"));
+    }
 }
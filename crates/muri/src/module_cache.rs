@@ -1,6 +1,16 @@
+use crate::compiler::CompilerRegistry;
 use crate::parser::{ImportInfo, ImportKind, ParseError, extract_imports};
 use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::UNIX_EPOCH;
+
+/// File name of the persisted cache inside the cache directory
+const CACHE_FILE_NAME: &str = "modules.json";
 
 /// Information about a parsed module, stored in the cache.
 /// This struct is extensible for future features (unused exports, etc.)
@@ -17,7 +27,8 @@ pub struct ModuleInfo {
 impl ModuleInfo {
     /// Create a ModuleInfo from successfully parsed imports
     pub fn from_imports(imports: Vec<ImportInfo>) -> Self {
-        let has_dynamic_imports = imports.iter().any(|i| i.kind == ImportKind::Dynamic);
+        let has_dynamic_imports =
+            imports.iter().any(|i| matches!(i.kind, ImportKind::Dynamic | ImportKind::DynamicGlob));
         Self { imports, has_dynamic_imports, parse_error: None }
     }
 
@@ -31,15 +42,131 @@ impl ModuleInfo {
     }
 }
 
+/// Modification time and size of a file, used to detect changes between runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct FileStamp {
+    mtime_secs: u64,
+    mtime_nanos: u32,
+    size: u64,
+}
+
+impl FileStamp {
+    /// Read the stamp of a file, or `None` if it can't be stat-ed
+    fn of(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        let mtime = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        Some(Self {
+            mtime_secs: mtime.as_secs(),
+            mtime_nanos: mtime.subsec_nanos(),
+            size: metadata.len(),
+        })
+    }
+}
+
+/// A module's imports as stored on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PersistedModule {
+    #[serde(flatten)]
+    stamp: FileStamp,
+    imports: Vec<ImportInfo>,
+}
+
+/// On-disk cache file layout
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedCache {
+    /// muri version that wrote the cache; other versions discard it
+    version: String,
+    modules: BTreeMap<PathBuf, PersistedModule>,
+}
+
 /// Thread-safe cache for parsed module information.
 /// Allows reusing parse results across the analysis and for future extensions.
+///
+/// Results can be persisted to disk with [`ModuleCache::persist_to`] and loaded
+/// on the next run with [`ModuleCache::load_from`]; loaded entries are only
+/// used while the file's mtime and size are unchanged.
 pub struct ModuleCache {
     cache: DashMap<PathBuf, ModuleInfo>,
+    compilers: Arc<CompilerRegistry>,
+    /// Entries loaded from disk, consulted before parsing
+    persisted: DashMap<PathBuf, PersistedModule>,
+    /// Stamps of files parsed or served from disk during this run
+    stamps: DashMap<PathBuf, FileStamp>,
+    /// Number of files actually parsed (i.e. not served from any cache)
+    parse_count: AtomicUsize,
 }
 
 impl ModuleCache {
     pub fn new() -> Self {
-        Self { cache: DashMap::new() }
+        Self::with_compilers(Arc::new(CompilerRegistry::new()))
+    }
+
+    /// Create a cache that uses the given compilers for non-JS/TS files
+    pub fn with_compilers(compilers: Arc<CompilerRegistry>) -> Self {
+        Self {
+            cache: DashMap::new(),
+            compilers,
+            persisted: DashMap::new(),
+            stamps: DashMap::new(),
+            parse_count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Load entries persisted by a previous run from `cache_dir`.
+    ///
+    /// A missing, unreadable, or outdated cache file is ignored, so every file is parsed.
+    pub fn load_from(&self, cache_dir: &Path) {
+        let Ok(content) = fs::read_to_string(cache_dir.join(CACHE_FILE_NAME)) else {
+            return;
+        };
+        let Ok(cache) = serde_json::from_str::<PersistedCache>(&content) else {
+            return;
+        };
+        if cache.version != env!("CARGO_PKG_VERSION") {
+            return;
+        }
+
+        for (path, module) in cache.modules {
+            self.persisted.insert(path, module);
+        }
+    }
+
+    /// Write successfully parsed entries to `cache_dir`, creating it if needed.
+    ///
+    /// Entries loaded from disk but not used during this run are kept.
+    pub fn persist_to(&self, cache_dir: &Path) -> std::io::Result<()> {
+        let mut modules: BTreeMap<PathBuf, PersistedModule> =
+            self.persisted.iter().map(|e| (e.key().clone(), e.value().clone())).collect();
+
+        for entry in self.cache.iter() {
+            let (path, info) = (entry.key(), entry.value());
+            if info.parse_error.is_some() {
+                modules.remove(path);
+                continue;
+            }
+            if let Some(stamp) = self.stamps.get(path) {
+                modules.insert(
+                    path.clone(),
+                    PersistedModule { stamp: *stamp, imports: info.imports.clone() },
+                );
+            }
+        }
+
+        let cache = PersistedCache { version: env!("CARGO_PKG_VERSION").to_string(), modules };
+        let content = serde_json::to_string(&cache).map_err(std::io::Error::other)?;
+
+        fs::create_dir_all(cache_dir)?;
+        fs::write(cache_dir.join(CACHE_FILE_NAME), content)
+    }
+
+    /// Number of files parsed so far (excludes files served from memory or disk)
+    pub fn parse_count(&self) -> usize {
+        self.parse_count.load(Ordering::Relaxed)
+    }
+
+    /// Check if the file is handled by a registered compiler
+    pub fn has_compiler(&self, path: &Path) -> bool {
+        self.compilers.handles(path)
     }
 
     /// Get or compute the ModuleInfo for a file
@@ -49,8 +176,24 @@ impl ModuleCache {
             return info.clone();
         }
 
-        // Parse the file
-        let info = match extract_imports(path) {
+        // Serve unchanged files from the on-disk cache
+        let stamp = FileStamp::of(path);
+        if let Some(stamp) = stamp {
+            self.stamps.insert(path.to_path_buf(), stamp);
+            if let Some(persisted) = self.persisted.get(path).filter(|p| p.stamp == stamp) {
+                let info = ModuleInfo::from_imports(persisted.imports.clone());
+                self.cache.insert(path.to_path_buf(), info.clone());
+                return info;
+            }
+        }
+
+        // Parse the file, using a compiler for non-JS/TS files
+        self.parse_count.fetch_add(1, Ordering::Relaxed);
+        let result = match self.compilers.compile(path) {
+            Some(compiled) => compiled.map(|output| output.imports),
+            None => extract_imports(path),
+        };
+        let info = match result {
             Ok(imports) => ModuleInfo::from_imports(imports),
             Err(e) => ModuleInfo::from_error(e),
         };
@@ -60,6 +203,13 @@ impl ModuleCache {
         info
     }
 
+    /// Forget everything cached for a file so it is reparsed on next access
+    pub fn invalidate(&self, path: &Path) {
+        self.cache.remove(path);
+        self.persisted.remove(path);
+        self.stamps.remove(path);
+    }
+
     /// Get cached info without parsing (returns None if not cached)
     pub fn get(&self, path: &Path) -> Option<ModuleInfo> {
         self.cache.get(path).map(|r| r.clone())
@@ -81,3 +231,78 @@ impl Default for ModuleCache {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_unchanged_files_are_served_from_disk_cache() {
+        let temp = tempdir().unwrap();
+        let cache_dir = temp.path().join(".muri-cache");
+        let a = temp.path().join("a.ts");
+        let b = temp.path().join("b.ts");
+        fs::write(&a, "import './b';").unwrap();
+        fs::write(&b, "export {};").unwrap();
+
+        let cache = ModuleCache::new();
+        cache.get_or_parse(&a);
+        cache.get_or_parse(&b);
+        assert_eq!(cache.parse_count(), 2);
+        cache.persist_to(&cache_dir).unwrap();
+
+        let cache = ModuleCache::new();
+        cache.load_from(&cache_dir);
+        let info = cache.get_or_parse(&a);
+        cache.get_or_parse(&b);
+        assert_eq!(cache.parse_count(), 0);
+        assert_eq!(info.imports.len(), 1);
+        assert_eq!(info.imports[0].source, "./b");
+    }
+
+    #[test]
+    fn test_modified_file_invalidates_only_its_entry() {
+        let temp = tempdir().unwrap();
+        let cache_dir = temp.path().join(".muri-cache");
+        let a = temp.path().join("a.ts");
+        let b = temp.path().join("b.ts");
+        fs::write(&a, "import './b';").unwrap();
+        fs::write(&b, "export {};").unwrap();
+
+        let cache = ModuleCache::new();
+        cache.get_or_parse(&a);
+        cache.get_or_parse(&b);
+        cache.persist_to(&cache_dir).unwrap();
+
+        // Different size guarantees a changed stamp even on coarse-mtime filesystems
+        fs::write(&b, "import './c';\nimport './d';").unwrap();
+
+        let cache = ModuleCache::new();
+        cache.load_from(&cache_dir);
+        cache.get_or_parse(&a);
+        let info = cache.get_or_parse(&b);
+        assert_eq!(cache.parse_count(), 1);
+        assert_eq!(info.imports.len(), 2);
+    }
+
+    #[test]
+    fn test_missing_or_corrupt_cache_is_ignored() {
+        let temp = tempdir().unwrap();
+        let cache_dir = temp.path().join(".muri-cache");
+        let a = temp.path().join("a.ts");
+        fs::write(&a, "export {};").unwrap();
+
+        let cache = ModuleCache::new();
+        cache.load_from(&cache_dir);
+        cache.get_or_parse(&a);
+        assert_eq!(cache.parse_count(), 1);
+
+        fs::create_dir_all(&cache_dir).unwrap();
+        fs::write(cache_dir.join(CACHE_FILE_NAME), "not json").unwrap();
+        let cache = ModuleCache::new();
+        cache.load_from(&cache_dir);
+        cache.get_or_parse(&a);
+        assert_eq!(cache.parse_count(), 1);
+    }
+}
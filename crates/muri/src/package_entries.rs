@@ -0,0 +1,158 @@
+use crate::types::DEFAULT_EXTENSIONS;
+use std::path::{Path, PathBuf};
+
+/// package.json fields naming a single file of the package
+const FILE_FIELDS: &[&str] = &["main", "module", "types", "typings"];
+
+/// Entry points a package declares in its package.json: `main`, `module`, `types`,
+/// every target of the `exports` map (across all conditions), and each `bin` script.
+///
+/// Targets are resolved against `cwd`, trying the usual extensions when one is
+/// missing (`"main": "index"`). Targets that don't exist (e.g. unbuilt `dist/`
+/// output), subpath patterns (`"./*": "./src/*.js"`), and JSON files are skipped. Returns
+/// canonical paths, sorted and deduplicated.
+pub fn package_entries(cwd: &Path) -> Vec<PathBuf> {
+    let Ok(content) = std::fs::read_to_string(cwd.join("package.json")) else {
+        return Vec::new();
+    };
+    let Ok(pkg) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+
+    let mut targets: Vec<&str> =
+        FILE_FIELDS.iter().filter_map(|field| pkg.get(*field)?.as_str()).collect();
+    if let Some(exports) = pkg.get("exports") {
+        export_targets(exports, &mut targets);
+    }
+    match pkg.get("bin") {
+        Some(serde_json::Value::String(bin)) => targets.push(bin),
+        Some(serde_json::Value::Object(bins)) => {
+            targets.extend(bins.values().filter_map(|bin| bin.as_str()));
+        }
+        _ => {}
+    }
+
+    let mut entries: Vec<PathBuf> = targets
+        .into_iter()
+        .filter(|target| !target.contains('*') && !target.ends_with(".json"))
+        .filter_map(|target| resolve_target(cwd, target))
+        .collect();
+    entries.sort();
+    entries.dedup();
+    entries
+}
+
+/// Collect the file targets of an `exports` value: a string, an array of
+/// fallbacks, or an object of subpaths (`"./utils"`) or conditions (`"import"`)
+/// nested to any depth
+fn export_targets<'a>(value: &'a serde_json::Value, targets: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::String(target) => targets.push(target),
+        serde_json::Value::Array(fallbacks) => {
+            fallbacks.iter().for_each(|fallback| export_targets(fallback, targets));
+        }
+        serde_json::Value::Object(map) => {
+            map.values().for_each(|nested| export_targets(nested, targets));
+        }
+        _ => {}
+    }
+}
+
+/// Resolve a package.json target to an existing file, trying common extensions
+/// and `index` files when the target has no extension or names a directory
+fn resolve_target(cwd: &Path, target: &str) -> Option<PathBuf> {
+    let path = cwd.join(target);
+    std::iter::once(path.clone())
+        .chain(
+            DEFAULT_EXTENSIONS.iter().map(|ext| PathBuf::from(format!("{}{ext}", path.display()))),
+        )
+        .chain(DEFAULT_EXTENSIONS.iter().map(|ext| path.join(format!("index{ext}"))))
+        .find(|candidate| candidate.is_file())
+        .and_then(|candidate| candidate.canonicalize().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    fn relative(entries: &[PathBuf], root: &Path) -> Vec<String> {
+        let root = root.canonicalize().unwrap();
+        entries
+            .iter()
+            .map(|p| p.strip_prefix(&root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect()
+    }
+
+    #[test]
+    fn test_library_exports_map() {
+        let temp = tempdir().unwrap();
+        write_files(
+            temp.path(),
+            &["src/index.ts", "src/index.cjs", "src/utils/index.ts", "src/server.ts", "types.d.ts"],
+        );
+        let pkg = r#"{
+  "name": "lib",
+  "main": "./src/index.cjs",
+  "module": "./src/index.ts",
+  "types": "./types.d.ts",
+  "exports": {
+    ".": { "import": "./src/index.ts", "require": "./src/index.cjs" },
+    "./utils": "./src/utils",
+    "./server": { "node": { "default": ["./src/server.ts", "./dist/server.js"] } },
+    "./features/*": "./src/features/*.ts",
+    "./internal": null,
+    "./package.json": "./package.json"
+  }
+}"#;
+        fs::write(temp.path().join("package.json"), pkg).unwrap();
+
+        assert_eq!(
+            relative(&package_entries(temp.path()), temp.path()),
+            vec![
+                "src/index.cjs",
+                "src/index.ts",
+                "src/server.ts",
+                "src/utils/index.ts",
+                "types.d.ts",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cli_bin_scripts() {
+        let temp = tempdir().unwrap();
+        write_files(temp.path(), &["bin/cli.js", "bin/migrate.mjs"]);
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "name": "tool", "bin": { "tool": "bin/cli.js", "tool-migrate": "./bin/migrate" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            relative(&package_entries(temp.path()), temp.path()),
+            vec!["bin/cli.js", "bin/migrate.mjs"]
+        );
+
+        fs::write(temp.path().join("package.json"), r#"{ "bin": "./bin/cli.js" }"#).unwrap();
+        assert_eq!(relative(&package_entries(temp.path()), temp.path()), vec!["bin/cli.js"]);
+    }
+
+    #[test]
+    fn test_missing_targets_and_package_json() {
+        let temp = tempdir().unwrap();
+        assert!(package_entries(temp.path()).is_empty());
+
+        fs::write(temp.path().join("package.json"), r#"{ "main": "dist/index.js" }"#).unwrap();
+        assert!(package_entries(temp.path()).is_empty());
+    }
+}
@@ -1,16 +1,25 @@
 use oxc_allocator::Allocator;
-use oxc_ast::ast::{Argument, Expression, Statement};
+use oxc_ast::ast::{
+    Argument, ArrayExpressionElement, Class, Declaration, ExportDefaultDeclarationKind, Expression,
+    ForStatementInit, ImportDeclaration, ImportDeclarationSpecifier, Statement,
+    TSModuleDeclaration, TSModuleDeclarationBody, TSModuleDeclarationName, TSModuleReference,
+    TemplateLiteral,
+};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
 use std::path::Path;
+use std::sync::OnceLock;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportInfo {
     pub source: String,
     pub kind: ImportKind,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ImportKind {
     Static,
     Dynamic,
@@ -18,6 +27,21 @@ pub enum ImportKind {
     ExportFrom,
     ExportStar,
     SideEffect,
+    /// `import(`./locales/${lang}.js`)`: `source` is a glob (`./locales/*.js`)
+    /// matching every file the import could load
+    DynamicGlob,
+    /// Type-only `import type`/`export type ... from` (or one whose specifiers are
+    /// all `type`). The file is still required, but only by the type checker.
+    Type,
+    /// `/// <reference path="..." />` directive: `source` is the referenced file,
+    /// relative to the importing file
+    Reference,
+    /// `url('./bg.png')` inside a CSS-in-JS tagged template (`styled.div`...``,
+    /// `css`...``): `source` is the referenced asset, relative to the importing file
+    UrlAsset,
+    /// Sass `@use`/`@forward`/`@import` (in a stylesheet or a `<style lang="scss">`
+    /// block): `source` may name a partial (`_name.scss`) or a directory index
+    UseSass,
 }
 
 #[derive(Debug)]
@@ -34,12 +58,21 @@ impl From<std::io::Error> for ParseError {
 
 pub fn extract_imports(path: &Path) -> Result<Vec<ImportInfo>, ParseError> {
     let source = std::fs::read_to_string(path)?;
-    let allocator = Allocator::default();
     let source_type = SourceType::from_path(path).unwrap_or_default();
-    let parsed = Parser::new(&allocator, &source, source_type).parse();
+    extract_imports_from_source(&source, source_type)
+        .map_err(|_| ParseError::ParseFailed(format!("Parser panicked on {}", path.display())))
+}
+
+/// Extract imports from JS/TS source text (e.g. a `<script>` block inside a component file)
+pub fn extract_imports_from_source(
+    source: &str,
+    source_type: SourceType,
+) -> Result<Vec<ImportInfo>, ParseError> {
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, source, source_type).parse();
 
     if parsed.panicked {
-        return Err(ParseError::ParseFailed(format!("Parser panicked on {}", path.display())));
+        return Err(ParseError::ParseFailed("Parser panicked".to_string()));
     }
 
     let mut imports = Vec::new();
@@ -47,16 +80,230 @@ pub fn extract_imports(path: &Path) -> Result<Vec<ImportInfo>, ParseError> {
     for stmt in &parsed.program.body {
         extract_from_statement(stmt, &mut imports);
     }
+    extract_references(source, &mut imports);
 
     Ok(imports)
 }
 
+/// Extract `/// <reference path="..." />` directives, which pull another file (usually
+/// a `.d.ts`) into the compilation. `types` references name packages and are skipped.
+///
+/// TypeScript only honors directives in the comments before the first statement, so
+/// this scans the leading comment block (after an optional shebang) line by line and
+/// stops at the first line of code.
+fn extract_references(source: &str, imports: &mut Vec<ImportInfo>) {
+    let mut in_block_comment = false;
+
+    for (index, line) in source.lines().enumerate() {
+        let line = line.trim();
+        if in_block_comment {
+            in_block_comment = !line.contains("*/");
+            continue;
+        }
+        if line.is_empty() || (index == 0 && line.starts_with("#!")) {
+            continue;
+        }
+        if let Some(directive) = line.strip_prefix("///") {
+            if let Some(path) = reference_path(directive) {
+                // Reference paths are relative to the file, even without `./`
+                let source = if path.starts_with(['.', '/']) {
+                    path.to_string()
+                } else {
+                    format!("./{path}")
+                };
+                imports.push(ImportInfo { source, kind: ImportKind::Reference });
+            }
+        } else if let Some(comment) = line.strip_prefix("/*") {
+            in_block_comment = !comment.contains("*/");
+        } else if !line.starts_with("//") {
+            break;
+        }
+    }
+}
+
+/// The `path` attribute of a `<reference ... />` directive
+fn reference_path(directive: &str) -> Option<&str> {
+    let attributes = directive.trim_start().strip_prefix("<reference")?;
+    let (start, _) = attributes
+        .match_indices("path")
+        .find(|(i, _)| attributes[..*i].ends_with(char::is_whitespace))?;
+    let value = attributes[start + "path".len()..].trim_start().strip_prefix('=')?.trim_start();
+    let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let (path, _) = value[1..].split_once(quote)?;
+    (!path.is_empty()).then_some(path)
+}
+
+/// Which exports of an imported module are used
+#[derive(Debug, Clone, PartialEq)]
+pub enum UsedNames {
+    /// Any export may be used (namespace import, dynamic import, require, `export *`)
+    All,
+    /// Only these export names are used (`default` for default imports)
+    Names(Vec<String>),
+}
+
+impl UsedNames {
+    /// Combine names used by several imports of the same module
+    pub(crate) fn merge(&mut self, other: UsedNames) {
+        match (self, other) {
+            (UsedNames::Names(names), UsedNames::Names(other)) => names.extend(other),
+            (this, _) => *this = UsedNames::All,
+        }
+    }
+}
+
+/// An import specifier together with the export names used from it
+#[derive(Debug, Clone)]
+pub struct SymbolUse {
+    pub source: String,
+    pub kind: ImportKind,
+    pub names: UsedNames,
+}
+
+/// Exported names of a module and the names it uses from each import
+#[derive(Debug, Clone, Default)]
+pub struct ModuleSymbols {
+    /// Names exported by the module's own declarations (re-exports are not included)
+    pub exports: Vec<String>,
+    /// Every import of the module, with the export names it uses
+    pub uses: Vec<SymbolUse>,
+}
+
+pub fn extract_symbols(path: &Path) -> Result<ModuleSymbols, ParseError> {
+    let source = std::fs::read_to_string(path)?;
+    let source_type = SourceType::from_path(path).unwrap_or_default();
+    extract_symbols_from_source(&source, source_type)
+        .map_err(|_| ParseError::ParseFailed(format!("Parser panicked on {}", path.display())))
+}
+
+/// Extract exported names and per-import name usage from JS/TS source text.
+///
+/// Imports whose used names can't be determined statically (dynamic imports,
+/// `require`, `export *`, imports nested in namespaces) are reported as [`UsedNames::All`].
+pub fn extract_symbols_from_source(
+    source: &str,
+    source_type: SourceType,
+) -> Result<ModuleSymbols, ParseError> {
+    let allocator = Allocator::default();
+    let parsed = Parser::new(&allocator, source, source_type).parse();
+
+    if parsed.panicked {
+        return Err(ParseError::ParseFailed("Parser panicked".to_string()));
+    }
+
+    let mut imports = Vec::new();
+    let mut exports = Vec::new();
+    // Names used from top-level `import`/`export ... from` declarations, keyed by specifier
+    let mut named: FxHashMap<String, UsedNames> = FxHashMap::default();
+
+    for stmt in &parsed.program.body {
+        extract_from_statement(stmt, &mut imports);
+
+        match stmt {
+            Statement::ImportDeclaration(decl) => {
+                let mut names = UsedNames::Names(Vec::new());
+                for specifier in decl.specifiers.iter().flatten() {
+                    names.merge(match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(s) => {
+                            UsedNames::Names(vec![s.imported.name().to_string()])
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => {
+                            UsedNames::Names(vec!["default".to_string()])
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => UsedNames::All,
+                    });
+                }
+                add_used_names(&mut named, &decl.source.value, names);
+            }
+            Statement::ExportNamedDeclaration(decl) => match &decl.source {
+                // Re-exported names count as used in the source module
+                Some(source) => {
+                    let names = decl.specifiers.iter().map(|s| s.local.name().to_string());
+                    add_used_names(&mut named, &source.value, UsedNames::Names(names.collect()));
+                }
+                None => {
+                    exports.extend(decl.specifiers.iter().map(|s| s.exported.name().to_string()));
+                    if let Some(declaration) = &decl.declaration {
+                        exports.extend(declaration_names(declaration));
+                    }
+                }
+            },
+            Statement::ExportDefaultDeclaration(_) => exports.push("default".to_string()),
+            _ => {}
+        }
+    }
+
+    let uses = imports
+        .into_iter()
+        .map(|import| {
+            let names = match import.kind {
+                ImportKind::Static
+                | ImportKind::SideEffect
+                | ImportKind::ExportFrom
+                | ImportKind::Type => named.get(&import.source).cloned().unwrap_or(UsedNames::All),
+                _ => UsedNames::All,
+            };
+            SymbolUse { source: import.source, kind: import.kind, names }
+        })
+        .collect();
+
+    Ok(ModuleSymbols { exports, uses })
+}
+
+fn add_used_names(named: &mut FxHashMap<String, UsedNames>, source: &str, names: UsedNames) {
+    match named.get_mut(source) {
+        Some(existing) => existing.merge(names),
+        None => {
+            named.insert(source.to_string(), names);
+        }
+    }
+}
+
+/// Names bound by an exported declaration (`export const a = 1, b = 2` -> `a`, `b`)
+fn declaration_names(declaration: &Declaration) -> Vec<String> {
+    match declaration {
+        Declaration::VariableDeclaration(var_decl) => var_decl
+            .declarations
+            .iter()
+            .flat_map(|d| d.id.get_binding_identifiers())
+            .map(|ident| ident.name.to_string())
+            .collect(),
+        Declaration::FunctionDeclaration(func) => {
+            func.id.iter().map(|id| id.name.to_string()).collect()
+        }
+        Declaration::ClassDeclaration(class) => {
+            class.id.iter().map(|id| id.name.to_string()).collect()
+        }
+        Declaration::TSTypeAliasDeclaration(decl) => vec![decl.id.name.to_string()],
+        Declaration::TSInterfaceDeclaration(decl) => vec![decl.id.name.to_string()],
+        Declaration::TSEnumDeclaration(decl) => vec![decl.id.name.to_string()],
+        Declaration::TSModuleDeclaration(decl) => match &decl.id {
+            TSModuleDeclarationName::Identifier(id) => vec![id.name.to_string()],
+            TSModuleDeclarationName::StringLiteral(_) => Vec::new(),
+        },
+        Declaration::TSImportEqualsDeclaration(decl) => vec![decl.id.name.to_string()],
+    }
+}
+
+/// `import type { A } from` or `import { type A, type B } from`
+fn is_type_only_import(decl: &ImportDeclaration) -> bool {
+    let specifiers = decl.specifiers.as_ref().map_or(&[][..], |s| &s[..]);
+    decl.import_kind.is_type()
+        || (!specifiers.is_empty()
+            && specifiers.iter().all(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(s) => s.import_kind.is_type(),
+                _ => false,
+            }))
+}
+
 fn extract_from_statement(stmt: &Statement, imports: &mut Vec<ImportInfo>) {
     match stmt {
         Statement::ImportDeclaration(decl) => {
             // Type-only imports still establish a dependency for unused file detection
             // (the file cannot be deleted even if only imported for types)
-            let kind = if decl.specifiers.as_ref().is_some_and(|s| s.is_empty()) {
+            let kind = if is_type_only_import(decl) {
+                ImportKind::Type
+            } else if decl.specifiers.as_ref().is_some_and(|s| s.is_empty()) {
                 ImportKind::SideEffect
             } else {
                 ImportKind::Static
@@ -66,18 +313,49 @@ fn extract_from_statement(stmt: &Statement, imports: &mut Vec<ImportInfo>) {
         Statement::ExportNamedDeclaration(decl) => {
             // Type-only exports still establish a dependency for unused file detection
             if let Some(source) = &decl.source {
-                imports.push(ImportInfo {
-                    source: source.value.to_string(),
-                    kind: ImportKind::ExportFrom,
-                });
+                let type_only = decl.export_kind.is_type()
+                    || (!decl.specifiers.is_empty()
+                        && decl.specifiers.iter().all(|s| s.export_kind.is_type()));
+                let kind = if type_only { ImportKind::Type } else { ImportKind::ExportFrom };
+                imports.push(ImportInfo { source: source.value.to_string(), kind });
+            }
+            match &decl.declaration {
+                Some(Declaration::VariableDeclaration(var_decl)) => {
+                    for decl in &var_decl.declarations {
+                        if let Some(init) = &decl.init {
+                            extract_from_expression(init, imports);
+                        }
+                    }
+                }
+                Some(Declaration::TSModuleDeclaration(module)) => {
+                    extract_from_module_declaration(module, imports);
+                }
+                _ => {}
             }
         }
+        Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                if let Some(body) = &func.body {
+                    for stmt in &body.statements {
+                        extract_from_statement(stmt, imports);
+                    }
+                }
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                extract_from_class(class, imports);
+            }
+            // `export default [...base, require("./extra")]`
+            kind => {
+                if let Some(expr) = kind.as_expression() {
+                    extract_from_expression(expr, imports);
+                }
+            }
+        },
         Statement::ExportAllDeclaration(decl) => {
             // Type-only exports still establish a dependency for unused file detection
-            imports.push(ImportInfo {
-                source: decl.source.value.to_string(),
-                kind: ImportKind::ExportStar,
-            });
+            let kind =
+                if decl.export_kind.is_type() { ImportKind::Type } else { ImportKind::ExportStar };
+            imports.push(ImportInfo { source: decl.source.value.to_string(), kind });
         }
         Statement::ExpressionStatement(expr_stmt) => {
             extract_from_expression(&expr_stmt.expression, imports);
@@ -106,6 +384,24 @@ fn extract_from_statement(stmt: &Statement, imports: &mut Vec<ImportInfo>) {
             extract_from_statement(&while_stmt.body, imports);
         }
         Statement::ForStatement(for_stmt) => {
+            match &for_stmt.init {
+                Some(ForStatementInit::VariableDeclaration(var_decl)) => {
+                    for decl in &var_decl.declarations {
+                        if let Some(init) = &decl.init {
+                            extract_from_expression(init, imports);
+                        }
+                    }
+                }
+                Some(init) => {
+                    if let Some(expr) = init.as_expression() {
+                        extract_from_expression(expr, imports);
+                    }
+                }
+                None => {}
+            }
+            for expr in for_stmt.test.iter().chain(&for_stmt.update) {
+                extract_from_expression(expr, imports);
+            }
             extract_from_statement(&for_stmt.body, imports);
         }
         Statement::ForInStatement(for_in) => {
@@ -132,6 +428,9 @@ fn extract_from_statement(stmt: &Statement, imports: &mut Vec<ImportInfo>) {
         Statement::SwitchStatement(switch_stmt) => {
             extract_from_expression(&switch_stmt.discriminant, imports);
             for case in &switch_stmt.cases {
+                if let Some(test) = &case.test {
+                    extract_from_expression(test, imports);
+                }
                 for stmt in &case.consequent {
                     extract_from_statement(stmt, imports);
                 }
@@ -149,39 +448,131 @@ fn extract_from_statement(stmt: &Statement, imports: &mut Vec<ImportInfo>) {
                 }
             }
         }
-        Statement::ClassDeclaration(class) => {
-            for element in &class.body.body {
-                if let oxc_ast::ast::ClassElement::MethodDefinition(method) = element {
-                    if let Some(body) = &method.value.body {
-                        for stmt in &body.statements {
-                            extract_from_statement(stmt, imports);
-                        }
-                    }
-                }
+        Statement::ClassDeclaration(class) => extract_from_class(class, imports),
+        Statement::TSImportEqualsDeclaration(decl) => {
+            // `import foo = require("./foo")`
+            if let TSModuleReference::ExternalModuleReference(ext) = &decl.module_reference {
+                imports.push(ImportInfo {
+                    source: ext.expression.value.to_string(),
+                    kind: ImportKind::Require,
+                });
             }
         }
+        Statement::TSModuleDeclaration(module) => {
+            extract_from_module_declaration(module, imports);
+        }
         _ => {}
     }
 }
 
+/// Descend into `namespace Foo { ... }` / `declare module "x" { ... }` bodies.
+/// Nested `namespace A.B {}` declarations are represented as a chain of module declarations.
+fn extract_from_module_declaration(module: &TSModuleDeclaration, imports: &mut Vec<ImportInfo>) {
+    match &module.body {
+        Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+            for stmt in &block.body {
+                extract_from_statement(stmt, imports);
+            }
+        }
+        Some(TSModuleDeclarationBody::TSModuleDeclaration(nested)) => {
+            extract_from_module_declaration(nested, imports);
+        }
+        None => {}
+    }
+}
+
+/// Turn a template-literal `import()` into an import.
+///
+/// A template without interpolations is a plain dynamic import. With
+/// interpolations, it becomes a `DynamicGlob` (each `${...}` replaced by `*`)
+/// when it starts with a relative path and ends with a file extension,
+/// e.g. `./locales/${lang}.js` -> `./locales/*.js`.
+fn template_import(template: &TemplateLiteral) -> Option<ImportInfo> {
+    let quasis: Vec<&str> = template.quasis.iter().map(|q| q.value.raw.as_str()).collect();
+
+    if template.expressions.is_empty() {
+        return Some(ImportInfo { source: quasis.concat(), kind: ImportKind::Dynamic });
+    }
+
+    let prefix = quasis.first()?;
+    let is_relative = prefix.starts_with("./") || prefix.starts_with("../");
+    let has_extension = quasis
+        .last()?
+        .rsplit_once('.')
+        .is_some_and(|(_, ext)| !ext.is_empty() && ext.chars().all(|c| c.is_ascii_alphanumeric()));
+    if !is_relative || !has_extension {
+        return None;
+    }
+
+    Some(ImportInfo { source: quasis.join("*"), kind: ImportKind::DynamicGlob })
+}
+
+/// Local `url(...)` references in the text of a CSS-in-JS tagged template.
+///
+/// Only literal `./` and `../` paths are picked up; anything interpolated,
+/// remote, or root-absolute is left to the bundler. Query strings and
+/// fragments are stripped.
+fn template_url_assets(template: &TemplateLiteral) -> Vec<ImportInfo> {
+    static URL: OnceLock<Regex> = OnceLock::new();
+    let url_re =
+        URL.get_or_init(|| Regex::new(r#"url\(\s*["']?(\.\.?/[^"')\s?#]+)"#).expect("valid regex"));
+
+    template
+        .quasis
+        .iter()
+        .flat_map(|quasi| url_re.captures_iter(quasi.value.raw.as_str()))
+        .map(|c| ImportInfo { source: c[1].to_string(), kind: ImportKind::UrlAsset })
+        .collect()
+}
+
+/// `object.method("<specifier>")` calls whose first argument references a module file
+const MODULE_MEMBER_CALLS: &[(&str, &str)] =
+    &[("require", "resolve"), ("jest", "mock"), ("jest", "requireActual"), ("vi", "mock")];
+
+/// Extract imports from the method bodies of a class
+fn extract_from_class(class: &Class, imports: &mut Vec<ImportInfo>) {
+    for element in &class.body.body {
+        if let oxc_ast::ast::ClassElement::MethodDefinition(method) = element {
+            if let Some(body) = &method.value.body {
+                for stmt in &body.statements {
+                    extract_from_statement(stmt, imports);
+                }
+            }
+        }
+    }
+}
+
 fn extract_from_expression(expr: &Expression, imports: &mut Vec<ImportInfo>) {
     match expr {
-        Expression::ImportExpression(import_expr) => {
-            if let Expression::StringLiteral(lit) = &import_expr.source {
+        Expression::ImportExpression(import_expr) => match &import_expr.source {
+            Expression::StringLiteral(lit) => {
                 imports
                     .push(ImportInfo { source: lit.value.to_string(), kind: ImportKind::Dynamic });
             }
-        }
+            Expression::TemplateLiteral(template) => {
+                if let Some(import) = template_import(template) {
+                    imports.push(import);
+                }
+            }
+            _ => {}
+        },
         Expression::CallExpression(call) => {
-            // Check for require("...")
-            if let Expression::Identifier(ident) = &call.callee {
-                if ident.name == "require" {
-                    if let Some(Argument::StringLiteral(lit)) = call.arguments.first() {
-                        imports.push(ImportInfo {
-                            source: lit.value.to_string(),
-                            kind: ImportKind::Require,
-                        });
-                    }
+            // Check for require("...") and module-referencing member calls
+            // like require.resolve("...") or jest.mock("...")
+            let references_module = match &call.callee {
+                Expression::Identifier(ident) => ident.name == "require",
+                Expression::StaticMemberExpression(member) => {
+                    matches!(&member.object, Expression::Identifier(object)
+                        if MODULE_MEMBER_CALLS.contains(&(object.name.as_str(), member.property.name.as_str())))
+                }
+                _ => false,
+            };
+            if references_module {
+                if let Some(Argument::StringLiteral(lit)) = call.arguments.first() {
+                    imports.push(ImportInfo {
+                        source: lit.value.to_string(),
+                        kind: ImportKind::Require,
+                    });
                 }
             }
             // Recurse into callee and arguments
@@ -194,6 +585,24 @@ fn extract_from_expression(expr: &Expression, imports: &mut Vec<ImportInfo>) {
                 }
             }
         }
+        Expression::NewExpression(new_expr) => {
+            extract_from_expression(&new_expr.callee, imports);
+            for arg in &new_expr.arguments {
+                if let Argument::SpreadElement(spread) = arg {
+                    extract_from_expression(&spread.argument, imports);
+                } else if let Some(expr) = arg.as_expression() {
+                    extract_from_expression(expr, imports);
+                }
+            }
+        }
+        Expression::TaggedTemplateExpression(tagged) => {
+            imports.extend(template_url_assets(&tagged.quasi));
+            // `styled(Button)`...`` and interpolated `${css`...`}` fragments
+            extract_from_expression(&tagged.tag, imports);
+            for expr in &tagged.quasi.expressions {
+                extract_from_expression(expr, imports);
+            }
+        }
         Expression::ArrowFunctionExpression(arrow) => {
             for stmt in &arrow.body.statements {
                 extract_from_statement(stmt, imports);
@@ -236,17 +645,29 @@ fn extract_from_expression(expr: &Expression, imports: &mut Vec<ImportInfo>) {
         Expression::ParenthesizedExpression(paren) => {
             extract_from_expression(&paren.expression, imports);
         }
+        // `require("./x") as T`, `require("./x")!` and friends
+        Expression::TSAsExpression(ts) => extract_from_expression(&ts.expression, imports),
+        Expression::TSSatisfiesExpression(ts) => extract_from_expression(&ts.expression, imports),
+        Expression::TSNonNullExpression(ts) => extract_from_expression(&ts.expression, imports),
+        Expression::TSTypeAssertion(ts) => extract_from_expression(&ts.expression, imports),
         Expression::ArrayExpression(arr) => {
             for elem in &arr.elements {
-                if let Some(expr) = elem.as_expression() {
+                if let ArrayExpressionElement::SpreadElement(spread) = elem {
+                    extract_from_expression(&spread.argument, imports);
+                } else if let Some(expr) = elem.as_expression() {
                     extract_from_expression(expr, imports);
                 }
             }
         }
         Expression::ObjectExpression(obj) => {
             for prop in &obj.properties {
-                if let oxc_ast::ast::ObjectPropertyKind::ObjectProperty(p) = prop {
-                    extract_from_expression(&p.value, imports);
+                match prop {
+                    oxc_ast::ast::ObjectPropertyKind::ObjectProperty(p) => {
+                        extract_from_expression(&p.value, imports);
+                    }
+                    oxc_ast::ast::ObjectPropertyKind::SpreadProperty(spread) => {
+                        extract_from_expression(&spread.argument, imports);
+                    }
                 }
             }
         }
@@ -258,3 +679,430 @@ fn extract_from_expression(expr: &Expression, imports: &mut Vec<ImportInfo>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn parse_sources(file_name: &str, content: &str) -> Vec<String> {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join(file_name);
+        fs::write(&path, content).unwrap();
+        extract_imports(&path).unwrap().into_iter().map(|i| i.source).collect()
+    }
+
+    #[test]
+    fn test_import_equals_require() {
+        let sources = parse_sources("index.ts", r#"import bar = require("./bar");"#);
+        assert_eq!(sources, vec!["./bar"]);
+    }
+
+    #[test]
+    fn test_import_equals_inside_namespace() {
+        let content = r#"
+namespace Foo {
+    import Bar = require("./bar");
+    export const baz = require("./baz");
+}
+"#;
+        let sources = parse_sources("index.ts", content);
+        assert_eq!(sources, vec!["./bar", "./baz"]);
+    }
+
+    #[test]
+    fn test_dotted_and_exported_namespace() {
+        let content = r#"
+export namespace A.B {
+    export const lazy = () => import("./lazy");
+}
+"#;
+        let sources = parse_sources("index.ts", content);
+        assert_eq!(sources, vec!["./lazy"]);
+    }
+
+    #[test]
+    fn test_module_augmentation_body() {
+        let content = r#"
+declare module "some-lib" {
+    import type { Options } from "./options";
+}
+"#;
+        let sources = parse_sources("augment.d.ts", content);
+        assert_eq!(sources, vec!["./options"]);
+    }
+
+    #[test]
+    fn test_symbols_exports_and_named_uses() {
+        let content = r#"
+import def, { a, b as c } from "./lib";
+import * as ns from "./ns";
+import "./side";
+export { x } from "./reexported";
+export * from "./star";
+export const one = 1, { two } = obj;
+export function three() {}
+export interface Four {}
+const five = 5;
+export { five as renamed };
+export default three;
+const lazy = import("./lazy");
+"#;
+        let symbols = extract_symbols_from_source(content, SourceType::ts()).unwrap();
+
+        assert_eq!(symbols.exports, vec!["one", "two", "three", "Four", "renamed", "default"]);
+
+        let used = |source: &str| {
+            symbols.uses.iter().find(|u| u.source == source).map(|u| u.names.clone()).unwrap()
+        };
+        assert_eq!(
+            used("./lib"),
+            UsedNames::Names(vec!["default".to_string(), "a".to_string(), "b".to_string()])
+        );
+        assert_eq!(used("./ns"), UsedNames::All);
+        assert_eq!(used("./side"), UsedNames::Names(Vec::new()));
+        assert_eq!(used("./reexported"), UsedNames::Names(vec!["x".to_string()]));
+        assert_eq!(used("./star"), UsedNames::All);
+        assert_eq!(used("./lazy"), UsedNames::All);
+    }
+
+    #[test]
+    fn test_module_referencing_member_calls() {
+        let content = r#"
+const fixture = require.resolve("./fixture");
+jest.mock("./service", () => ({}));
+const actual = jest.requireActual("./actual");
+vi.mock("./vi-service");
+"#;
+        let sources = parse_sources("index.test.ts", content);
+        assert_eq!(sources, vec!["./fixture", "./service", "./actual", "./vi-service"]);
+    }
+
+    #[test]
+    fn test_module_member_calls_ignore_non_literal_arguments() {
+        let content = r#"
+jest.mock(modulePath);
+require.resolve(`./${name}`);
+other.mock("./not-a-module");
+"#;
+        let sources = parse_sources("index.test.ts", content);
+        assert!(sources.is_empty());
+    }
+
+    fn parse_imports(file_name: &str, content: &str) -> Vec<(String, ImportKind)> {
+        let temp = tempdir().unwrap();
+        let path = temp.path().join(file_name);
+        fs::write(&path, content).unwrap();
+        extract_imports(&path).unwrap().into_iter().map(|i| (i.source, i.kind)).collect()
+    }
+
+    #[test]
+    fn test_reference_path_directives() {
+        let content = r#"#!/usr/bin/env node
+// Ambient declarations
+/// <reference path="./globals.d.ts" />
+/// <reference path='types/env.d.ts'/>
+/* license
+ * header */
+/// <reference types="node" />
+/// <reference lib="es2020" />
+import "./app";
+/// <reference path="./after-code.d.ts" />
+"#;
+        let imports = parse_imports("index.ts", content);
+        assert_eq!(
+            imports,
+            vec![
+                ("./app".to_string(), ImportKind::Static),
+                ("./globals.d.ts".to_string(), ImportKind::Reference),
+                ("./types/env.d.ts".to_string(), ImportKind::Reference),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_reference_directives_are_skipped() {
+        let content = r#"/// <reference path="" />
+/// <reference path="./unterminated.d.ts />
+/// <reference path=./unquoted.d.ts />
+/// <reference typespath="./attribute.d.ts" />
+/// reference path="./no-tag.d.ts"
+// <reference path="./double-slash.d.ts" />
+/// <reference path = "./spaced.d.ts" />
+export {};
+"#;
+        let imports = parse_imports("index.d.ts", content);
+        assert_eq!(imports, vec![("./spaced.d.ts".to_string(), ImportKind::Reference)]);
+    }
+
+    #[test]
+    fn test_type_only_imports() {
+        let content = r#"
+import type { Foo } from "./foo";
+import { type Bar, type Baz } from "./bar";
+import { type Qux, quux } from "./mixed";
+import type Def from "./def";
+"#;
+        assert_eq!(
+            parse_imports("index.ts", content),
+            vec![
+                ("./foo".to_string(), ImportKind::Type),
+                ("./bar".to_string(), ImportKind::Type),
+                ("./mixed".to_string(), ImportKind::Static),
+                ("./def".to_string(), ImportKind::Type),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_only_exports() {
+        let content = r#"
+export type { Foo } from "./foo";
+export { type Bar } from "./bar";
+export type * from "./types";
+export type * as ns from "./ns-types";
+export * from "./values";
+"#;
+        assert_eq!(
+            parse_imports("index.ts", content),
+            vec![
+                ("./foo".to_string(), ImportKind::Type),
+                ("./bar".to_string(), ImportKind::Type),
+                ("./types".to_string(), ImportKind::Type),
+                ("./ns-types".to_string(), ImportKind::Type),
+                ("./values".to_string(), ImportKind::ExportStar),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_barrel_re_exports() {
+        let content = r#"
+export { default } from "./button";
+export { default as Card } from "./card";
+export { a, b as c } from "./mod";
+export * as icons from "./icons";
+export * from "./utils";
+"#;
+        assert_eq!(
+            parse_imports("index.ts", content),
+            vec![
+                ("./button".to_string(), ImportKind::ExportFrom),
+                ("./card".to_string(), ImportKind::ExportFrom),
+                ("./mod".to_string(), ImportKind::ExportFrom),
+                ("./icons".to_string(), ImportKind::ExportStar),
+                ("./utils".to_string(), ImportKind::ExportStar),
+            ]
+        );
+
+        let symbols = extract_symbols_from_source(content, SourceType::ts()).unwrap();
+        let names: Vec<(&str, &UsedNames)> =
+            symbols.uses.iter().map(|u| (u.source.as_str(), &u.names)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("./button", &UsedNames::Names(vec!["default".to_string()])),
+                ("./card", &UsedNames::Names(vec!["default".to_string()])),
+                ("./mod", &UsedNames::Names(vec!["a".to_string(), "b".to_string()])),
+                ("./icons", &UsedNames::All),
+                ("./utils", &UsedNames::All),
+            ]
+        );
+        assert!(symbols.exports.is_empty());
+    }
+
+    #[test]
+    fn test_type_only_import_names_are_used() {
+        let content = "import { type Foo } from \"./foo\";\nexport type * from \"./types\";";
+        let symbols = extract_symbols_from_source(content, SourceType::ts()).unwrap();
+
+        let names: Vec<(&str, &UsedNames)> =
+            symbols.uses.iter().map(|u| (u.source.as_str(), &u.names)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("./foo", &UsedNames::Names(vec!["Foo".to_string()])),
+                ("./types", &UsedNames::All),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_css_in_js_url_assets() {
+        let content = r#"
+import styled, { css, createGlobalStyle } from "styled-components";
+
+export const Hero = styled.section`
+  background: url('./images/hero.png?v=2') no-repeat;
+  ${(props) => props.dark && css`background-image: url(../assets/dark.jpg);`}
+`;
+const Card = styled(Box)`
+  border-image: url("./border.svg#frame") 30;
+  mask: url(${maskUrl});
+  cursor: url(https://example.com/cursor.png), auto;
+  background: url(/static/bg.png);
+`;
+const Global = createGlobalStyle`
+  @font-face { src: url(./fonts/inter.woff2) format("woff2"); }
+`;
+const plain = `url(./not-css.png)`;
+"#;
+        assert_eq!(
+            parse_imports("styles.tsx", content),
+            vec![
+                ("styled-components".to_string(), ImportKind::Static),
+                ("./images/hero.png".to_string(), ImportKind::UrlAsset),
+                ("../assets/dark.jpg".to_string(), ImportKind::UrlAsset),
+                ("./border.svg".to_string(), ImportKind::UrlAsset),
+                ("./fonts/inter.woff2".to_string(), ImportKind::UrlAsset),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_template_import_with_interpolation_is_glob() {
+        let imports = parse_imports(
+            "index.ts",
+            "import(`./locales/${lang}.js`);\nimport(`../pages/${section}/${page}.tsx`);\nimport(`./file-${name}.js`);",
+        );
+        assert_eq!(
+            imports,
+            vec![
+                ("./locales/*.js".to_string(), ImportKind::DynamicGlob),
+                ("../pages/*/*.tsx".to_string(), ImportKind::DynamicGlob),
+                ("./file-*.js".to_string(), ImportKind::DynamicGlob),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_static_template_import_is_dynamic() {
+        let imports = parse_imports("index.ts", "const a = import(`./static.js`);");
+        assert_eq!(imports, vec![("./static.js".to_string(), ImportKind::Dynamic)]);
+    }
+
+    #[test]
+    fn test_template_import_without_static_prefix_is_ignored() {
+        let imports =
+            parse_imports("index.ts", "import(`${base}/a.js`);\nimport(`./locales/${lang}`);");
+        assert!(imports.is_empty());
+    }
+
+    #[test]
+    fn test_requires_inside_if_statements() {
+        let content = r#"
+if (process.env.FEATURE) require("./feature");
+if (process.env.A) {
+  require("./a");
+} else if (process.env.B) {
+  require("./b");
+} else require("./c");
+"#;
+        assert_eq!(parse_sources("index.js", content), vec!["./feature", "./a", "./b", "./c"]);
+    }
+
+    #[test]
+    fn test_requires_inside_switch() {
+        let content = r#"
+switch (process.env.TARGET) {
+  case "node":
+    require("./node");
+    break;
+  case require("./targets").WEB:
+    module.exports = require("./web");
+    break;
+  default: {
+    require("./fallback");
+  }
+}
+"#;
+        assert_eq!(
+            parse_sources("index.js", content),
+            vec!["./node", "./targets", "./web", "./fallback"]
+        );
+    }
+
+    #[test]
+    fn test_requires_inside_ternaries() {
+        let content = r#"
+const impl = process.env.NATIVE ? require("./native") : require("./wasm");
+const nested = a ? (b ? require("./ab") : null) : require("./not-a");
+"#;
+        assert_eq!(
+            parse_sources("index.js", content),
+            vec!["./native", "./wasm", "./ab", "./not-a"]
+        );
+    }
+
+    #[test]
+    fn test_requires_inside_short_circuits() {
+        let content = r#"
+process.env.DEBUG && require("./debug");
+const polyfill = globalThis.fetch || require("./fetch");
+const config = loadConfig() ?? require("./default-config");
+let cache;
+cache ||= require("./cache");
+!process.env.CI && void require("./dev-tools");
+"#;
+        assert_eq!(
+            parse_sources("index.js", content),
+            vec!["./debug", "./fetch", "./default-config", "./cache", "./dev-tools"]
+        );
+    }
+
+    #[test]
+    fn test_requires_inside_typescript_expressions() {
+        let content = r#"
+const plugin = (process.env.PRO ? require("./pro") : require("./basic")) as Plugin;
+const store = flag && (require("./store")! satisfies Store);
+const Client = new (require("./client").Client)();
+for (let i = require("./start").START; i < 3; i++) {}
+"#;
+        assert_eq!(
+            parse_sources("index.ts", content),
+            vec!["./pro", "./basic", "./store", "./client", "./start"]
+        );
+    }
+
+    #[test]
+    fn test_eslint_flat_config_imports() {
+        let content = r#"
+import baseConfig from "./base.mjs";
+const { default: react } = await import("./config/react.mjs");
+export default [
+  ...baseConfig,
+  ...(process.env.CI ? (await import("./config/ci.mjs")).default : []),
+  { plugins: { local: require("./rules/index.cjs") } },
+];
+"#;
+        assert_eq!(
+            parse_sources("eslint.config.mjs", content),
+            vec!["./base.mjs", "./config/react.mjs", "./config/ci.mjs", "./rules/index.cjs"]
+        );
+    }
+
+    #[test]
+    fn test_commonjs_re_exports() {
+        let content = r#"
+module.exports = require("./a");
+Object.assign(module.exports, require("./b"));
+Object.assign(exports, require("./b2"), { ...require("./b3") });
+module.exports = { ...require("./c"), d: require("./d") };
+exports.e = require("./e");
+module.exports.f = require("./f").f;
+__exportStar(require("./g"), exports);
+Object.defineProperty(exports, "h", { get: () => require("./h").h });
+Object.defineProperty(exports, "i", { get: function () { return require("./i"); } });
+module.exports = { get j() { return require("./j"); } };
+const merged = new Set(...require("./k"));
+"#;
+        assert_eq!(
+            parse_sources("index.js", content),
+            vec![
+                "./a", "./b", "./b2", "./b3", "./c", "./d", "./e", "./f", "./g", "./h", "./i",
+                "./j", "./k"
+            ]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use super::{Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Target options that point at files the Angular CLI builds from.
+/// `browser` and `server` replace `main` in the esbuild-based application builder.
+const ENTRY_OPTIONS: &[&str] = &["main", "browser", "server", "polyfills", "tsConfig"];
+
+/// Plugin to discover Angular CLI build and test entry points from `angular.json`
+pub struct AngularPlugin;
+
+impl AngularPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect the entry files of every target (`build`, `test`, `server`, ...)
+    /// of every project. Paths are relative to the workspace root; `polyfills`
+    /// entries that are package names (e.g. `zone.js`) don't exist on disk and
+    /// are skipped.
+    fn parse_workspace(&self, cwd: &Path) -> Result<Vec<PathBuf>, PluginError> {
+        let content = fs::read_to_string(cwd.join("angular.json"))?;
+        let json: serde_json::Value =
+            serde_json::from_str(&content).map_err(|e| PluginError::ConfigParse(e.to_string()))?;
+
+        let mut files = Vec::new();
+        let projects = json.get("projects").and_then(|p| p.as_object());
+
+        for project in projects.into_iter().flat_map(|projects| projects.values()) {
+            let targets = project
+                .get("architect")
+                .or_else(|| project.get("targets"))
+                .and_then(|targets| targets.as_object());
+
+            for target in targets.into_iter().flat_map(|targets| targets.values()) {
+                let Some(options) = target.get("options") else {
+                    continue;
+                };
+
+                for key in ENTRY_OPTIONS {
+                    match options.get(*key) {
+                        Some(serde_json::Value::String(file)) => files.push(file.as_str()),
+                        Some(serde_json::Value::Array(list)) => {
+                            files.extend(list.iter().filter_map(|file| file.as_str()));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let mut seen = FxHashSet::default();
+        Ok(files
+            .into_iter()
+            .map(|file| cwd.join(file))
+            .filter(|path| path.is_file())
+            .filter_map(|path| path.canonicalize().ok())
+            .filter(|path| seen.insert(path.clone()))
+            .collect())
+    }
+}
+
+impl Default for AngularPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for AngularPlugin {
+    fn name(&self) -> &str {
+        "angular"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("@angular/core")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        if !cwd.join("angular.json").is_file() {
+            return Ok(PluginEntries::Empty);
+        }
+
+        Ok(PluginEntries::paths(self.parse_workspace(cwd)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const ANGULAR_JSON: &str = r#"{
+  "version": 1,
+  "projects": {
+    "shop": {
+      "root": "",
+      "sourceRoot": "src",
+      "architect": {
+        "build": {
+          "builder": "@angular-devkit/build-angular:browser",
+          "options": {
+            "main": "src/main.ts",
+            "polyfills": "src/polyfills.ts",
+            "tsConfig": "tsconfig.app.json"
+          }
+        },
+        "test": {
+          "builder": "@angular-devkit/build-angular:karma",
+          "options": {
+            "main": "src/test.ts",
+            "polyfills": "src/polyfills.ts",
+            "tsConfig": "tsconfig.spec.json"
+          }
+        }
+      }
+    },
+    "admin": {
+      "root": "projects/admin",
+      "architect": {
+        "build": {
+          "builder": "@angular-devkit/build-angular:application",
+          "options": {
+            "browser": "projects/admin/src/main.ts",
+            "server": "projects/admin/src/main.server.ts",
+            "polyfills": ["zone.js"],
+            "tsConfig": "projects/admin/tsconfig.app.json"
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_should_enable_with_angular_core() {
+        let plugin = AngularPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@angular/core".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_angular_core() {
+        let plugin = AngularPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("react".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_two_project_workspace() {
+        let plugin = AngularPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(temp.path().join("angular.json"), ANGULAR_JSON).unwrap();
+        write_files(
+            temp.path(),
+            &[
+                "src/main.ts",
+                "src/polyfills.ts",
+                "src/test.ts",
+                "tsconfig.app.json",
+                "tsconfig.spec.json",
+                "projects/admin/src/main.ts",
+                "projects/admin/src/main.server.ts",
+                "projects/admin/tsconfig.app.json",
+            ],
+        );
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        let mut paths: Vec<_> = entries
+            .get_paths()
+            .iter()
+            .map(|p| p.strip_prefix(&root).unwrap().to_path_buf())
+            .collect();
+
+        // Shared polyfills are listed once and `zone.js` (a package) is skipped
+        let mut expected: Vec<PathBuf> = [
+            "src/main.ts",
+            "src/polyfills.ts",
+            "tsconfig.app.json",
+            "src/test.ts",
+            "tsconfig.spec.json",
+            "projects/admin/src/main.ts",
+            "projects/admin/src/main.server.ts",
+            "projects/admin/tsconfig.app.json",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+        paths.sort();
+        expected.sort();
+        assert_eq!(paths, expected);
+    }
+
+    #[test]
+    fn test_no_angular_json() {
+        let plugin = AngularPlugin::new();
+        let temp = tempdir().unwrap();
+
+        assert!(plugin.detect_entries(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: AngularPlugin = Default::default();
+    }
+}
@@ -0,0 +1,363 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrayExpressionElement, ExportDefaultDeclarationKind, Expression, ObjectExpression,
+    ObjectPropertyKind, PropertyKey, Statement,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config files AVA looks for, in lookup order
+const CONFIG_FILES: &[&str] = &["ava.config.js", "ava.config.cjs", "ava.config.mjs"];
+
+/// Test file globs AVA uses when `files` is not set, without the extension
+const DEFAULT_FILE_GLOBS: &[&str] = &[
+    "test",
+    "src/test",
+    "source/test",
+    "**/test-*",
+    "**/*.spec",
+    "**/*.test",
+    "**/test/**/*",
+    "**/tests/**/*",
+    "**/__tests__/**/*",
+];
+
+/// Extensions AVA loads when `extensions` is not set
+const DEFAULT_EXTENSIONS: &[&str] = &["js", "cjs", "mjs"];
+
+/// Extensions to try when resolving `require` modules
+const RESOLVE_EXTENSIONS: &[&str] = &[".js", ".ts", ".mjs", ".cjs", ".jsx", ".tsx"];
+
+/// Plugin to discover AVA test files, `require` hooks, and config as entry points
+pub struct AvaPlugin;
+
+/// Settings read from the `ava` key in package.json or `ava.config.*`
+#[derive(Debug, Default, PartialEq)]
+struct AvaConfig {
+    files: Option<Vec<String>>,
+    extensions: Option<Vec<String>>,
+    require: Vec<String>,
+}
+
+impl AvaPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find the AVA config file in the project root
+    fn find_config_file(&self, cwd: &Path) -> Option<PathBuf> {
+        CONFIG_FILES.iter().map(|name| cwd.join(name)).find(|path| path.is_file())
+    }
+
+    /// Read the `ava` key from package.json
+    fn parse_package_json_config(&self, cwd: &Path) -> Option<AvaConfig> {
+        let content = fs::read_to_string(cwd.join("package.json")).ok()?;
+        let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+        let ava = json.get("ava")?.as_object()?;
+
+        let strings = |value: &serde_json::Value| -> Vec<String> {
+            value
+                .as_array()
+                .map(|arr| arr.iter().filter_map(|s| s.as_str().map(String::from)).collect())
+                .unwrap_or_default()
+        };
+
+        Some(AvaConfig {
+            files: ava.get("files").map(strings),
+            // `extensions` is either `["ts"]` or `{ "ts": "module" }`
+            extensions: ava.get("extensions").map(|value| match value.as_object() {
+                Some(map) => map.keys().cloned().collect(),
+                None => strings(value),
+            }),
+            require: ava.get("require").map(strings).unwrap_or_default(),
+        })
+    }
+
+    /// Parse `ava.config.*` (`export default { ... }` or `module.exports = { ... }`)
+    fn parse_js_config(&self, config_path: &Path) -> Result<AvaConfig, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        for stmt in &parsed.program.body {
+            let obj = match stmt {
+                Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                    ExportDefaultDeclarationKind::ObjectExpression(obj) => Some(obj),
+                    _ => None,
+                },
+                Statement::ExpressionStatement(expr_stmt) => match &expr_stmt.expression {
+                    Expression::AssignmentExpression(assign) => match &assign.right {
+                        Expression::ObjectExpression(obj) => Some(obj),
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some(obj) = obj {
+                return Ok(self.extract_config_from_object(obj));
+            }
+        }
+
+        Ok(AvaConfig::default())
+    }
+
+    /// Extract `files`, `extensions`, and `require` from the config object
+    fn extract_config_from_object(&self, obj: &ObjectExpression) -> AvaConfig {
+        let mut config = AvaConfig::default();
+
+        for prop in &obj.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = prop else {
+                continue;
+            };
+            let key_name = match &property.key {
+                PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+                PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+                _ => continue,
+            };
+
+            match (key_name, &property.value) {
+                ("files", Expression::ArrayExpression(arr)) => {
+                    config.files = Some(self.string_elements(&arr.elements));
+                }
+                ("extensions", Expression::ArrayExpression(arr)) => {
+                    config.extensions = Some(self.string_elements(&arr.elements));
+                }
+                ("extensions", Expression::ObjectExpression(map)) => {
+                    config.extensions = Some(
+                        map.properties
+                            .iter()
+                            .filter_map(|prop| match prop {
+                                ObjectPropertyKind::ObjectProperty(p) => match &p.key {
+                                    PropertyKey::StaticIdentifier(ident) => {
+                                        Some(ident.name.to_string())
+                                    }
+                                    PropertyKey::StringLiteral(lit) => Some(lit.value.to_string()),
+                                    _ => None,
+                                },
+                                _ => None,
+                            })
+                            .collect(),
+                    );
+                }
+                ("require", Expression::ArrayExpression(arr)) => {
+                    config.require = self.string_elements(&arr.elements);
+                }
+                _ => {}
+            }
+        }
+
+        config
+    }
+
+    /// String literal elements of an array expression
+    fn string_elements(&self, elements: &[ArrayExpressionElement]) -> Vec<String> {
+        elements
+            .iter()
+            .filter_map(|elem| match elem.as_expression() {
+                Some(Expression::StringLiteral(lit)) => Some(lit.value.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Build entry patterns from `files` globs (or AVA's defaults).
+    /// Globs without an extension only match AVA's test file extensions.
+    fn test_patterns(&self, files: Option<&[String]>, extensions: &[String]) -> Vec<EntryPattern> {
+        let exts = if extensions.len() == 1 {
+            extensions[0].clone()
+        } else {
+            format!("{{{}}}", extensions.join(","))
+        };
+
+        let with_extension = |glob: &str| {
+            let file_name = glob.rsplit('/').next().unwrap_or_default();
+            if file_name.contains('.') && !file_name.ends_with(".*") {
+                glob.to_string()
+            } else {
+                format!("{}.{exts}", glob.trim_end_matches(".*"))
+            }
+        };
+
+        match files {
+            Some(files) => files
+                .iter()
+                .filter(|glob| !glob.starts_with('!'))
+                .map(|glob| EntryPattern::new(with_extension(glob.trim_start_matches("./"))))
+                .collect(),
+            None => DEFAULT_FILE_GLOBS
+                .iter()
+                .map(|glob| EntryPattern::new(format!("{glob}.{exts}")))
+                .collect(),
+        }
+    }
+
+    /// Resolve local `require` modules; package names (e.g. `ts-node/register`) are skipped
+    fn resolve_requires(&self, requires: &[String], cwd: &Path) -> Vec<PathBuf> {
+        requires
+            .iter()
+            .filter(|module| module.starts_with('.'))
+            .filter_map(|module| {
+                let target = cwd.join(module);
+                std::iter::once(target.clone())
+                    .chain(
+                        RESOLVE_EXTENSIONS
+                            .iter()
+                            .map(|ext| PathBuf::from(format!("{}{ext}", target.to_string_lossy()))),
+                    )
+                    .find(|path| path.is_file())
+            })
+            .filter_map(|path| path.canonicalize().ok())
+            .collect()
+    }
+}
+
+impl Default for AvaPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for AvaPlugin {
+    fn name(&self) -> &str {
+        "ava"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("ava")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let mut paths = Vec::new();
+
+        // A config file takes precedence over the package.json `ava` key
+        let config = match self.find_config_file(cwd) {
+            Some(config_path) => {
+                if let Ok(canonical) = config_path.canonicalize() {
+                    paths.push(canonical);
+                }
+                self.parse_js_config(&config_path).unwrap_or_default()
+            }
+            None => self.parse_package_json_config(cwd).unwrap_or_default(),
+        };
+
+        let extensions = config
+            .extensions
+            .unwrap_or_else(|| DEFAULT_EXTENSIONS.iter().map(|ext| ext.to_string()).collect());
+        let patterns = self.test_patterns(config.files.as_deref(), &extensions);
+        paths.extend(self.resolve_requires(&config.require, cwd));
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn patterns(entries: &PluginEntries) -> Vec<&str> {
+        entries.get_patterns().iter().map(|p| p.pattern.as_str()).collect()
+    }
+
+    #[test]
+    fn test_should_enable_with_ava() {
+        let plugin = AvaPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("ava".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_ava() {
+        let plugin = AvaPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("mocha".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_default_globs() {
+        let plugin = AvaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let patterns = patterns(&entries);
+        assert_eq!(patterns.len(), DEFAULT_FILE_GLOBS.len());
+        assert!(patterns.contains(&"test.{js,cjs,mjs}"));
+        assert!(patterns.contains(&"**/*.test.{js,cjs,mjs}"));
+        assert!(patterns.contains(&"**/__tests__/**/*.{js,cjs,mjs}"));
+        assert!(entries.get_paths().is_empty());
+    }
+
+    #[test]
+    fn test_package_json_files_extensions_and_require() {
+        let plugin = AvaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let package_json = r#"{
+  "devDependencies": { "ava": "^6.0.0" },
+  "ava": {
+    "files": ["test/**/*", "!test/fixtures/**", "spec/*.spec.ts"],
+    "extensions": { "ts": "module" },
+    "require": ["ts-node/register", "./test/_setup"]
+  }
+}"#;
+        fs::write(temp.path().join("package.json"), package_json).unwrap();
+        fs::create_dir(temp.path().join("test")).unwrap();
+        fs::write(temp.path().join("test/_setup.ts"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["test/**/*.ts", "spec/*.spec.ts"]);
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("test/_setup.ts"));
+    }
+
+    #[test]
+    fn test_config_file_files_array() {
+        let plugin = AvaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+export default {
+  files: ['tests/unit/**/*.js', './tests/e2e/*'],
+  require: ['./tests/helpers/setup.js'],
+};
+"#;
+        fs::write(temp.path().join("ava.config.mjs"), config_content).unwrap();
+        fs::create_dir_all(temp.path().join("tests/helpers")).unwrap();
+        fs::write(temp.path().join("tests/helpers/setup.js"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["tests/unit/**/*.js", "tests/e2e/*.{js,cjs,mjs}"]);
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with("ava.config.mjs"));
+        assert!(paths[1].ends_with("tests/helpers/setup.js"));
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: AvaPlugin = Default::default();
+    }
+}
@@ -0,0 +1,347 @@
+use super::{Plugin, PluginEntries, PluginError, strip_json_comments};
+use oxc_allocator::Allocator;
+use oxc_ast::Visit;
+use oxc_ast::ast::{ArrayExpressionElement, Expression, ObjectProperty, PropertyKey};
+use oxc_ast::visit::walk::walk_object_property;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Project-wide and file-relative config files Babel loads from the root
+const CONFIG_NAMES: &[&str] = &[
+    "babel.config.js",
+    "babel.config.cjs",
+    "babel.config.mjs",
+    "babel.config.cts",
+    "babel.config.json",
+    ".babelrc",
+    ".babelrc.js",
+    ".babelrc.cjs",
+    ".babelrc.mjs",
+    ".babelrc.cts",
+    ".babelrc.json",
+];
+
+/// Config options listing presets and plugins
+const ITEM_OPTIONS: &[&str] = &["presets", "plugins"];
+
+/// Extensions to try when resolving a preset or plugin path
+const RESOLVE_EXTENSIONS: &[&str] = &[".js", ".cjs", ".mjs", ".ts", ".cts", ".mts"];
+
+/// Collects the names of `presets`/`plugins` items in a JS config: plain strings
+/// (`"./my-plugin"`) and the first element of `[name, options]` tuples
+#[derive(Default)]
+struct ItemVisitor {
+    items: Vec<String>,
+}
+
+impl<'a> Visit<'a> for ItemVisitor {
+    fn visit_object_property(&mut self, property: &ObjectProperty<'a>) {
+        let is_items = match &property.key {
+            PropertyKey::StaticIdentifier(ident) => ITEM_OPTIONS.contains(&ident.name.as_str()),
+            PropertyKey::StringLiteral(lit) => ITEM_OPTIONS.contains(&lit.value.as_str()),
+            _ => false,
+        };
+
+        if let (true, Expression::ArrayExpression(arr)) = (is_items, &property.value) {
+            for elem in &arr.elements {
+                let name = match elem {
+                    ArrayExpressionElement::StringLiteral(lit) => Some(&lit.value),
+                    ArrayExpressionElement::ArrayExpression(tuple) => {
+                        match tuple.elements.first() {
+                            Some(ArrayExpressionElement::StringLiteral(lit)) => Some(&lit.value),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                self.items.extend(name.map(|name| name.to_string()));
+            }
+        }
+
+        // `env` and `overrides` nest further presets/plugins
+        walk_object_property(self, property);
+    }
+}
+
+/// Collect `presets`/`plugins` item names from a JSON config value, including
+/// those nested under `env` and `overrides`
+fn json_items(value: &serde_json::Value, items: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, nested) in map {
+                if let (true, serde_json::Value::Array(list)) =
+                    (ITEM_OPTIONS.contains(&key.as_str()), nested)
+                {
+                    items.extend(list.iter().filter_map(|item| match item {
+                        serde_json::Value::String(name) => Some(name.clone()),
+                        serde_json::Value::Array(tuple) => {
+                            tuple.first()?.as_str().map(String::from)
+                        }
+                        _ => None,
+                    }));
+                }
+                json_items(nested, items);
+            }
+        }
+        serde_json::Value::Array(list) => list.iter().for_each(|nested| json_items(nested, items)),
+        _ => {}
+    }
+}
+
+/// Plugin to discover Babel configs and the local presets/plugins they reference.
+///
+/// Babel loads presets and plugins by name, so a local one
+/// (`plugins: [["./babel/my-plugin", { ... }]]`) is never imported. The config
+/// files are entries, as are the local items they reference; package names are
+/// left alone. The `babel` field of package.json is read too.
+pub struct BabelPlugin;
+
+impl BabelPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find Babel config files in the project root
+    fn find_config_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        CONFIG_NAMES
+            .iter()
+            .map(|name| cwd.join(name))
+            .filter(|path| path.is_file())
+            .filter_map(|path| path.canonicalize().ok())
+            .collect()
+    }
+
+    /// Extract the preset and plugin names of a config file. `.babelrc` and
+    /// `.json` configs are JSON (with comments); everything else is JS.
+    fn parse_items(&self, config_path: &Path) -> Result<Vec<String>, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let is_json = config_path.extension().is_none_or(|ext| ext == "json");
+
+        let mut items = Vec::new();
+        if is_json {
+            let value: serde_json::Value = serde_json::from_str(&strip_json_comments(&content))
+                .map_err(|e| {
+                    PluginError::ConfigParse(format!(
+                        "Failed to parse {}: {e}",
+                        config_path.display()
+                    ))
+                })?;
+            json_items(&value, &mut items);
+            return Ok(items);
+        }
+
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let allocator = Allocator::default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        let mut visitor = ItemVisitor::default();
+        visitor.visit_program(&parsed.program);
+        Ok(visitor.items)
+    }
+
+    /// Preset and plugin names from the `babel` field of package.json
+    fn package_json_items(&self, cwd: &Path) -> Vec<String> {
+        let mut items = Vec::new();
+        let babel = fs::read_to_string(cwd.join("package.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .and_then(|pkg| pkg.get("babel").cloned());
+        if let Some(babel) = babel {
+            json_items(&babel, &mut items);
+        }
+        items
+    }
+
+    /// Resolve a local preset/plugin path, trying extensions and `index` files
+    fn resolve_item(&self, cwd: &Path, item: &str) -> Option<PathBuf> {
+        let target = cwd.join(item);
+        std::iter::once(target.clone())
+            .chain(RESOLVE_EXTENSIONS.iter().map(|ext| cwd.join(format!("{item}{ext}"))))
+            .chain(RESOLVE_EXTENSIONS.iter().map(|ext| target.join(format!("index{ext}"))))
+            .find(|candidate| candidate.is_file())
+            .and_then(|candidate| candidate.canonicalize().ok())
+    }
+}
+
+impl Default for BabelPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check if a preset/plugin name is a local path rather than a package name
+fn is_local_path(item: &str) -> bool {
+    item.starts_with("./") || item.starts_with("../")
+}
+
+impl Plugin for BabelPlugin {
+    fn name(&self) -> &str {
+        "babel"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("@babel/core")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let mut paths = self.find_config_files(cwd);
+
+        let mut items = self.package_json_items(cwd);
+        for config in &paths {
+            items.extend(self.parse_items(config).unwrap_or_default());
+        }
+
+        for item in items.iter().filter(|item| is_local_path(item)) {
+            if let Some(resolved) = self.resolve_item(cwd, item) {
+                if !paths.contains(&resolved) {
+                    paths.push(resolved);
+                }
+            }
+        }
+
+        Ok(PluginEntries::paths(paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    fn relative(paths: &[&PathBuf], root: &Path) -> Vec<String> {
+        let root = root.canonicalize().unwrap();
+        paths
+            .iter()
+            .map(|p| p.strip_prefix(&root).unwrap().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_should_enable_with_babel_core() {
+        let plugin = BabelPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@babel/core".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_babel_core() {
+        let plugin = BabelPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@babel/preset-env".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_js_config_local_items() {
+        let plugin = BabelPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(
+            temp.path(),
+            &[
+                "babel/preset.js",
+                "babel/strip-debug.ts",
+                "babel/macros/index.js",
+                "babel/unused.js",
+            ],
+        );
+        let config = r#"
+module.exports = (api) => {
+  api.cache(true);
+  return {
+    presets: [["@babel/preset-env", { targets: "defaults" }], "./babel/preset"],
+    plugins: [
+      "@babel/plugin-transform-runtime",
+      ["./babel/strip-debug", { level: 1 }],
+      "./babel/missing",
+    ],
+    env: { test: { plugins: ["./babel/macros"] } },
+  };
+};
+"#;
+        fs::write(temp.path().join("babel.config.js"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            relative(&entries.get_paths(), temp.path()),
+            vec![
+                "babel.config.js",
+                "babel/preset.js",
+                "babel/strip-debug.ts",
+                "babel/macros/index.js",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_config_local_items() {
+        let plugin = BabelPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["tools/inline-env.js", "tools/preset.cjs"]);
+        let config = r#"{
+  // Comments are allowed in .babelrc
+  "presets": ["@babel/preset-react", ["./tools/preset", { "runtime": "automatic" }]],
+  "overrides": [{ "test": "*.ts", "plugins": ["./tools/inline-env.js", "babel-plugin-macros"] }]
+}"#;
+        fs::write(temp.path().join(".babelrc"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            relative(&entries.get_paths(), temp.path()),
+            vec![".babelrc", "tools/preset.cjs", "tools/inline-env.js"]
+        );
+    }
+
+    #[test]
+    fn test_package_json_babel_field() {
+        let plugin = BabelPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["scripts/babel-plugin.js"]);
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "babel": { "plugins": ["./scripts/babel-plugin", "@babel/plugin-syntax-jsx"] } }"#,
+        )
+        .unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(relative(&entries.get_paths(), temp.path()), vec!["scripts/babel-plugin.js"]);
+    }
+
+    #[test]
+    fn test_no_config_returns_empty() {
+        let plugin = BabelPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: BabelPlugin = Default::default();
+    }
+}
@@ -0,0 +1,160 @@
+use super::{Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::path::{Path, PathBuf};
+
+/// Plugin to discover Capacitor config files as entry points.
+///
+/// Capacitor reads `capacitor.config.{ts,js,json}` from the project root when
+/// syncing native projects, so the config is never imported by app code.
+/// By adding the config file as an entry point, normal import tracing will
+/// discover any local files it references.
+pub struct CapacitorPlugin;
+
+impl CapacitorPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find Capacitor config files in the project root.
+    /// Follows the same lookup order as the Capacitor CLI:
+    /// https://capacitorjs.com/docs/config
+    fn find_config_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        let config_names = ["capacitor.config.ts", "capacitor.config.js", "capacitor.config.json"];
+
+        let mut found = Vec::new();
+
+        for name in &config_names {
+            let path = cwd.join(name);
+            if path.exists() && path.is_file() {
+                if let Ok(canonical) = path.canonicalize() {
+                    found.push(canonical);
+                }
+            }
+        }
+
+        found
+    }
+}
+
+impl Default for CapacitorPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for CapacitorPlugin {
+    fn name(&self) -> &str {
+        "capacitor"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("@capacitor/core")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        Ok(PluginEntries::paths(self.find_config_files(cwd)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_should_enable_with_capacitor_core() {
+        let plugin = CapacitorPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@capacitor/core".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_capacitor_core() {
+        let plugin = CapacitorPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@capacitor/cli".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_find_capacitor_config_ts() {
+        let plugin = CapacitorPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+import type { CapacitorConfig } from '@capacitor/cli';
+
+const config: CapacitorConfig = {
+  appId: 'com.example.app',
+  appName: 'example',
+  webDir: 'dist',
+};
+
+export default config;
+"#;
+        fs::write(temp.path().join("capacitor.config.ts"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("capacitor.config.ts"));
+    }
+
+    #[test]
+    fn test_find_capacitor_config_js() {
+        let plugin = CapacitorPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+module.exports = {
+  appId: 'com.example.app',
+  appName: 'example',
+  webDir: 'dist',
+};
+"#;
+        fs::write(temp.path().join("capacitor.config.js"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("capacitor.config.js"));
+    }
+
+    #[test]
+    fn test_find_capacitor_config_json() {
+        let plugin = CapacitorPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"{
+  "appId": "com.example.app",
+  "appName": "example",
+  "webDir": "dist"
+}"#;
+        fs::write(temp.path().join("capacitor.config.json"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("capacitor.config.json"));
+    }
+
+    #[test]
+    fn test_no_config_returns_empty() {
+        let plugin = CapacitorPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: CapacitorPlugin = Default::default();
+    }
+}
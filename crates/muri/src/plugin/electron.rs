@@ -0,0 +1,224 @@
+use super::{Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directories that usually hold compiled output rather than source files
+const BUILD_DIRS: &[&str] = &["dist", "build", "out", "dist-electron", ".vite"];
+
+/// Source extensions to try when mapping a built `main` file back to its source
+const SOURCE_EXTENSIONS: &[&str] = &["ts", "mts", "cts", "js", "mjs", "cjs"];
+
+/// Plugin to discover Electron main-process and preload entry points.
+///
+/// Electron starts from the package.json `main` field and loads preload scripts
+/// by path at runtime, so neither is imported by app code. This plugin returns
+/// the `main` file (mapped back to source when it points at build output),
+/// common preload script locations, and electron-vite config files.
+pub struct ElectronPlugin;
+
+impl ElectronPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Read the `main` field from package.json
+    fn read_main_field(&self, cwd: &Path) -> Option<String> {
+        let content = fs::read_to_string(cwd.join("package.json")).ok()?;
+        let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+        json.get("main")?.as_str().map(|s| s.to_string())
+    }
+
+    /// Resolve the `main` field to a source file.
+    ///
+    /// If `main` points into a build directory (e.g. `dist/main.js`), look for the
+    /// matching source file under `src/` or the project root instead.
+    fn resolve_main(&self, main: &str, cwd: &Path) -> Option<PathBuf> {
+        let main_path = Path::new(main.trim_start_matches("./"));
+
+        let mut components = main_path.components();
+        let first = components.next()?.as_os_str().to_str()?;
+
+        if BUILD_DIRS.contains(&first) {
+            let rest = components.as_path().with_extension("");
+            for base in [cwd.join("src"), cwd.to_path_buf()] {
+                for ext in SOURCE_EXTENSIONS {
+                    let candidate = base.join(&rest).with_extension(ext);
+                    if candidate.is_file() {
+                        return candidate.canonicalize().ok();
+                    }
+                }
+            }
+            return None;
+        }
+
+        let path = cwd.join(main_path);
+        if path.is_file() { path.canonicalize().ok() } else { None }
+    }
+
+    /// Find preload scripts and electron-vite config files at conventional locations
+    fn find_conventional_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        let candidates = [
+            "preload.js",
+            "preload.ts",
+            "src/preload.js",
+            "src/preload.ts",
+            "src/preload/index.js",
+            "src/preload/index.ts",
+            "electron/preload.js",
+            "electron/preload.ts",
+            "electron.vite.config.js",
+            "electron.vite.config.mjs",
+            "electron.vite.config.cjs",
+            "electron.vite.config.ts",
+            "electron.vite.config.mts",
+            "electron.vite.config.cts",
+        ];
+
+        let mut found = Vec::new();
+
+        for name in &candidates {
+            let path = cwd.join(name);
+            if path.exists() && path.is_file() {
+                if let Ok(canonical) = path.canonicalize() {
+                    found.push(canonical);
+                }
+            }
+        }
+
+        found
+    }
+}
+
+impl Default for ElectronPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for ElectronPlugin {
+    fn name(&self) -> &str {
+        "electron"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("electron")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let mut paths = Vec::new();
+
+        if let Some(main) = self.read_main_field(cwd).and_then(|m| self.resolve_main(&m, cwd)) {
+            paths.push(main);
+        }
+
+        for path in self.find_conventional_files(cwd) {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+
+        Ok(PluginEntries::paths(paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_should_enable_with_electron() {
+        let plugin = ElectronPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("electron".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_electron() {
+        let plugin = ElectronPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("react".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_main_pointing_at_source_file() {
+        let plugin = ElectronPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(temp.path().join("package.json"), r#"{ "main": "src/main.js" }"#).unwrap();
+        fs::create_dir(temp.path().join("src")).unwrap();
+        fs::write(temp.path().join("src/main.js"), "require('electron');").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("src/main.js"));
+    }
+
+    #[test]
+    fn test_main_pointing_at_built_file_maps_to_source() {
+        let plugin = ElectronPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(temp.path().join("package.json"), r#"{ "main": "./dist/main.js" }"#).unwrap();
+        fs::create_dir(temp.path().join("src")).unwrap();
+        fs::write(temp.path().join("src/main.ts"), "import 'electron';").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("src/main.ts"));
+    }
+
+    #[test]
+    fn test_built_main_without_source_is_skipped() {
+        let plugin = ElectronPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(temp.path().join("package.json"), r#"{ "main": "dist/main.js" }"#).unwrap();
+        fs::create_dir(temp.path().join("dist")).unwrap();
+        fs::write(temp.path().join("dist/main.js"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_missing_main_returns_empty() {
+        let plugin = ElectronPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(temp.path().join("package.json"), r#"{ "name": "app" }"#).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_find_preload_and_electron_vite_config() {
+        let plugin = ElectronPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::create_dir_all(temp.path().join("src/preload")).unwrap();
+        fs::write(temp.path().join("src/preload/index.ts"), "").unwrap();
+        fs::write(temp.path().join("electron.vite.config.ts"), "export default {}").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().any(|p| p.ends_with("src/preload/index.ts")));
+        assert!(paths.iter().any(|p| p.ends_with("electron.vite.config.ts")));
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: ElectronPlugin = Default::default();
+    }
+}
@@ -0,0 +1,259 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::Visit;
+use oxc_ast::ast::{
+    ArrayExpressionElement, Expression, ObjectProperty, ObjectPropertyKind, PropertyKey,
+};
+use oxc_ast::visit::walk::walk_object_property;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Build scripts that commonly call esbuild's JS API
+const BUILD_SCRIPTS: &[&str] = &[
+    "esbuild.config.js",
+    "esbuild.config.mjs",
+    "esbuild.config.ts",
+    "scripts/build.js",
+    "scripts/build.mjs",
+    "scripts/build.ts",
+];
+
+/// Plugin to discover esbuild `entryPoints` from build scripts
+pub struct EsbuildPlugin;
+
+/// Collects string values of every `entryPoints` property in a script
+#[derive(Default)]
+struct EntryPointsVisitor {
+    entry_points: Vec<String>,
+}
+
+impl<'a> Visit<'a> for EntryPointsVisitor {
+    fn visit_object_property(&mut self, property: &ObjectProperty<'a>) {
+        let is_entry_points = match &property.key {
+            PropertyKey::StaticIdentifier(ident) => ident.name == "entryPoints",
+            PropertyKey::StringLiteral(lit) => lit.value == "entryPoints",
+            _ => false,
+        };
+
+        if is_entry_points {
+            match &property.value {
+                // `entryPoints: ['src/a.ts', { in: 'src/b.ts', out: 'b' }]`
+                Expression::ArrayExpression(arr) => {
+                    self.entry_points.extend(arr.elements.iter().filter_map(array_entry));
+                }
+                // `entryPoints: { out: 'src/a.ts' }`
+                Expression::ObjectExpression(obj) => {
+                    self.entry_points.extend(obj.properties.iter().filter_map(|prop| match prop {
+                        ObjectPropertyKind::ObjectProperty(p) => string_value(&p.value),
+                        _ => None,
+                    }));
+                }
+                _ => {}
+            }
+        }
+
+        walk_object_property(self, property);
+    }
+}
+
+/// Source path of an `entryPoints` array element: a string or `{ in, out }`
+fn array_entry(elem: &ArrayExpressionElement) -> Option<String> {
+    match elem.as_expression()? {
+        Expression::ObjectExpression(obj) => obj.properties.iter().find_map(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(p) => match &p.key {
+                PropertyKey::StaticIdentifier(ident) if ident.name == "in" => {
+                    string_value(&p.value)
+                }
+                _ => None,
+            },
+            _ => None,
+        }),
+        expr => string_value(expr),
+    }
+}
+
+/// Value of a string literal expression
+fn string_value(expr: &Expression) -> Option<String> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+        _ => None,
+    }
+}
+
+impl EsbuildPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find build scripts in the project
+    fn find_build_scripts(&self, cwd: &Path) -> Vec<PathBuf> {
+        BUILD_SCRIPTS.iter().map(|name| cwd.join(name)).filter(|path| path.is_file()).collect()
+    }
+
+    /// Extract every `entryPoints` value from a build script
+    fn parse_entry_points(&self, script_path: &Path) -> Result<Vec<String>, PluginError> {
+        let content = fs::read_to_string(script_path)?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(script_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                script_path.display()
+            )));
+        }
+
+        let mut visitor = EntryPointsVisitor::default();
+        visitor.visit_program(&parsed.program);
+        Ok(visitor.entry_points)
+    }
+}
+
+impl Default for EsbuildPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for EsbuildPlugin {
+    fn name(&self) -> &str {
+        "esbuild"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("esbuild")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let mut patterns = Vec::new();
+        let mut paths = Vec::new();
+
+        for script in self.find_build_scripts(cwd) {
+            let entry_points = self.parse_entry_points(&script).unwrap_or_default();
+            if let Ok(canonical) = script.canonicalize() {
+                paths.push(canonical);
+            }
+
+            // esbuild resolves entry points from the working directory, and
+            // accepts glob patterns since 0.19
+            for entry in entry_points {
+                let entry = entry.trim_start_matches("./");
+                if entry.contains(['*', '?', '[', '{']) {
+                    patterns.push(EntryPattern::new(entry));
+                } else if let Ok(canonical) = cwd.join(entry).canonicalize() {
+                    paths.push(canonical);
+                }
+            }
+        }
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_should_enable_with_esbuild() {
+        let plugin = EsbuildPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("esbuild".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_esbuild() {
+        let plugin = EsbuildPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("vite".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_array_entry_points() {
+        let plugin = EsbuildPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let script = r#"
+import * as esbuild from 'esbuild';
+
+await esbuild.build({
+  entryPoints: ['./src/index.ts', { in: 'src/worker.ts', out: 'worker' }, 'src/pages/*.tsx'],
+  bundle: true,
+  outdir: 'dist',
+});
+"#;
+        write_files(temp.path(), &["src/index.ts", "src/worker.ts"]);
+        fs::write(temp.path().join("esbuild.config.mjs"), script).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with("esbuild.config.mjs"));
+        assert!(paths[1].ends_with("src/index.ts"));
+        assert!(paths[2].ends_with("src/worker.ts"));
+
+        let patterns = entries.get_patterns();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[0].pattern, "src/pages/*.tsx");
+    }
+
+    #[test]
+    fn test_object_entry_points() {
+        let plugin = EsbuildPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let script = r#"
+const esbuild = require('esbuild');
+
+const options = {
+  entryPoints: { main: 'src/main.js', 'admin/app': 'src/admin.js' },
+  outdir: 'dist',
+};
+
+esbuild.build(options).catch(() => process.exit(1));
+"#;
+        write_files(temp.path(), &["src/main.js", "src/admin.js"]);
+        fs::create_dir(temp.path().join("scripts")).unwrap();
+        fs::write(temp.path().join("scripts/build.js"), script).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with("scripts/build.js"));
+        assert!(paths[1].ends_with("src/main.js"));
+        assert!(paths[2].ends_with("src/admin.js"));
+        assert!(entries.get_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_no_build_scripts() {
+        let plugin = EsbuildPlugin::new();
+        let temp = tempdir().unwrap();
+
+        assert_eq!(plugin.detect_entries(temp.path()).unwrap().total_count(), 0);
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: EsbuildPlugin = Default::default();
+    }
+}
@@ -0,0 +1,133 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::path::{Path, PathBuf};
+
+/// Gatsby's root-level API files
+const API_FILES: &[&str] = &["gatsby-config", "gatsby-node", "gatsby-browser", "gatsby-ssr"];
+
+/// Extensions Gatsby accepts for its API files
+const API_FILE_EXTENSIONS: &[&str] = &["js", "mjs", "ts", "tsx", "jsx"];
+
+/// Directories whose files Gatsby turns into routes, page templates, and functions
+const CONVENTION_DIRS: &[&str] = &["src/pages", "src/templates", "src/api"];
+
+/// Plugin to discover Gatsby entry points.
+///
+/// Gatsby loads its `gatsby-*` API files from the project root, turns every
+/// file in `src/pages/` into a route and every file in `src/api/` into a
+/// serverless function, and `src/templates/` holds templates that
+/// `gatsby-node` passes to `createPage` by path.
+pub struct GatsbyPlugin;
+
+impl GatsbyPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find `gatsby-{config,node,browser,ssr}` files in the project root
+    fn find_api_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        API_FILES
+            .iter()
+            .flat_map(|name| {
+                API_FILE_EXTENSIONS.iter().map(move |ext| cwd.join(format!("{name}.{ext}")))
+            })
+            .filter(|path| path.is_file())
+            .filter_map(|path| path.canonicalize().ok())
+            .collect()
+    }
+}
+
+impl Default for GatsbyPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for GatsbyPlugin {
+    fn name(&self) -> &str {
+        "gatsby"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("gatsby")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let patterns = CONVENTION_DIRS
+            .iter()
+            .filter(|dir| cwd.join(dir).is_dir())
+            .map(|dir| EntryPattern::with_base("**/*.{js,jsx,ts,tsx}", *dir))
+            .collect();
+
+        Ok(PluginEntries::mixed(patterns, self.find_api_files(cwd)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_should_enable_with_gatsby() {
+        let plugin = GatsbyPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("gatsby".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_gatsby() {
+        let plugin = GatsbyPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("react".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_detect_entries_gatsby_layout() {
+        let plugin = GatsbyPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(temp.path().join("gatsby-config.ts"), "export default {}").unwrap();
+        fs::write(temp.path().join("gatsby-node.js"), "").unwrap();
+        fs::write(temp.path().join("gatsby-browser.tsx"), "").unwrap();
+        fs::write(temp.path().join("gatsby-ssr.jsx"), "").unwrap();
+        fs::create_dir_all(temp.path().join("src/pages/blog")).unwrap();
+        fs::create_dir_all(temp.path().join("src/templates")).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 4);
+        assert!(paths[0].ends_with("gatsby-config.ts"));
+        assert!(paths[1].ends_with("gatsby-node.js"));
+        assert!(paths[2].ends_with("gatsby-browser.tsx"));
+        assert!(paths[3].ends_with("gatsby-ssr.jsx"));
+
+        // `src/api` doesn't exist, so only pages and templates are scanned
+        let patterns = entries.get_patterns();
+        let bases: Vec<_> = patterns.iter().map(|p| p.base.clone().unwrap()).collect();
+        assert_eq!(bases, vec![PathBuf::from("src/pages"), PathBuf::from("src/templates")]);
+        assert!(patterns.iter().all(|p| p.pattern == "**/*.{js,jsx,ts,tsx}"));
+    }
+
+    #[test]
+    fn test_detect_entries_empty_project() {
+        let plugin = GatsbyPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(entries.total_count(), 0);
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: GatsbyPlugin = Default::default();
+    }
+}
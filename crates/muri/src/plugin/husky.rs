@@ -92,98 +92,103 @@ impl HuskyPlugin {
 
     /// Parse a shell script and extract referenced JS/TS file paths
     fn extract_js_files_from_script(&self, script_path: &Path, cwd: &Path) -> Vec<PathBuf> {
-        let content = match fs::read_to_string(script_path) {
-            Ok(c) => c,
-            Err(_) => return Vec::new(),
-        };
+        match fs::read_to_string(script_path) {
+            Ok(content) => extract_script_files(&content, cwd),
+            Err(_) => Vec::new(),
+        }
+    }
+}
 
-        let cwd_canonical = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
-        let mut found_files = FxHashSet::default();
-
-        // Patterns to match JS/TS file references in shell scripts
-        static PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
-        let patterns = PATTERNS.get_or_init(|| {
-            vec![
-                // node script.js, node ./script.js, node ../scripts/lint.js
-                Regex::new(r#"(?:^|\s)node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:js|mjs|cjs))["']?"#)
-                    .unwrap(),
-                // npx ts-node script.ts, npx ts-node ./scripts/check.ts
-                Regex::new(
-                    r#"(?:^|\s)npx\s+ts-node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|mts|cts))["']?"#,
-                )
-                .unwrap(),
-                // npx tsx script.ts
-                Regex::new(
-                    r#"(?:^|\s)npx\s+tsx\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|mts|cts|js|jsx|mjs|cjs))["']?"#,
-                )
-                .unwrap(),
-                // tsx script.ts (when tsx is installed globally or via npx)
-                Regex::new(
-                    r#"(?:^|\s)tsx\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|mts|cts|js|jsx|mjs|cjs))["']?"#,
-                )
-                .unwrap(),
-                // ts-node script.ts (when ts-node is in PATH)
-                Regex::new(
-                    r#"(?:^|\s)ts-node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|mts|cts))["']?"#,
-                )
-                .unwrap(),
-                // bun run script.ts, bun script.ts
-                Regex::new(
-                    r#"(?:^|\s)bun\s+(?:run\s+)?(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|js|jsx|mts|cts|mjs|cjs))["']?"#,
-                )
+/// Extract local JS/TS files run by shell commands in `content` (one command per
+/// line, `#` comments skipped), e.g. `node scripts/lint.js` or `npx tsx check.ts`.
+/// Paths are resolved against `cwd` and must exist inside it.
+pub(super) fn extract_script_files(content: &str, cwd: &Path) -> Vec<PathBuf> {
+    let cwd_canonical = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
+    let mut found_files = FxHashSet::default();
+
+    // Patterns to match JS/TS file references in shell scripts
+    static PATTERNS: OnceLock<Vec<Regex>> = OnceLock::new();
+    let patterns = PATTERNS.get_or_init(|| {
+        vec![
+            // node script.js, node ./script.js, node ../scripts/lint.js
+            Regex::new(r#"(?:^|\s)node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:js|mjs|cjs))["']?"#)
                 .unwrap(),
-                // deno run script.ts
-                Regex::new(
-                    r#"(?:^|\s)deno\s+run\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|js|jsx|mts|cts|mjs|cjs))["']?"#,
-                )
+            // npx ts-node script.ts, npx ts-node ./scripts/check.ts
+            Regex::new(
+                r#"(?:^|\s)npx\s+ts-node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|mts|cts))["']?"#,
+            )
+            .unwrap(),
+            // npx tsx script.ts
+            Regex::new(
+                r#"(?:^|\s)npx\s+tsx\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|mts|cts|js|jsx|mjs|cjs))["']?"#,
+            )
+            .unwrap(),
+            // tsx script.ts (when tsx is installed globally or via npx)
+            Regex::new(
+                r#"(?:^|\s)tsx\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|mts|cts|js|jsx|mjs|cjs))["']?"#,
+            )
+            .unwrap(),
+            // ts-node script.ts (when ts-node is in PATH)
+            Regex::new(
+                r#"(?:^|\s)ts-node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|mts|cts))["']?"#,
+            )
+            .unwrap(),
+            // bun run script.ts, bun script.ts
+            Regex::new(
+                r#"(?:^|\s)bun\s+(?:run\s+)?(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|js|jsx|mts|cts|mjs|cjs))["']?"#,
+            )
+            .unwrap(),
+            // deno run script.ts
+            Regex::new(
+                r#"(?:^|\s)deno\s+run\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|tsx|js|jsx|mts|cts|mjs|cjs))["']?"#,
+            )
+            .unwrap(),
+            // ./node_modules/.bin/ts-node script.ts
+            Regex::new(
+                r#"(?:^|\s)\./node_modules/\.bin/ts-node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|mts|cts))["']?"#,
+            )
+            .unwrap(),
+            // Generic: require('./script.js') or import('./script.ts') in shell heredocs
+            Regex::new(r#"require\s*\(\s*["']([^"']+\.(?:js|mjs|cjs|ts|mts|cts))["']\s*\)"#)
                 .unwrap(),
-                // ./node_modules/.bin/ts-node script.ts
-                Regex::new(
-                    r#"(?:^|\s)\./node_modules/\.bin/ts-node\s+(?:--[^\s]+\s+)*["']?([^\s"']+\.(?:ts|mts|cts))["']?"#,
-                )
-                .unwrap(),
-                // Generic: require('./script.js') or import('./script.ts') in shell heredocs
-                Regex::new(r#"require\s*\(\s*["']([^"']+\.(?:js|mjs|cjs|ts|mts|cts))["']\s*\)"#)
-                    .unwrap(),
-            ]
-        });
-
-        for line in content.lines() {
-            let line = line.trim();
-
-            // Skip comments and empty lines
-            if line.is_empty() || line.starts_with('#') {
-                continue;
-            }
+        ]
+    });
 
-            for pattern in patterns {
-                for cap in pattern.captures_iter(line) {
-                    if let Some(file_match) = cap.get(1) {
-                        let file_path = file_match.as_str();
-
-                        // Resolve the path relative to cwd
-                        let resolved = if file_path.starts_with('/') {
-                            PathBuf::from(file_path)
-                        } else {
-                            cwd.join(file_path)
-                        };
-
-                        // Validate and canonicalize
-                        if resolved.exists() {
-                            if let Ok(canonical) = resolved.canonicalize() {
-                                // Security check: ensure path is within project directory
-                                if canonical.starts_with(&cwd_canonical) {
-                                    found_files.insert(canonical);
-                                }
+    for line in content.lines() {
+        let line = line.trim();
+
+        // Skip comments and empty lines
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        for pattern in patterns {
+            for cap in pattern.captures_iter(line) {
+                if let Some(file_match) = cap.get(1) {
+                    let file_path = file_match.as_str();
+
+                    // Resolve the path relative to cwd
+                    let resolved = if file_path.starts_with('/') {
+                        PathBuf::from(file_path)
+                    } else {
+                        cwd.join(file_path)
+                    };
+
+                    // Validate and canonicalize
+                    if resolved.exists() {
+                        if let Ok(canonical) = resolved.canonicalize() {
+                            // Security check: ensure path is within project directory
+                            if canonical.starts_with(&cwd_canonical) {
+                                found_files.insert(canonical);
                             }
                         }
                     }
                 }
             }
         }
-
-        found_files.into_iter().collect()
     }
+
+    found_files.into_iter().collect()
 }
 
 impl Default for HuskyPlugin {
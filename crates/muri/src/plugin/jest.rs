@@ -1,4 +1,5 @@
-use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use super::{EntryPattern, ModuleAlias, Plugin, PluginEntries, PluginError};
+use crate::types::PluginSettings;
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{
     Argument, Expression, ModuleDeclaration, ObjectPropertyKind, PropertyKey, Statement,
@@ -7,7 +8,7 @@ use oxc_parser::Parser;
 use oxc_span::SourceType;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 
 /// Default test patterns used by Jest when no testMatch or testRegex is specified
 /// These are expanded versions of Jest's default patterns that work with the glob crate
@@ -36,15 +37,26 @@ const INDEX_FILES: &[&str] =
     &["index.js", "index.ts", "index.jsx", "index.tsx", "index.mjs", "index.cjs"];
 
 /// Plugin to discover Jest test files and configuration as entry points
-pub struct JestPlugin;
+pub struct JestPlugin {
+    /// Custom config file path (relative to cwd) from plugin settings
+    config_path: Option<PathBuf>,
+}
 
 impl JestPlugin {
     pub fn new() -> Self {
-        Self
+        Self { config_path: None }
     }
 
-    /// Find Jest config files in the project
+    /// Find Jest config files in the project.
+    /// A custom config path from plugin settings takes precedence over the default names.
     fn find_config_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        if let Some(config_path) = &self.config_path {
+            let path = cwd.join(config_path);
+            if path.exists() {
+                return vec![path];
+            }
+        }
+
         let extensions = ["js", "ts", "mjs", "cjs"];
         let mut found = Vec::new();
 
@@ -130,7 +142,43 @@ impl JestPlugin {
             })
         });
 
-        Some(JestConfig { test_match, test_regex, setup_files, setup_files_after_env, transform })
+        let module_name_mapper = obj
+            .get("moduleNameMapper")
+            .and_then(|v| v.as_object())
+            .map(|mapper| {
+                mapper
+                    .iter()
+                    .map(|(pattern, targets)| {
+                        let targets = match targets {
+                            serde_json::Value::String(s) => vec![s.clone()],
+                            serde_json::Value::Array(arr) => {
+                                arr.iter().filter_map(|s| s.as_str().map(String::from)).collect()
+                            }
+                            _ => Vec::new(),
+                        };
+                        (pattern.clone(), targets)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let root_dir = obj.get("rootDir").and_then(|v| v.as_str()).map(String::from);
+
+        let roots = obj.get("roots").and_then(|v| {
+            v.as_array()
+                .map(|arr| arr.iter().filter_map(|s| s.as_str().map(String::from)).collect())
+        });
+
+        Some(JestConfig {
+            test_match,
+            test_regex,
+            setup_files,
+            setup_files_after_env,
+            transform,
+            module_name_mapper,
+            root_dir,
+            roots,
+        })
     }
 
     /// Parse Jest config from a JS/TS file
@@ -256,6 +304,16 @@ impl JestPlugin {
                         "transform" => {
                             config.transform = self.extract_transform_paths(&property.value);
                         }
+                        "moduleNameMapper" => {
+                            config.module_name_mapper =
+                                self.extract_module_name_mapper(&property.value);
+                        }
+                        "rootDir" => {
+                            config.root_dir = self.extract_string_from_expression(&property.value);
+                        }
+                        "roots" => {
+                            config.roots = self.extract_string_array(&property.value);
+                        }
                         _ => {}
                     }
                 }
@@ -322,6 +380,48 @@ impl JestPlugin {
         }
     }
 
+    /// Extract `moduleNameMapper` entries: regex keys mapped to a target string or an
+    /// array of fallback targets
+    fn extract_module_name_mapper(&self, expr: &Expression) -> Vec<(String, Vec<String>)> {
+        let Expression::ObjectExpression(obj) = expr else {
+            return Vec::new();
+        };
+        obj.properties
+            .iter()
+            .filter_map(|prop| {
+                let ObjectPropertyKind::ObjectProperty(property) = prop else {
+                    return None;
+                };
+                let pattern = match &property.key {
+                    PropertyKey::StaticIdentifier(ident) => ident.name.to_string(),
+                    PropertyKey::StringLiteral(lit) => lit.value.to_string(),
+                    _ => return None,
+                };
+                let targets = self.extract_string_or_array(&property.value)?;
+                Some((pattern, targets))
+            })
+            .collect()
+    }
+
+    /// Turn `moduleNameMapper` entries into resolver aliases, with `<rootDir>`
+    /// replaced by the configured root directory
+    fn module_aliases(
+        &self,
+        mapper: &[(String, Vec<String>)],
+        root_dir: &Path,
+    ) -> Vec<ModuleAlias> {
+        let root = root_dir.canonicalize().unwrap_or_else(|_| root_dir.to_path_buf());
+        let root = root.to_string_lossy();
+        mapper
+            .iter()
+            .map(|(pattern, targets)| {
+                let targets =
+                    targets.iter().map(|target| target.replace("<rootDir>", &root)).collect();
+                ModuleAlias::new(pattern.clone(), targets)
+            })
+            .collect()
+    }
+
     /// Extract a string value from an expression
     fn extract_string_from_expression(&self, expr: &Expression) -> Option<String> {
         match expr {
@@ -371,19 +471,67 @@ impl JestPlugin {
         new_result
     }
 
-    /// Convert test patterns to EntryPatterns
-    fn patterns_to_entry_patterns(&self, patterns: &[String]) -> Vec<EntryPattern> {
-        patterns
-            .iter()
-            .map(|p| {
-                let converted = self.convert_jest_glob(p);
-                EntryPattern::new(converted)
-            })
-            .collect()
+    /// Convert test patterns to EntryPatterns, one per configured root.
+    ///
+    /// Patterns anchored with `<rootDir>` apply once, relative to the root
+    /// directory; all others are applied under each of `roots`.
+    fn patterns_to_entry_patterns(
+        &self,
+        patterns: &[String],
+        root_dir: &Path,
+        roots: &[PathBuf],
+        cwd: &Path,
+    ) -> Vec<EntryPattern> {
+        let cwd = normalize_path(cwd);
+        let entry_pattern = |pattern: &str, base: &Path| {
+            let converted = self.convert_jest_glob(pattern);
+            match normalize_path(base).strip_prefix(&cwd) {
+                Ok(relative) if relative.as_os_str().is_empty() => EntryPattern::new(converted),
+                Ok(relative) => EntryPattern::with_base(converted, relative),
+                Err(_) => EntryPattern::with_base(converted, base),
+            }
+        };
+
+        let mut entry_patterns = Vec::new();
+        for pattern in patterns {
+            if let Some(anchored) = pattern.strip_prefix("<rootDir>") {
+                entry_patterns.push(entry_pattern(anchored.trim_start_matches('/'), root_dir));
+            } else {
+                entry_patterns.extend(roots.iter().map(|root| entry_pattern(pattern, root)));
+            }
+        }
+        entry_patterns
+    }
+
+    /// Expand a config path: `<rootDir>` refers to the root directory, other
+    /// paths are relative to cwd
+    fn expand_root_dir(&self, path: &str, root_dir: &Path, cwd: &Path) -> PathBuf {
+        if let Some(stripped) = path.strip_prefix("<rootDir>/") {
+            root_dir.join(stripped)
+        } else if let Some(stripped) = path.strip_prefix("<rootDir>") {
+            root_dir.join(stripped)
+        } else if let Some(stripped) = path.strip_prefix('/') {
+            cwd.join(stripped)
+        } else {
+            cwd.join(path)
+        }
+    }
+
+    /// The directories test patterns apply under: `roots`, defaulting to the
+    /// root directory itself
+    fn resolve_roots(&self, roots: Option<&[String]>, root_dir: &Path, cwd: &Path) -> Vec<PathBuf> {
+        match roots {
+            Some(roots) => roots
+                .iter()
+                .map(|root| self.expand_root_dir(root, root_dir, cwd))
+                .map(|root| normalize_path(&root))
+                .collect(),
+            None => vec![root_dir.to_path_buf()],
+        }
     }
 
     /// Resolve setup files and transform paths to absolute paths
-    fn resolve_paths(&self, paths: &[String], cwd: &Path) -> Vec<PathBuf> {
+    fn resolve_paths(&self, paths: &[String], root_dir: &Path, cwd: &Path) -> Vec<PathBuf> {
         let cwd_canonical = cwd.canonicalize().unwrap_or_else(|_| cwd.to_path_buf());
         let mut resolved = Vec::new();
 
@@ -393,17 +541,7 @@ impl JestPlugin {
                 continue;
             }
 
-            // Handle <rootDir> prefix
-            let normalized = if let Some(stripped) = path.strip_prefix("<rootDir>/") {
-                cwd.join(stripped)
-            } else if let Some(stripped) = path.strip_prefix("<rootDir>") {
-                cwd.join(stripped)
-            } else if let Some(stripped) = path.strip_prefix('/') {
-                cwd.join(stripped)
-            } else {
-                cwd.join(path)
-            };
-
+            let normalized = self.expand_root_dir(path, root_dir, cwd);
             if let Some(resolved_path) = self.resolve_path(&normalized, cwd) {
                 if resolved_path.starts_with(&cwd_canonical) {
                     resolved.push(resolved_path);
@@ -459,6 +597,10 @@ impl Plugin for JestPlugin {
         dependencies.contains("jest")
     }
 
+    fn configure(&mut self, settings: &PluginSettings) {
+        self.config_path = settings.config.clone();
+    }
+
     fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
         let mut paths = Vec::new();
 
@@ -490,8 +632,8 @@ impl Plugin for JestPlugin {
         }
 
         // Determine test patterns to use
-        let test_patterns: Vec<String> = if config.test_match.is_some() {
-            config.test_match.unwrap()
+        let test_patterns: Vec<String> = if let Some(test_match) = config.test_match {
+            test_match
         } else if config.test_regex.is_some() {
             // testRegex patterns need different handling - they're regex, not globs
             // For now, skip regex patterns and fall back to defaults
@@ -501,24 +643,33 @@ impl Plugin for JestPlugin {
             DEFAULT_TEST_PATTERNS.iter().map(|s| s.to_string()).collect()
         };
 
+        // `rootDir` is relative to the config; `roots` default to it
+        let root_dir = match config.root_dir.as_deref() {
+            Some(root_dir) => normalize_path(&cwd.join(root_dir)),
+            None => cwd.to_path_buf(),
+        };
+        let roots = self.resolve_roots(config.roots.as_deref(), &root_dir, cwd);
+
         // Convert test patterns to EntryPatterns
-        let entry_patterns = self.patterns_to_entry_patterns(&test_patterns);
+        let entry_patterns =
+            self.patterns_to_entry_patterns(&test_patterns, &root_dir, &roots, cwd);
 
         // Resolve setup files (paths, not patterns)
         if let Some(setup_files) = &config.setup_files {
-            paths.extend(self.resolve_paths(setup_files, cwd));
+            paths.extend(self.resolve_paths(setup_files, &root_dir, cwd));
         }
 
         if let Some(setup_files_after_env) = &config.setup_files_after_env {
-            paths.extend(self.resolve_paths(setup_files_after_env, cwd));
+            paths.extend(self.resolve_paths(setup_files_after_env, &root_dir, cwd));
         }
 
         // Resolve transform paths (only local ones)
         if let Some(transform) = &config.transform {
-            paths.extend(self.resolve_paths(transform, cwd));
+            paths.extend(self.resolve_paths(transform, &root_dir, cwd));
         }
 
-        Ok(PluginEntries::mixed(entry_patterns, paths))
+        let aliases = self.module_aliases(&config.module_name_mapper, &root_dir);
+        Ok(PluginEntries::mixed(entry_patterns, paths).with_aliases(aliases))
     }
 }
 
@@ -530,6 +681,12 @@ struct JestConfig {
     setup_files: Option<Vec<String>>,
     setup_files_after_env: Option<Vec<String>>,
     transform: Option<Vec<String>>,
+    /// `moduleNameMapper` as (regex, targets) pairs, in the order Jest tries them
+    module_name_mapper: Vec<(String, Vec<String>)>,
+    /// `rootDir`, relative to the config's directory
+    root_dir: Option<String>,
+    /// `roots`, the directories test files are searched in
+    roots: Option<Vec<String>>,
 }
 
 impl JestConfig {
@@ -544,8 +701,31 @@ impl JestConfig {
                 other.setup_files_after_env,
             ),
             transform: merge_option_vec(self.transform, other.transform),
+            module_name_mapper: other
+                .module_name_mapper
+                .into_iter()
+                .chain(self.module_name_mapper)
+                .collect(),
+            root_dir: other.root_dir.or(self.root_dir),
+            roots: other.roots.or(self.roots),
+        }
+    }
+}
+
+/// Lexically normalize `.` and `..` components, so `<rootDir>/../packages` and
+/// `./src` compare cleanly against cwd
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
         }
     }
+    normalized
 }
 
 /// Merge two optional vectors
@@ -935,4 +1115,124 @@ module.exports = {
         // Should also have default test patterns since config is empty
         assert!(!patterns.is_empty());
     }
+
+    #[test]
+    fn test_custom_config_path() {
+        let mut plugin = JestPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::create_dir(temp.path().join("config")).unwrap();
+        let config_content = r#"
+module.exports = {
+  testMatch: ["**/*.check.js"],
+};
+"#;
+        fs::write(temp.path().join("config/jest.unit.js"), config_content).unwrap();
+
+        plugin.configure(&PluginSettings {
+            config: Some(PathBuf::from("config/jest.unit.js")),
+            ..Default::default()
+        });
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("config/jest.unit.js"));
+
+        let patterns = entries.get_patterns();
+        assert!(patterns.iter().any(|p| p.pattern == "**/*.check.js"));
+    }
+
+    #[test]
+    fn test_module_name_mapper_aliases() {
+        let plugin = JestPlugin::new();
+        let temp = tempdir().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+
+        let config_content = r#"
+module.exports = {
+  moduleNameMapper: {
+    '^@/(.*)$': '<rootDir>/src/$1',
+    "\\.(css|less)$": ["<rootDir>/mocks/style.ts", "identity-obj-proxy"],
+  },
+};
+"#;
+        fs::write(temp.path().join("jest.config.js"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let aliases = entries.get_aliases();
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(aliases[0].pattern, "^@/(.*)$");
+        assert_eq!(aliases[0].targets, vec![format!("{}/src/$1", root.display())]);
+        assert_eq!(aliases[1].pattern, "\\.(css|less)$");
+        assert_eq!(
+            aliases[1].targets,
+            vec![format!("{}/mocks/style.ts", root.display()), "identity-obj-proxy".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_module_name_mapper_json_config() {
+        let plugin = JestPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"{ "moduleNameMapper": { "^~/(.*)$": "<rootDir>/lib/$1" } }"#;
+        fs::write(temp.path().join("jest.config.json"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let aliases = entries.get_aliases();
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(aliases[0].pattern, "^~/(.*)$");
+        assert!(aliases[0].targets[0].ends_with("/lib/$1"));
+        assert!(!aliases[0].targets[0].contains("<rootDir>"));
+    }
+
+    #[test]
+    fn test_roots_scope_test_patterns() {
+        let plugin = JestPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+module.exports = {
+  roots: ['<rootDir>/packages', '<rootDir>/tools'],
+  testMatch: ['**/*.test.ts'],
+};
+"#;
+        fs::write(temp.path().join("jest.config.js"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            entries.get_patterns(),
+            vec![
+                &EntryPattern::with_base("**/*.test.ts", "packages"),
+                &EntryPattern::with_base("**/*.test.ts", "tools"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_root_dir_applies_to_roots_and_anchored_patterns() {
+        let plugin = JestPlugin::new();
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("app/test")).unwrap();
+        fs::write(temp.path().join("app/test/setup.ts"), "").unwrap();
+
+        let config_content = r#"{
+  "rootDir": "./app",
+  "roots": ["<rootDir>/src"],
+  "testMatch": ["<rootDir>/e2e/**/*.spec.ts", "**/*.test.ts"],
+  "setupFiles": ["<rootDir>/test/setup.ts"]
+}"#;
+        fs::write(temp.path().join("jest.config.json"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            entries.get_patterns(),
+            vec![
+                &EntryPattern::with_base("e2e/**/*.spec.ts", "app"),
+                &EntryPattern::with_base("**/*.test.ts", "app/src"),
+            ]
+        );
+        assert!(entries.get_paths().iter().any(|p| p.ends_with("app/test/setup.ts")));
+    }
 }
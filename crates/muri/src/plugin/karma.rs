@@ -0,0 +1,297 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrayExpressionElement, ExportDefaultDeclarationKind, Expression, FunctionBody,
+    ObjectExpression, ObjectPropertyKind, PropertyKey, Statement,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config files Karma looks for
+const CONFIG_FILES: &[&str] =
+    &["karma.conf.js", "karma.conf.ts", "karma.conf.cjs", "karma.conf.mjs"];
+
+/// Plugin to discover files loaded by Karma and its config as entry points
+pub struct KarmaPlugin;
+
+/// `files` patterns and `basePath` from `config.set({ ... })`
+#[derive(Debug, Default, PartialEq)]
+struct KarmaConfig {
+    base_path: Option<String>,
+    files: Vec<String>,
+}
+
+impl KarmaPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find the Karma config file in the project root
+    fn find_config_file(&self, cwd: &Path) -> Option<PathBuf> {
+        CONFIG_FILES.iter().map(|name| cwd.join(name)).find(|path| path.is_file())
+    }
+
+    /// Parse the exported config function (`module.exports = function (config) { ... }`
+    /// or `export default (config) => { ... }`) and read its `config.set({ ... })` call
+    fn parse_config(&self, config_path: &Path) -> Result<KarmaConfig, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        let mut config = KarmaConfig::default();
+
+        for stmt in &parsed.program.body {
+            let body = match stmt {
+                Statement::ExpressionStatement(expr_stmt) => match &expr_stmt.expression {
+                    Expression::AssignmentExpression(assign) => self.function_body(&assign.right),
+                    _ => None,
+                },
+                Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.body.as_deref(),
+                    kind => kind.as_expression().and_then(|expr| self.function_body(expr)),
+                },
+                _ => None,
+            };
+
+            if let Some(body) = body {
+                self.extract_from_body(body, &mut config);
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Body of a function or arrow function expression
+    fn function_body<'a, 'b>(&self, expr: &'b Expression<'a>) -> Option<&'b FunctionBody<'a>> {
+        match expr {
+            Expression::FunctionExpression(func) => func.body.as_deref(),
+            Expression::ArrowFunctionExpression(arrow) => Some(&arrow.body),
+            _ => None,
+        }
+    }
+
+    /// Find `<param>.set({ ... })` calls in the function body
+    fn extract_from_body(&self, body: &FunctionBody, config: &mut KarmaConfig) {
+        for stmt in &body.statements {
+            let Statement::ExpressionStatement(expr_stmt) = stmt else {
+                continue;
+            };
+            let Expression::CallExpression(call) = &expr_stmt.expression else {
+                continue;
+            };
+            let Expression::StaticMemberExpression(member) = &call.callee else {
+                continue;
+            };
+            if member.property.name != "set" {
+                continue;
+            }
+            if let Some(Expression::ObjectExpression(obj)) =
+                call.arguments.first().and_then(|arg| arg.as_expression())
+            {
+                self.extract_config_from_object(obj, config);
+            }
+        }
+    }
+
+    /// Extract `basePath` and the `files` array, whose entries are either
+    /// strings or `{ pattern, included, watched, served }` objects
+    fn extract_config_from_object(&self, obj: &ObjectExpression, config: &mut KarmaConfig) {
+        for prop in &obj.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = prop else {
+                continue;
+            };
+            let key_name = match &property.key {
+                PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+                PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+                _ => continue,
+            };
+
+            match (key_name, &property.value) {
+                ("basePath", Expression::StringLiteral(lit)) => {
+                    config.base_path = Some(lit.value.to_string());
+                }
+                ("files", Expression::ArrayExpression(arr)) => {
+                    config
+                        .files
+                        .extend(arr.elements.iter().filter_map(|elem| self.file_pattern(elem)));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Pattern of a `files` entry
+    fn file_pattern(&self, elem: &ArrayExpressionElement) -> Option<String> {
+        match elem.as_expression()? {
+            Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+            Expression::ObjectExpression(obj) => {
+                obj.properties.iter().find_map(|prop| match prop {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        match (&property.key, &property.value) {
+                            (
+                                PropertyKey::StaticIdentifier(ident),
+                                Expression::StringLiteral(lit),
+                            ) if ident.name == "pattern" => Some(lit.value.to_string()),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for KarmaPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for KarmaPlugin {
+    fn name(&self) -> &str {
+        "karma"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("karma")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let Some(config_path) = self.find_config_file(cwd) else {
+            return Ok(PluginEntries::Empty);
+        };
+
+        let mut paths = Vec::new();
+        if let Ok(canonical) = config_path.canonicalize() {
+            paths.push(canonical);
+        }
+
+        // `files` patterns are relative to `basePath`, which is relative to the config file
+        let config = self.parse_config(&config_path).unwrap_or_default();
+        let base = config
+            .base_path
+            .as_deref()
+            .map(|base| base.trim_start_matches("./").trim_end_matches('/'))
+            .filter(|base| !base.is_empty() && *base != ".");
+        let patterns = config
+            .files
+            .iter()
+            .map(|file| file.trim_start_matches("./"))
+            .map(|file| match base {
+                Some(base) => EntryPattern::with_base(file, base),
+                None => EntryPattern::new(file),
+            })
+            .collect();
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn patterns(entries: &PluginEntries) -> Vec<&str> {
+        entries.get_patterns().iter().map(|p| p.pattern.as_str()).collect()
+    }
+
+    #[test]
+    fn test_should_enable_with_karma() {
+        let plugin = KarmaPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("karma".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_karma() {
+        let plugin = KarmaPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("jasmine".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_no_config_file() {
+        let plugin = KarmaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        assert!(plugin.detect_entries(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_string_and_object_file_entries() {
+        let plugin = KarmaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+module.exports = function (config) {
+  config.set({
+    frameworks: ['jasmine'],
+    files: [
+      'src/**/*.spec.js',
+      { pattern: 'test/helpers/*.js', watched: false },
+      { pattern: 'test/fixtures/**/*.json', included: false },
+    ],
+    browsers: ['ChromeHeadless'],
+  });
+};
+"#;
+        fs::write(temp.path().join("karma.conf.js"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            patterns(&entries),
+            vec!["src/**/*.spec.js", "test/helpers/*.js", "test/fixtures/**/*.json"]
+        );
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("karma.conf.js"));
+    }
+
+    #[test]
+    fn test_arrow_function_with_base_path() {
+        let plugin = KarmaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+export default (config: any) => {
+  config.set({
+    basePath: './client/',
+    files: ['./app/**/*.test.ts'],
+  });
+};
+"#;
+        fs::write(temp.path().join("karma.conf.ts"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let patterns = entries.get_patterns();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[0].pattern, "app/**/*.test.ts");
+        assert_eq!(patterns[0].base.as_deref(), Some(Path::new("client")));
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: KarmaPlugin = Default::default();
+    }
+}
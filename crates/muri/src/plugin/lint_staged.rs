@@ -1,5 +1,12 @@
+use super::husky::extract_script_files;
 use super::{Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::Visit;
+use oxc_ast::ast::{StringLiteral, TemplateLiteral};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 use rustc_hash::FxHashSet;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 /// Plugin to discover lint-staged config files as entry points.
@@ -7,8 +14,43 @@ use std::path::{Path, PathBuf};
 /// lint-staged config files can import/require other local files like
 /// custom scripts or shared configurations. By adding the config file
 /// as an entry point, normal import tracing will discover these dependencies.
+/// Local scripts run by the configured commands (e.g. `node scripts/check.js`)
+/// are entry points too.
 pub struct LintStagedPlugin;
 
+/// Collects string and template literal text from a JS config, where a command
+/// may be a string or built by a function from the staged file list
+#[derive(Default)]
+struct CommandVisitor {
+    commands: Vec<String>,
+}
+
+impl<'a> Visit<'a> for CommandVisitor {
+    fn visit_string_literal(&mut self, lit: &StringLiteral<'a>) {
+        self.commands.push(lit.value.to_string());
+    }
+
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        let text: Vec<&str> = lit.quasis.iter().map(|quasi| quasi.value.raw.as_str()).collect();
+        self.commands.push(text.join(" "));
+        oxc_ast::visit::walk::walk_template_literal(self, lit);
+    }
+}
+
+/// Collect the command strings of a JSON config: `{ "<glob>": "cmd" | ["cmd", ...] }`
+fn json_commands(value: &serde_json::Value, commands: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(command) => commands.push(command.clone()),
+        serde_json::Value::Array(items) => {
+            items.iter().for_each(|item| json_commands(item, commands))
+        }
+        serde_json::Value::Object(map) => {
+            map.values().for_each(|item| json_commands(item, commands))
+        }
+        _ => {}
+    }
+}
+
 impl LintStagedPlugin {
     pub fn new() -> Self {
         Self
@@ -46,6 +88,54 @@ impl LintStagedPlugin {
 
         found
     }
+
+    /// Extract the commands configured in a lint-staged config file
+    fn parse_commands(&self, config_path: &Path) -> Result<Vec<String>, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let ext = config_path.extension().and_then(|ext| ext.to_str());
+
+        if matches!(ext, Some("js" | "mjs" | "cjs")) {
+            let allocator = Allocator::default();
+            let source_type = SourceType::from_path(config_path).unwrap_or_default();
+            let parsed = Parser::new(&allocator, &content, source_type).parse();
+            if parsed.panicked {
+                return Err(PluginError::ConfigParse(format!(
+                    "Failed to parse {}",
+                    config_path.display()
+                )));
+            }
+            let mut visitor = CommandVisitor::default();
+            visitor.visit_program(&parsed.program);
+            return Ok(visitor.commands);
+        }
+
+        // `.lintstagedrc` may hold JSON or YAML
+        if !matches!(ext, Some("yaml" | "yml")) {
+            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+                let mut commands = Vec::new();
+                json_commands(&json, &mut commands);
+                return Ok(commands);
+            }
+        }
+
+        // YAML: commands are plain or quoted values; dropping the quotes leaves
+        // each command separated by whitespace
+        Ok(content.lines().map(|line| line.replace(['"', '\''], " ")).collect())
+    }
+
+    /// Read commands from the `lint-staged` key of package.json
+    fn package_json_commands(&self, cwd: &Path) -> Vec<String> {
+        let mut commands = Vec::new();
+        let Ok(content) = fs::read_to_string(cwd.join("package.json")) else {
+            return commands;
+        };
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+            if let Some(config) = json.get("lint-staged") {
+                json_commands(config, &mut commands);
+            }
+        }
+        commands
+    }
 }
 
 impl Default for LintStagedPlugin {
@@ -64,10 +154,24 @@ impl Plugin for LintStagedPlugin {
     }
 
     fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
-        // Simply return config files as entry points.
-        // The normal import/require tracing will discover any local dependencies
-        // (like custom scripts, shared configs, etc.)
-        Ok(PluginEntries::paths(self.find_config_files(cwd)))
+        // Config files are entry points; the normal import/require tracing will
+        // discover any local dependencies (like custom scripts, shared configs, etc.)
+        let mut paths = self.find_config_files(cwd);
+
+        let mut commands = self.package_json_commands(cwd);
+        for config in &paths {
+            commands.extend(self.parse_commands(config).unwrap_or_default());
+        }
+
+        let mut scripts = extract_script_files(&commands.join("\n"), cwd);
+        scripts.sort();
+        for script in scripts {
+            if !paths.contains(&script) {
+                paths.push(script);
+            }
+        }
+
+        Ok(PluginEntries::paths(paths))
     }
 }
 
@@ -264,6 +368,78 @@ module.exports = {
         assert_eq!(paths.len(), 2);
     }
 
+    fn write_script(root: &Path, file: &str) {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "").unwrap();
+    }
+
+    #[test]
+    fn test_object_config_references_local_script() {
+        let plugin = LintStagedPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_script(temp.path(), "scripts/check.js");
+        write_script(temp.path(), "scripts/unused.js");
+        let config = r#"{
+  "*.ts": ["eslint --fix", "node scripts/check.js"],
+  "*.md": "prettier --write"
+}"#;
+        fs::write(temp.path().join(".lintstagedrc.json"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with(".lintstagedrc.json"));
+        assert!(paths[1].ends_with("scripts/check.js"));
+    }
+
+    #[test]
+    fn test_function_config_references_local_script() {
+        let plugin = LintStagedPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_script(temp.path(), "scripts/typecheck.ts");
+        let config = r#"
+module.exports = {
+  '*.{ts,tsx}': (files) => [
+    `npx tsx scripts/typecheck.ts ${files.join(' ')}`,
+    'eslint --fix',
+  ],
+};
+"#;
+        fs::write(temp.path().join("lint-staged.config.js"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with("lint-staged.config.js"));
+        assert!(paths[1].ends_with("scripts/typecheck.ts"));
+    }
+
+    #[test]
+    fn test_package_json_and_yaml_commands() {
+        let plugin = LintStagedPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_script(temp.path(), "scripts/a.js");
+        write_script(temp.path(), "scripts/b.mjs");
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "lint-staged": { "*.js": "node ./scripts/a.js" } }"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join(".lintstagedrc.yml"), "'*.js':\n  - \"node scripts/b.mjs\"\n")
+            .unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with(".lintstagedrc.yml"));
+        assert!(paths[1].ends_with("scripts/a.js"));
+        assert!(paths[2].ends_with("scripts/b.mjs"));
+    }
+
     #[test]
     fn test_plugin_name() {
         let plugin = LintStagedPlugin::new();
@@ -0,0 +1,398 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrayExpressionElement, Expression, ObjectPropertyKind, PropertyKey, Statement,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config files Mocha looks for, in priority order
+const CONFIG_FILES: &[&str] = &[
+    ".mocharc.js",
+    ".mocharc.cjs",
+    ".mocharc.yaml",
+    ".mocharc.yml",
+    ".mocharc.jsonc",
+    ".mocharc.json",
+];
+
+/// Spec glob Mocha uses when `spec` is not set
+const DEFAULT_SPEC: &str = "test/*.{js,cjs,mjs}";
+
+/// Extensions to try when resolving `require` modules
+const RESOLVE_EXTENSIONS: &[&str] = &[".js", ".ts", ".mjs", ".cjs"];
+
+/// Plugin to discover Mocha spec files, `require` hooks, and `.mocharc` as entry points
+pub struct MochaPlugin;
+
+/// `spec` and `require` options from a Mocha config
+#[derive(Debug, Default, PartialEq)]
+struct MochaConfig {
+    spec: Vec<String>,
+    require: Vec<String>,
+}
+
+impl MochaPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find the `.mocharc` file in the project root
+    fn find_config_file(&self, cwd: &Path) -> Option<PathBuf> {
+        CONFIG_FILES.iter().map(|name| cwd.join(name)).find(|path| path.is_file())
+    }
+
+    /// Parse a `.mocharc` file based on its extension
+    fn parse_config(&self, config_path: &Path) -> Result<MochaConfig, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        match config_path.extension().and_then(|ext| ext.to_str()) {
+            Some("js" | "cjs") => self.parse_js_config(&content, config_path),
+            Some("yaml" | "yml") => Ok(self.parse_yaml_config(&content)),
+            _ => self.parse_json_config(&content),
+        }
+    }
+
+    /// Parse `.mocharc.json`/`.mocharc.jsonc`, or the `mocha` key from package.json
+    fn parse_json_config(&self, content: &str) -> Result<MochaConfig, PluginError> {
+        let mut content = content.to_string();
+        json_strip_comments::strip(&mut content)
+            .map_err(|e| PluginError::ConfigParse(e.to_string()))?;
+        let json: serde_json::Value =
+            serde_json::from_str(&content).map_err(|e| PluginError::ConfigParse(e.to_string()))?;
+        Ok(self.config_from_json(&json))
+    }
+
+    /// Read `spec` and `require`, each either a string or an array of strings
+    fn config_from_json(&self, json: &serde_json::Value) -> MochaConfig {
+        let strings = |key: &str| -> Vec<String> {
+            match json.get(key) {
+                Some(serde_json::Value::String(s)) => vec![s.clone()],
+                Some(serde_json::Value::Array(arr)) => {
+                    arr.iter().filter_map(|s| s.as_str().map(String::from)).collect()
+                }
+                _ => Vec::new(),
+            }
+        };
+
+        MochaConfig { spec: strings("spec"), require: strings("require") }
+    }
+
+    /// Parse `.mocharc.js` (`module.exports = { ... }`)
+    fn parse_js_config(
+        &self,
+        content: &str,
+        config_path: &Path,
+    ) -> Result<MochaConfig, PluginError> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        let mut config = MochaConfig::default();
+
+        for stmt in &parsed.program.body {
+            let Statement::ExpressionStatement(expr_stmt) = stmt else {
+                continue;
+            };
+            let Expression::AssignmentExpression(assign) = &expr_stmt.expression else {
+                continue;
+            };
+            let Expression::ObjectExpression(obj) = &assign.right else {
+                continue;
+            };
+
+            for prop in &obj.properties {
+                let ObjectPropertyKind::ObjectProperty(property) = prop else {
+                    continue;
+                };
+                let key_name = match &property.key {
+                    PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+                    PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+                    _ => continue,
+                };
+                let values = match &property.value {
+                    Expression::StringLiteral(lit) => vec![lit.value.to_string()],
+                    Expression::ArrayExpression(arr) => self.string_elements(&arr.elements),
+                    _ => continue,
+                };
+
+                match key_name {
+                    "spec" => config.spec = values,
+                    "require" => config.require = values,
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// String literal elements of an array expression
+    fn string_elements(&self, elements: &[ArrayExpressionElement]) -> Vec<String> {
+        elements
+            .iter()
+            .filter_map(|elem| match elem.as_expression() {
+                Some(Expression::StringLiteral(lit)) => Some(lit.value.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Extract top-level `spec` and `require` from `.mocharc.yml`. Values may be a
+    /// scalar (`spec: test/**/*.spec.js`), a flow list (`[a, b]`), or a block list.
+    fn parse_yaml_config(&self, content: &str) -> MochaConfig {
+        let mut config = MochaConfig::default();
+        let mut current: Option<&mut Vec<String>> = None;
+
+        let unquote = |value: &str| -> String {
+            let value = value.split(" #").next().unwrap_or(value).trim();
+            value.trim_matches(|c| c == '"' || c == '\'').to_string()
+        };
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if !line.starts_with([' ', '\t', '-']) {
+                let (key, value) = trimmed.split_once(':').unwrap_or((trimmed, ""));
+                let target = match key.trim() {
+                    "spec" => &mut config.spec,
+                    "require" => &mut config.require,
+                    _ => {
+                        current = None;
+                        continue;
+                    }
+                };
+
+                let value = value.trim();
+                if let Some(list) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                    target.extend(list.split(',').map(unquote).filter(|v| !v.is_empty()));
+                } else if !value.is_empty() {
+                    target.push(unquote(value));
+                }
+                current = Some(target);
+                continue;
+            }
+
+            if let (Some(target), Some(item)) = (current.as_mut(), trimmed.strip_prefix('-')) {
+                let item = unquote(item);
+                if !item.is_empty() {
+                    target.push(item);
+                }
+            }
+        }
+
+        config
+    }
+
+    /// Resolve local `require` hooks; package names (e.g. `ts-node/register`) are skipped
+    fn resolve_requires(&self, requires: &[String], cwd: &Path) -> Vec<PathBuf> {
+        requires
+            .iter()
+            .filter(|module| module.starts_with('.'))
+            .filter_map(|module| {
+                let target = cwd.join(module);
+                std::iter::once(target.clone())
+                    .chain(
+                        RESOLVE_EXTENSIONS
+                            .iter()
+                            .map(|ext| PathBuf::from(format!("{}{ext}", target.to_string_lossy()))),
+                    )
+                    .find(|path| path.is_file())
+            })
+            .filter_map(|path| path.canonicalize().ok())
+            .collect()
+    }
+}
+
+impl Default for MochaPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for MochaPlugin {
+    fn name(&self) -> &str {
+        "mocha"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("mocha")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let mut paths = Vec::new();
+
+        // A `.mocharc` file takes precedence over the package.json `mocha` key
+        let config = match self.find_config_file(cwd) {
+            Some(config_path) => {
+                if let Ok(canonical) = config_path.canonicalize() {
+                    paths.push(canonical);
+                }
+                self.parse_config(&config_path).unwrap_or_default()
+            }
+            None => fs::read_to_string(cwd.join("package.json"))
+                .ok()
+                .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+                .and_then(|json| json.get("mocha").map(|mocha| self.config_from_json(mocha)))
+                .unwrap_or_default(),
+        };
+
+        let patterns = if config.spec.is_empty() {
+            vec![EntryPattern::new(DEFAULT_SPEC)]
+        } else {
+            config
+                .spec
+                .iter()
+                .map(|spec| EntryPattern::new(spec.trim_start_matches("./").to_string()))
+                .collect()
+        };
+        paths.extend(self.resolve_requires(&config.require, cwd));
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn patterns(entries: &PluginEntries) -> Vec<&str> {
+        entries.get_patterns().iter().map(|p| p.pattern.as_str()).collect()
+    }
+
+    #[test]
+    fn test_should_enable_with_mocha() {
+        let plugin = MochaPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("mocha".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_mocha() {
+        let plugin = MochaPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("jest".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_default_spec_without_rc() {
+        let plugin = MochaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["test/*.{js,cjs,mjs}"]);
+        assert!(entries.get_paths().is_empty());
+    }
+
+    #[test]
+    fn test_json_rc() {
+        let plugin = MochaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"{
+  // Unit tests only
+  "spec": ["./test/unit/**/*.spec.ts"],
+  "require": ["ts-node/register", "./test/setup"]
+}"#;
+        fs::write(temp.path().join(".mocharc.json"), config_content).unwrap();
+        fs::create_dir(temp.path().join("test")).unwrap();
+        fs::write(temp.path().join("test/setup.ts"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["test/unit/**/*.spec.ts"]);
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with(".mocharc.json"));
+        assert!(paths[1].ends_with("test/setup.ts"));
+    }
+
+    #[test]
+    fn test_js_rc() {
+        let plugin = MochaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+'use strict';
+
+module.exports = {
+  spec: 'spec/**/*.test.js',
+  require: './spec/hooks.js',
+  timeout: 5000,
+};
+"#;
+        fs::write(temp.path().join(".mocharc.js"), config_content).unwrap();
+        fs::create_dir(temp.path().join("spec")).unwrap();
+        fs::write(temp.path().join("spec/hooks.js"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["spec/**/*.test.js"]);
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with(".mocharc.js"));
+        assert!(paths[1].ends_with("spec/hooks.js"));
+    }
+
+    #[test]
+    fn test_yaml_rc() {
+        let plugin = MochaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+# Mocha config
+require:
+  - '@babel/register'
+  - ./test/fixtures.js
+spec: [test/a/*.js, "test/b/*.js"]
+recursive: true
+"#;
+        fs::write(temp.path().join(".mocharc.yml"), config_content).unwrap();
+        fs::create_dir(temp.path().join("test")).unwrap();
+        fs::write(temp.path().join("test/fixtures.js"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["test/a/*.js", "test/b/*.js"]);
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[1].ends_with("test/fixtures.js"));
+    }
+
+    #[test]
+    fn test_package_json_mocha_key() {
+        let plugin = MochaPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(temp.path().join("package.json"), r#"{ "mocha": { "spec": "tests/**/*.js" } }"#)
+            .unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["tests/**/*.js"]);
+        assert!(entries.get_paths().is_empty());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: MochaPlugin = Default::default();
+    }
+}
@@ -1,33 +1,71 @@
+mod angular;
+mod ava;
+mod babel;
+mod capacitor;
 mod cypress;
+mod electron;
+mod esbuild;
 mod eslint;
+mod gatsby;
 mod husky;
 mod jest;
+mod karma;
 mod lint_staged;
+mod mocha;
+mod msw;
 mod nextjs;
+mod nx;
 mod playwright;
 mod postcss;
+mod qwik;
 mod registry;
+mod rollup;
+mod solid_start;
+mod stencil;
 mod storybook;
 mod tailwind;
+mod turbo;
 mod typescript;
 mod vite;
 mod vitest;
+mod wdio;
 
+pub use angular::AngularPlugin;
+pub use ava::AvaPlugin;
+pub use babel::BabelPlugin;
+pub use capacitor::CapacitorPlugin;
 pub use cypress::CypressPlugin;
+pub use electron::ElectronPlugin;
+pub use esbuild::EsbuildPlugin;
 pub use eslint::EslintPlugin;
+pub use gatsby::GatsbyPlugin;
 pub use husky::HuskyPlugin;
 pub use jest::JestPlugin;
+pub use karma::KarmaPlugin;
 pub use lint_staged::LintStagedPlugin;
+pub use mocha::MochaPlugin;
+pub use msw::MswPlugin;
 pub use nextjs::NextjsPlugin;
+pub use nx::NxPlugin;
 pub use playwright::PlaywrightPlugin;
 pub use postcss::PostcssPlugin;
-pub use registry::PluginRegistry;
+pub use qwik::QwikPlugin;
+pub use registry::{ActivePlugin, DetectedEntries, PluginRegistry, PluginTrigger};
+pub use rollup::RollupPlugin;
+pub use solid_start::SolidStartPlugin;
+pub use stencil::StencilPlugin;
 pub use storybook::StorybookPlugin;
 pub use tailwind::TailwindPlugin;
+pub use turbo::TurboPlugin;
 pub use typescript::TypescriptPlugin;
 pub use vite::VitePlugin;
 pub use vitest::VitestPlugin;
+pub use wdio::WdioPlugin;
 
+pub(crate) use typescript::strip_json_comments;
+
+use crate::dependencies::DependencyInfo;
+use crate::types::PluginSettings;
 use rustc_hash::FxHashSet;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
@@ -47,7 +85,7 @@ pub enum PluginError {
 /// Patterns are matched relative to the base directory (or cwd if None).
 /// This allows plugins like Storybook to specify patterns like `**/*.stories.tsx`
 /// with a base of `src/` to match files in `src/**/*.stories.tsx`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EntryPattern {
     /// The glob pattern (e.g., "**/*.stories.tsx")
     pub pattern: String,
@@ -67,6 +105,25 @@ impl EntryPattern {
     }
 }
 
+/// A module name mapping from a tool's config, e.g. Jest's
+/// `"^@/(.*)$": "<rootDir>/src/$1"`.
+///
+/// `pattern` is a regex matched against import specifiers; each target may use
+/// `$1`-style capture references and is either an absolute path or a package
+/// name. Targets are tried in order. The resolver only applies aliases to
+/// imports that don't resolve otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleAlias {
+    pub pattern: String,
+    pub targets: Vec<String>,
+}
+
+impl ModuleAlias {
+    pub fn new(pattern: impl Into<String>, targets: Vec<String>) -> Self {
+        Self { pattern: pattern.into(), targets }
+    }
+}
+
 /// What a plugin returns: patterns to match during collection, or already-resolved paths.
 ///
 /// - `Patterns`: Glob patterns that will be matched during the single filesystem walk.
@@ -75,6 +132,9 @@ impl EntryPattern {
 ///   specific locations). Used by plugins that return a fixed set of known files.
 /// - `Mixed`: Both patterns and paths. Used by plugins that need both behaviors
 ///   (e.g., Jest returns config files as paths and test patterns as patterns).
+///   Also carries `ignore` patterns, set with [`PluginEntries::with_ignore`], which
+///   exclude files from plugin pattern matches when patterns over-match, and module
+///   aliases for the resolver, set with [`PluginEntries::with_aliases`].
 #[derive(Debug, Clone, Default)]
 pub enum PluginEntries {
     #[default]
@@ -84,6 +144,8 @@ pub enum PluginEntries {
     Mixed {
         patterns: Vec<EntryPattern>,
         paths: Vec<PathBuf>,
+        ignore: Vec<EntryPattern>,
+        aliases: Vec<ModuleAlias>,
     },
 }
 
@@ -109,7 +171,38 @@ impl PluginEntries {
             (true, true) => Self::Empty,
             (true, false) => Self::Paths(paths),
             (false, true) => Self::Patterns(patterns),
-            (false, false) => Self::Mixed { patterns, paths },
+            (false, false) => {
+                Self::Mixed { patterns, paths, ignore: Vec::new(), aliases: Vec::new() }
+            }
+        }
+    }
+
+    /// Exclude files matching `ignore` from the files matched by plugin patterns.
+    /// Paths returned directly are kept.
+    pub fn with_ignore(self, ignore: Vec<EntryPattern>) -> Self {
+        if ignore.is_empty() {
+            return self;
+        }
+        let (patterns, paths, _, aliases) = self.into_parts();
+        Self::Mixed { patterns, paths, ignore, aliases }
+    }
+
+    /// Add module aliases for the resolver (e.g. Jest's `moduleNameMapper`)
+    pub fn with_aliases(self, aliases: Vec<ModuleAlias>) -> Self {
+        if aliases.is_empty() {
+            return self;
+        }
+        let (patterns, paths, ignore, _) = self.into_parts();
+        Self::Mixed { patterns, paths, ignore, aliases }
+    }
+
+    /// Split into patterns, paths, ignore patterns, and aliases
+    fn into_parts(self) -> (Vec<EntryPattern>, Vec<PathBuf>, Vec<EntryPattern>, Vec<ModuleAlias>) {
+        match self {
+            Self::Empty => (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
+            Self::Patterns(patterns) => (patterns, Vec::new(), Vec::new(), Vec::new()),
+            Self::Paths(paths) => (Vec::new(), paths, Vec::new(), Vec::new()),
+            Self::Mixed { patterns, paths, ignore, aliases } => (patterns, paths, ignore, aliases),
         }
     }
 
@@ -133,6 +226,22 @@ impl PluginEntries {
         }
     }
 
+    /// Get the ignore patterns from this PluginEntries (for testing/inspection)
+    pub fn get_ignore(&self) -> Vec<&EntryPattern> {
+        match self {
+            Self::Mixed { ignore, .. } => ignore.iter().collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Get the module aliases from this PluginEntries (for testing/inspection)
+    pub fn get_aliases(&self) -> Vec<&ModuleAlias> {
+        match self {
+            Self::Mixed { aliases, .. } => aliases.iter().collect(),
+            _ => vec![],
+        }
+    }
+
     /// Check if this PluginEntries is empty
     pub fn is_empty(&self) -> bool {
         matches!(self, Self::Empty)
@@ -144,7 +253,7 @@ impl PluginEntries {
             Self::Empty => 0,
             Self::Patterns(patterns) => patterns.len(),
             Self::Paths(paths) => paths.len(),
-            Self::Mixed { patterns, paths } => patterns.len() + paths.len(),
+            Self::Mixed { patterns, paths, .. } => patterns.len() + paths.len(),
         }
     }
 }
@@ -157,6 +266,13 @@ pub trait Plugin: Send + Sync {
     /// Check if this plugin should be enabled based on project dependencies
     fn should_enable(&self, cwd: &Path, dependencies: &FxHashSet<String>) -> bool;
 
+    /// Like [`Plugin::should_enable`], with dependencies split by the package.json
+    /// field declaring them, for plugins that care (e.g. only installed packages).
+    /// Defaults to `should_enable` with every dependency.
+    fn should_enable_for(&self, cwd: &Path, dependencies: &DependencyInfo) -> bool {
+        self.should_enable(cwd, &dependencies.all())
+    }
+
     /// Discover entry point files from project configuration.
     ///
     /// Returns either:
@@ -164,4 +280,10 @@ pub trait Plugin: Send + Sync {
     /// - Already-resolved absolute paths (for config files, etc.)
     /// - A mix of both
     fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError>;
+
+    /// Apply user settings from the config file before entries are detected.
+    ///
+    /// Plugins that support a custom config location or other options override this;
+    /// the default ignores the settings.
+    fn configure(&mut self, _settings: &PluginSettings) {}
 }
@@ -0,0 +1,131 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::path::{Path, PathBuf};
+
+/// Conventional MSW modules: request handlers and the worker/server setup
+/// (`setupWorker` in `browser`, `setupServer` in `server` or `node`)
+const MOCK_PATTERNS: &[&str] = &[
+    "**/mocks/{handlers,browser,server,node}.{ts,js,mjs,cjs}",
+    "**/mocks/handlers/**/*.{ts,js,mjs,cjs}",
+];
+
+/// Worker script generated by `msw init`, served by the dev server
+const WORKER_FILES: &[&str] = &["public/mockServiceWorker.js", "static/mockServiceWorker.js"];
+
+/// Plugin to discover Mock Service Worker handler and setup modules.
+///
+/// The mocks are often loaded indirectly, e.g. with a dynamic import guarded by
+/// the environment, or from test setup files that aren't entries themselves.
+/// Test setup files may already import them, so this is a safety net.
+pub struct MswPlugin;
+
+impl MswPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for MswPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for MswPlugin {
+    fn name(&self) -> &str {
+        "msw"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("msw")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let patterns = MOCK_PATTERNS.iter().map(|pattern| EntryPattern::new(*pattern)).collect();
+        let paths: Vec<PathBuf> = WORKER_FILES
+            .iter()
+            .map(|file| cwd.join(file))
+            .filter(|path| path.is_file())
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::build_glob;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_should_enable_with_msw() {
+        let plugin = MswPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("msw".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_msw() {
+        let plugin = MswPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("nock".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_conventional_mocks_layout() {
+        let plugin = MswPlugin::new();
+        let temp = tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("public")).unwrap();
+        fs::write(temp.path().join("public/mockServiceWorker.js"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("public/mockServiceWorker.js"));
+
+        let patterns = entries.get_patterns();
+        let is_mock = |file: &str| {
+            patterns
+                .iter()
+                .any(|p| build_glob(&p.pattern).unwrap().compile_matcher().is_match(file))
+        };
+        for file in [
+            "src/mocks/handlers.ts",
+            "src/mocks/browser.ts",
+            "src/mocks/server.js",
+            "mocks/node.ts",
+            "src/mocks/handlers/user.ts",
+            "apps/web/src/mocks/handlers/billing/invoices.ts",
+        ] {
+            assert!(is_mock(file), "{file} should be an MSW entry");
+        }
+        assert!(!is_mock("src/mocks/fixtures/user.json"));
+        assert!(!is_mock("src/mocks/data.ts"));
+        assert!(!is_mock("src/handlers.ts"));
+    }
+
+    #[test]
+    fn test_without_worker_script() {
+        let plugin = MswPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.get_paths().is_empty());
+        assert_eq!(entries.get_patterns().len(), MOCK_PATTERNS.len());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: MswPlugin = Default::default();
+    }
+}
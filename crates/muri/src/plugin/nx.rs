@@ -0,0 +1,279 @@
+use super::{Plugin, PluginEntries, PluginError};
+use ignore::WalkBuilder;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Target options that point at files an Nx executor builds from
+const ENTRY_OPTIONS: &[&str] = &["main", "tsConfig", "polyfills"];
+
+/// Plugin to discover Nx target entry points from `project.json` files and the
+/// legacy `workspace.json`
+pub struct NxPlugin;
+
+impl NxPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find every `project.json` in the workspace, skipping `node_modules` and
+    /// gitignored directories
+    fn find_project_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = WalkBuilder::new(cwd)
+            .require_git(false)
+            .filter_entry(|entry| entry.file_name() != "node_modules")
+            .build()
+            .flatten()
+            .filter(|entry| entry.file_name() == "project.json")
+            .map(|entry| entry.into_path())
+            .filter(|path| path.is_file())
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// Read a JSON file
+    fn read_json(&self, path: &Path) -> Result<serde_json::Value, PluginError> {
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| {
+            PluginError::ConfigParse(format!("Failed to parse {}: {}", path.display(), e))
+        })
+    }
+
+    /// Collect entry options of every target of a project. Paths are relative
+    /// to the workspace root, with `{workspaceRoot}` and `{projectRoot}` tokens
+    /// expanded.
+    fn collect_targets(
+        &self,
+        project: &serde_json::Value,
+        project_root: &str,
+        files: &mut Vec<String>,
+    ) {
+        let targets = project
+            .get("targets")
+            .or_else(|| project.get("architect"))
+            .and_then(|targets| targets.as_object());
+
+        for target in targets.into_iter().flat_map(|targets| targets.values()) {
+            let Some(options) = target.get("options") else {
+                continue;
+            };
+
+            for key in ENTRY_OPTIONS {
+                let values = match options.get(*key) {
+                    Some(serde_json::Value::String(file)) => vec![file.as_str()],
+                    Some(serde_json::Value::Array(list)) => {
+                        list.iter().filter_map(|file| file.as_str()).collect()
+                    }
+                    _ => continue,
+                };
+                files.extend(values.into_iter().map(|file| {
+                    file.replace("{workspaceRoot}/", "").replace("{projectRoot}", project_root)
+                }));
+            }
+        }
+    }
+
+    /// Project root of a `project.json`, relative to the workspace root
+    fn project_root(&self, cwd: &Path, project_file: &Path) -> String {
+        project_file
+            .parent()
+            .and_then(|dir| dir.strip_prefix(cwd).ok())
+            .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+            .filter(|dir| !dir.is_empty())
+            .unwrap_or_else(|| ".".to_string())
+    }
+}
+
+impl Default for NxPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for NxPlugin {
+    fn name(&self) -> &str {
+        "nx"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("nx")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let mut files = Vec::new();
+
+        for project_file in self.find_project_files(cwd) {
+            let Ok(project) = self.read_json(&project_file) else {
+                continue;
+            };
+            let root = self.project_root(cwd, &project_file);
+            self.collect_targets(&project, &root, &mut files);
+        }
+
+        // Legacy workspaces list projects inline; string values point at a
+        // directory whose `project.json` was already found above
+        let workspace_json = cwd.join("workspace.json");
+        if workspace_json.is_file() {
+            let workspace = self.read_json(&workspace_json)?;
+            let projects = workspace.get("projects").and_then(|p| p.as_object());
+            for project in projects.into_iter().flat_map(|projects| projects.values()) {
+                let root = project.get("root").and_then(|r| r.as_str()).unwrap_or(".");
+                self.collect_targets(project, root, &mut files);
+            }
+        }
+
+        let mut seen = FxHashSet::default();
+        let paths = files
+            .into_iter()
+            .map(|file| cwd.join(file))
+            .filter(|path| path.is_file())
+            .filter_map(|path| path.canonicalize().ok())
+            .filter(|path| seen.insert(path.clone()))
+            .collect();
+
+        Ok(PluginEntries::paths(paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    fn relative_paths(entries: &PluginEntries, root: &Path) -> Vec<String> {
+        let root = root.canonicalize().unwrap();
+        let mut paths: Vec<_> = entries
+            .get_paths()
+            .iter()
+            .map(|p| p.strip_prefix(&root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_should_enable_with_nx() {
+        let plugin = NxPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("nx".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_nx() {
+        let plugin = NxPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("turbo".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_project_json_targets() {
+        let plugin = NxPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let web = r#"{
+  "name": "web",
+  "targets": {
+    "build": {
+      "executor": "@nx/webpack:webpack",
+      "options": {
+        "main": "apps/web/src/main.ts",
+        "tsConfig": "apps/web/tsconfig.app.json",
+        "polyfills": ["apps/web/src/polyfills.ts", "zone.js"]
+      }
+    },
+    "lint": { "executor": "@nx/eslint:lint" }
+  }
+}"#;
+        let api = r#"{
+  "name": "api",
+  "targets": {
+    "build": {
+      "executor": "@nx/esbuild:esbuild",
+      "options": { "main": "{projectRoot}/src/main.ts", "tsConfig": "{projectRoot}/tsconfig.app.json" }
+    },
+    "serve": { "options": { "main": "{workspaceRoot}/apps/api/src/main.ts" } }
+  }
+}"#;
+        write_files(
+            temp.path(),
+            &[
+                "apps/web/src/main.ts",
+                "apps/web/src/polyfills.ts",
+                "apps/web/tsconfig.app.json",
+                "apps/api/src/main.ts",
+                "apps/api/tsconfig.app.json",
+            ],
+        );
+        fs::write(temp.path().join("apps/web/project.json"), web).unwrap();
+        fs::write(temp.path().join("apps/api/project.json"), api).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            relative_paths(&entries, temp.path()),
+            vec![
+                "apps/api/src/main.ts",
+                "apps/api/tsconfig.app.json",
+                "apps/web/src/main.ts",
+                "apps/web/src/polyfills.ts",
+                "apps/web/tsconfig.app.json",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_project_json_in_node_modules_is_skipped() {
+        let plugin = NxPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["node_modules/pkg/src/main.ts"]);
+        fs::write(
+            temp.path().join("node_modules/pkg/project.json"),
+            r#"{ "targets": { "build": { "options": { "main": "node_modules/pkg/src/main.ts" } } } }"#,
+        )
+        .unwrap();
+
+        assert!(plugin.detect_entries(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_legacy_workspace_json() {
+        let plugin = NxPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let workspace = r#"{
+  "version": 2,
+  "projects": {
+    "admin": {
+      "root": "apps/admin",
+      "targets": { "build": { "options": { "main": "apps/admin/src/main.tsx" } } }
+    },
+    "shared": "libs/shared"
+  }
+}"#;
+        write_files(temp.path(), &["apps/admin/src/main.tsx"]);
+        fs::write(temp.path().join("workspace.json"), workspace).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(relative_paths(&entries, temp.path()), vec!["apps/admin/src/main.tsx"]);
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: NxPlugin = Default::default();
+    }
+}
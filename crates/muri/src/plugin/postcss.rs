@@ -1,7 +1,55 @@
-use super::{Plugin, PluginEntries, PluginError};
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use crate::dependencies::detect_dependencies;
+use oxc_allocator::Allocator;
+use oxc_ast::Visit;
+use oxc_ast::ast::{Expression, ObjectProperty, PropertyKey};
+use oxc_ast::visit::walk::walk_object_property;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
 use rustc_hash::FxHashSet;
+use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Config options naming the stylesheets PostCSS processes. `to` names the
+/// generated output, so it isn't an entry.
+const INPUT_OPTIONS: &[&str] = &["from", "content"];
+
+/// Collects `.css` string values of `from`/`content` options in a config file
+#[derive(Default)]
+struct InputVisitor {
+    inputs: Vec<String>,
+}
+
+impl<'a> Visit<'a> for InputVisitor {
+    fn visit_object_property(&mut self, property: &ObjectProperty<'a>) {
+        let is_input = match &property.key {
+            PropertyKey::StaticIdentifier(ident) => INPUT_OPTIONS.contains(&ident.name.as_str()),
+            PropertyKey::StringLiteral(lit) => INPUT_OPTIONS.contains(&lit.value.as_str()),
+            _ => false,
+        };
+
+        if is_input {
+            let values: Vec<&str> = match &property.value {
+                Expression::StringLiteral(lit) => vec![lit.value.as_str()],
+                Expression::ArrayExpression(arr) => arr
+                    .elements
+                    .iter()
+                    .filter_map(|elem| match elem.as_expression() {
+                        Some(Expression::StringLiteral(lit)) => Some(lit.value.as_str()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            self.inputs.extend(
+                values.into_iter().filter(|value| value.ends_with(".css")).map(String::from),
+            );
+        }
+
+        walk_object_property(self, property);
+    }
+}
+
 /// Plugin to discover PostCSS config files as entry points.
 ///
 /// PostCSS config files often require/import other local files like
@@ -52,6 +100,28 @@ impl PostcssPlugin {
 
         found
     }
+
+    /// Extract the input stylesheets (`from`/`content`) of a JS config file.
+    /// JSON and YAML configs can't set these options, so they're skipped.
+    fn parse_inputs(&self, config_path: &Path) -> Result<Vec<String>, PluginError> {
+        let Some(source_type) = SourceType::from_path(config_path).ok() else {
+            return Ok(Vec::new());
+        };
+        let content = fs::read_to_string(config_path)?;
+        let allocator = Allocator::default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        let mut visitor = InputVisitor::default();
+        visitor.visit_program(&parsed.program);
+        Ok(visitor.inputs)
+    }
 }
 
 impl Default for PostcssPlugin {
@@ -70,10 +140,31 @@ impl Plugin for PostcssPlugin {
     }
 
     fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
-        // Simply return config files as entry points.
-        // The normal import/require tracing will discover any local dependencies
-        // (like tailwind.config.js, custom plugins, etc.)
-        Ok(PluginEntries::paths(self.find_config_files(cwd)))
+        // Config files are entry points; the normal import/require tracing will
+        // discover any local dependencies (like tailwind.config.js, custom plugins, etc.)
+        let mut paths = self.find_config_files(cwd);
+
+        // With postcss-import, the stylesheets the config names are roots whose
+        // `@import` chains the CSS compiler follows
+        let mut patterns = Vec::new();
+        if detect_dependencies(cwd).contains("postcss-import") {
+            let inputs: Vec<String> = paths
+                .iter()
+                .flat_map(|config| self.parse_inputs(config).unwrap_or_default())
+                .collect();
+            for input in &inputs {
+                let input = input.trim_start_matches("./");
+                if input.contains(['*', '?', '[', '{']) {
+                    patterns.push(EntryPattern::new(input));
+                } else if let Ok(canonical) = cwd.join(input).canonicalize() {
+                    if !paths.contains(&canonical) {
+                        paths.push(canonical);
+                    }
+                }
+            }
+        }
+
+        Ok(PluginEntries::mixed(patterns, paths))
     }
 }
 
@@ -180,6 +271,58 @@ module.exports = {
         assert!(entries.is_empty());
     }
 
+    #[test]
+    fn test_postcss_import_inputs_are_entries() {
+        let plugin = PostcssPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "devDependencies": { "postcss": "^8.0.0", "postcss-import": "^16.0.0" } }"#,
+        )
+        .unwrap();
+        let config = r#"
+module.exports = {
+  from: './src/styles/main.css',
+  to: 'dist/main.css',
+  content: ['src/themes/*.css', 'src/**/*.html'],
+  plugins: { 'postcss-import': {}, autoprefixer: {} },
+};
+"#;
+        fs::write(temp.path().join("postcss.config.js"), config).unwrap();
+        fs::create_dir_all(temp.path().join("src/styles")).unwrap();
+        fs::write(temp.path().join("src/styles/main.css"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with("postcss.config.js"));
+        assert!(paths[1].ends_with("src/styles/main.css"));
+
+        let patterns = entries.get_patterns();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[0].pattern, "src/themes/*.css");
+    }
+
+    #[test]
+    fn test_inputs_ignored_without_postcss_import() {
+        let plugin = PostcssPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "devDependencies": { "postcss": "^8" } }"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join("postcss.config.js"), "module.exports = { from: 'main.css' };")
+            .unwrap();
+        fs::write(temp.path().join("main.css"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(entries.get_paths().len(), 1);
+        assert!(entries.get_patterns().is_empty());
+    }
+
     #[test]
     fn test_find_multiple_configs() {
         let plugin = PostcssPlugin::new();
@@ -0,0 +1,195 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Qwik City's routing directory
+const ROUTES_DIR: &str = "src/routes";
+
+/// Route files Qwik City picks up by name, including named layouts
+/// (`layout-narrow.tsx`), layout overrides (`index@narrow.tsx`) and middleware
+/// plugins (`plugin@auth.ts`)
+const ROUTE_PATTERNS: &[&str] = &[
+    "**/{index,layout,plugin,menu,service-worker}.{ts,tsx}",
+    "**/{index,plugin}@*.{ts,tsx}",
+    "**/layout-*.{ts,tsx}",
+];
+
+/// Extensions of `src/root` and the `src/entry.*` adapter entries
+const SOURCE_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx"];
+
+/// Plugin to discover Qwik and Qwik City entry points.
+///
+/// Qwik renders from `src/root.tsx`, and each deployment adapter boots from
+/// its own `src/entry.<name>.tsx` (`entry.ssr.tsx`, `entry.express.tsx`, ...).
+/// Qwik City adds directory-based routing under `src/routes/`.
+pub struct QwikPlugin;
+
+impl QwikPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find `src/root.*` and every `src/entry.*` source file
+    fn find_entry_files(&self, cwd: &Path) -> Vec<PathBuf> {
+        let Ok(dir) = fs::read_dir(cwd.join("src")) else {
+            return Vec::new();
+        };
+
+        let mut found: Vec<PathBuf> = dir
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .filter(|path| {
+                let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+                let ext = path.extension().and_then(|e| e.to_str()).unwrap_or_default();
+                SOURCE_EXTENSIONS.contains(&ext)
+                    && (name.starts_with("entry.") || name == format!("root.{ext}"))
+            })
+            .filter_map(|path| path.canonicalize().ok())
+            .collect();
+        found.sort();
+        found
+    }
+}
+
+impl Default for QwikPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for QwikPlugin {
+    fn name(&self) -> &str {
+        "qwik"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("@builder.io/qwik-city") || dependencies.contains("@builder.io/qwik")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let patterns = if cwd.join(ROUTES_DIR).is_dir() {
+            ROUTE_PATTERNS
+                .iter()
+                .map(|pattern| EntryPattern::with_base(*pattern, ROUTES_DIR))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(PluginEntries::mixed(patterns, self.find_entry_files(cwd)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collector::build_glob;
+    use tempfile::tempdir;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_should_enable_with_qwik_city() {
+        let plugin = QwikPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@builder.io/qwik-city".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_enable_with_qwik() {
+        let plugin = QwikPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@builder.io/qwik".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_qwik() {
+        let plugin = QwikPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("vite".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_nested_routes() {
+        let plugin = QwikPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(
+            temp.path(),
+            &[
+                "src/root.tsx",
+                "src/entry.ssr.tsx",
+                "src/entry.dev.tsx",
+                "src/global.css",
+                "src/routes/layout.tsx",
+                "src/routes/index.tsx",
+                "src/routes/plugin@auth.ts",
+                "src/routes/blog/[slug]/index.tsx",
+                "src/routes/blog/layout-narrow.tsx",
+                "src/routes/blog/index@narrow.tsx",
+                "src/routes/blog/components/card.tsx",
+            ],
+        );
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with("src/entry.dev.tsx"));
+        assert!(paths[1].ends_with("src/entry.ssr.tsx"));
+        assert!(paths[2].ends_with("src/root.tsx"));
+
+        let patterns = entries.get_patterns();
+        assert!(patterns.iter().all(|p| p.base == Some(PathBuf::from(ROUTES_DIR))));
+        let is_route = |file: &str| {
+            patterns
+                .iter()
+                .any(|p| build_glob(&p.pattern).unwrap().compile_matcher().is_match(file))
+        };
+        for route in [
+            "layout.tsx",
+            "index.tsx",
+            "plugin@auth.ts",
+            "blog/[slug]/index.tsx",
+            "blog/layout-narrow.tsx",
+            "blog/index@narrow.tsx",
+        ] {
+            assert!(is_route(route), "{route} should be a route file");
+        }
+        assert!(!is_route("blog/components/card.tsx"));
+    }
+
+    #[test]
+    fn test_qwik_without_city() {
+        let plugin = QwikPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["src/root.tsx", "src/entry.ssr.tsx", "src/components/app.tsx"]);
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.get_patterns().is_empty());
+        assert_eq!(entries.get_paths().len(), 2);
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: QwikPlugin = Default::default();
+    }
+}
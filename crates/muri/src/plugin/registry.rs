@@ -1,34 +1,94 @@
-use super::{EntryPattern, Plugin, PluginEntries};
+use super::{EntryPattern, ModuleAlias, Plugin, PluginEntries};
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+/// Entries detected by all registered plugins
+#[derive(Debug, Default)]
+pub struct DetectedEntries {
+    /// Glob patterns to match during the collector's filesystem walk
+    pub patterns: Vec<EntryPattern>,
+    /// Already-resolved absolute paths (config files, etc.)
+    pub paths: Vec<PathBuf>,
+    /// Patterns whose matches are excluded from plugin pattern matches
+    pub ignore: Vec<EntryPattern>,
+    /// Module aliases for imports that don't resolve otherwise
+    pub aliases: Vec<ModuleAlias>,
+}
+
+/// Why a plugin is active
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "enabled_by", content = "dependency", rename_all = "snake_case")]
+pub enum PluginTrigger {
+    /// Enabled explicitly, through plugin settings or by registering it directly
+    Config,
+    /// Enabled by this dependency in package.json
+    Dependency(String),
+    /// Detected another way, e.g. from files in the project or a combination of
+    /// dependencies
+    Detected,
+}
+
+/// An active plugin and what enabled it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActivePlugin {
+    pub name: String,
+    #[serde(flatten)]
+    pub trigger: PluginTrigger,
+}
+
 /// Registry of plugins for discovering entry points
 pub struct PluginRegistry {
     plugins: Vec<Arc<dyn Plugin>>,
+    /// Names of plugins registered through [`PluginRegistry::register_detected`],
+    /// i.e. enabled by auto-detection rather than explicitly
+    detected: FxHashSet<String>,
+    /// Entry patterns added through plugin settings in the config file
+    extra_patterns: Vec<EntryPattern>,
 }
 
 impl PluginRegistry {
     /// Create a new empty registry
     pub fn new() -> Self {
-        Self { plugins: Vec::new() }
+        Self { plugins: Vec::new(), detected: FxHashSet::default(), extra_patterns: Vec::new() }
     }
 
-    /// Register a plugin
+    /// Register an explicitly enabled plugin
     pub fn register(&mut self, plugin: Arc<dyn Plugin>) {
         self.plugins.push(plugin);
     }
 
-    /// Collect all patterns and paths from registered plugins in a single pass.
-    ///
-    /// Returns (patterns, paths) where:
-    /// - patterns: Glob patterns to match during the collector's filesystem walk
-    /// - paths: Already-resolved absolute paths (config files, etc.)
+    /// Register a plugin enabled by auto-detection ([`Plugin::should_enable`])
+    pub fn register_detected(&mut self, plugin: Arc<dyn Plugin>) {
+        self.detected.insert(plugin.name().to_string());
+        self.plugins.push(plugin);
+    }
+
+    /// Add entry patterns that are returned alongside plugin-detected patterns
+    pub fn add_patterns(&mut self, patterns: impl IntoIterator<Item = EntryPattern>) {
+        self.extra_patterns.extend(patterns);
+    }
+
+    /// Collect all patterns, paths, and ignore patterns from registered plugins in
+    /// a single pass.
     ///
     /// This is more efficient than calling collect_patterns() and collect_paths()
     /// separately, as it only calls detect_entries() once per plugin.
-    pub fn collect_all(&self, cwd: &Path) -> (Vec<EntryPattern>, Vec<PathBuf>) {
-        let mut all_patterns = Vec::new();
+    ///
+    /// Paths claimed by several plugins are returned once, canonicalized and sorted;
+    /// duplicate patterns are dropped, keeping the first occurrence.
+    ///
+    /// Patterns are not expanded here: the collector matches them during its single
+    /// walk, which also applies the user's ignore and gitignore rules, and adds the
+    /// canonical matches to the same entry set as these paths. A file both listed
+    /// and matched is therefore a single entry.
+    pub fn collect_all(&self, cwd: &Path) -> DetectedEntries {
+        let mut all_patterns = self.extra_patterns.clone();
         let mut all_paths = Vec::new();
+        let mut all_ignore = Vec::new();
+        let mut all_aliases = Vec::new();
 
         for plugin in &self.plugins {
             match plugin.detect_entries(cwd) {
@@ -40,9 +100,11 @@ impl PluginRegistry {
                     PluginEntries::Paths(paths) => {
                         all_paths.extend(paths);
                     }
-                    PluginEntries::Mixed { patterns, paths } => {
+                    PluginEntries::Mixed { patterns, paths, ignore, aliases } => {
                         all_patterns.extend(patterns);
                         all_paths.extend(paths);
+                        all_ignore.extend(ignore);
+                        all_aliases.extend(aliases);
                     }
                 },
                 Err(e) => {
@@ -51,13 +113,67 @@ impl PluginRegistry {
             }
         }
 
-        (all_patterns, all_paths)
+        let paths: BTreeSet<PathBuf> =
+            all_paths.into_iter().map(|path| path.canonicalize().unwrap_or(path)).collect();
+
+        DetectedEntries {
+            patterns: dedup_patterns(all_patterns),
+            paths: paths.into_iter().collect(),
+            ignore: dedup_patterns(all_ignore),
+            aliases: all_aliases,
+        }
     }
 
     /// Get names of all registered plugins
     pub fn plugin_names(&self) -> Vec<&str> {
         self.plugins.iter().map(|p| p.name()).collect()
     }
+
+    /// Registered plugins with what enabled each, in registration order.
+    ///
+    /// A detected plugin is attributed to the first dependency (alphabetically)
+    /// that enables it on its own; plugins enabled without any dependency, or only
+    /// by several together, are [`PluginTrigger::Detected`].
+    pub fn active_plugins(
+        &self,
+        cwd: &Path,
+        dependencies: &FxHashSet<String>,
+    ) -> Vec<ActivePlugin> {
+        let mut sorted: Vec<&String> = dependencies.iter().collect();
+        sorted.sort();
+
+        self.plugins
+            .iter()
+            .map(|plugin| {
+                let trigger = if !self.detected.contains(plugin.name()) {
+                    PluginTrigger::Config
+                } else if plugin.should_enable(cwd, &FxHashSet::default()) {
+                    PluginTrigger::Detected
+                } else {
+                    sorted
+                        .iter()
+                        .find(|dep| {
+                            plugin.should_enable(cwd, &FxHashSet::from_iter([(**dep).clone()]))
+                        })
+                        .map_or(PluginTrigger::Detected, |dep| {
+                            PluginTrigger::Dependency((*dep).clone())
+                        })
+                };
+                ActivePlugin { name: plugin.name().to_string(), trigger }
+            })
+            .collect()
+    }
+}
+
+/// Drop repeated patterns, keeping the first occurrence
+fn dedup_patterns(all: Vec<EntryPattern>) -> Vec<EntryPattern> {
+    let mut patterns: Vec<EntryPattern> = Vec::with_capacity(all.len());
+    for pattern in all {
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    patterns
 }
 
 impl Default for PluginRegistry {
@@ -65,3 +181,163 @@ impl Default for PluginRegistry {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::PluginError;
+    use rustc_hash::FxHashSet;
+    use std::fs;
+    use tempfile::tempdir;
+
+    /// Plugin returning a fixed set of entries
+    struct StaticPlugin {
+        name: &'static str,
+        patterns: Vec<EntryPattern>,
+        paths: Vec<PathBuf>,
+        ignore: Vec<EntryPattern>,
+    }
+
+    impl Plugin for StaticPlugin {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn should_enable(&self, _cwd: &Path, _dependencies: &FxHashSet<String>) -> bool {
+            true
+        }
+
+        fn detect_entries(&self, _cwd: &Path) -> Result<PluginEntries, PluginError> {
+            Ok(PluginEntries::mixed(self.patterns.clone(), self.paths.clone())
+                .with_ignore(self.ignore.clone()))
+        }
+    }
+
+    #[test]
+    fn test_overlapping_entries_are_deduplicated_and_sorted() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::create_dir(cwd.join("__tests__")).unwrap();
+        fs::write(cwd.join("__tests__/a.test.ts"), "").unwrap();
+        fs::write(cwd.join("setup.ts"), "").unwrap();
+
+        let mut registry = PluginRegistry::new();
+        registry.register(Arc::new(StaticPlugin {
+            name: "jest",
+            patterns: vec![EntryPattern::new("**/__tests__/**/*.ts")],
+            paths: vec![cwd.join("setup.ts"), cwd.join("__tests__/a.test.ts")],
+            ignore: vec![],
+        }));
+        registry.register(Arc::new(StaticPlugin {
+            name: "vitest",
+            patterns: vec![EntryPattern::new("**/__tests__/**/*.ts")],
+            // The same file through a non-canonical path
+            paths: vec![cwd.join("__tests__/../__tests__/a.test.ts")],
+            ignore: vec![],
+        }));
+
+        let detected = registry.collect_all(&cwd);
+        assert_eq!(detected.patterns, vec![EntryPattern::new("**/__tests__/**/*.ts")]);
+        assert_eq!(detected.paths, vec![cwd.join("__tests__/a.test.ts"), cwd.join("setup.ts")]);
+        assert!(detected.ignore.is_empty());
+    }
+
+    #[test]
+    fn test_path_matched_by_another_plugins_pattern_is_kept_once() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("vitest.setup.ts"), "").unwrap();
+
+        let mut registry = PluginRegistry::new();
+        registry.register(Arc::new(StaticPlugin {
+            name: "vitest",
+            patterns: vec![],
+            paths: vec![cwd.join("./vitest.setup.ts")],
+            ignore: vec![],
+        }));
+        registry.register(Arc::new(StaticPlugin {
+            name: "jest",
+            patterns: vec![EntryPattern::new("*.setup.ts")],
+            paths: vec![],
+            ignore: vec![],
+        }));
+
+        // The path stays a plugin path (so it's classified as config); the pattern
+        // is left for the collector walk
+        let detected = registry.collect_all(&cwd);
+        assert_eq!(detected.paths, vec![cwd.join("vitest.setup.ts")]);
+        assert_eq!(detected.patterns, vec![EntryPattern::new("*.setup.ts")]);
+    }
+
+    #[test]
+    fn test_ignore_patterns_are_collected() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+
+        let mut registry = PluginRegistry::new();
+        for name in ["cypress", "playwright"] {
+            registry.register(Arc::new(StaticPlugin {
+                name,
+                patterns: vec![EntryPattern::new("**/*.ts")],
+                paths: vec![],
+                ignore: vec![EntryPattern::new("**/*.d.ts")],
+            }));
+        }
+
+        let detected = registry.collect_all(&cwd);
+        assert_eq!(detected.patterns, vec![EntryPattern::new("**/*.ts")]);
+        assert_eq!(detected.ignore, vec![EntryPattern::new("**/*.d.ts")]);
+        assert!(detected.paths.is_empty());
+    }
+
+    /// Plugin enabled when all of `needs` are dependencies
+    struct NeedsPlugin {
+        name: &'static str,
+        needs: &'static [&'static str],
+    }
+
+    impl Plugin for NeedsPlugin {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+            self.needs.iter().all(|need| dependencies.contains(*need))
+        }
+
+        fn detect_entries(&self, _cwd: &Path) -> Result<PluginEntries, PluginError> {
+            Ok(PluginEntries::Empty)
+        }
+    }
+
+    #[test]
+    fn test_active_plugins_triggers() {
+        let temp = tempdir().unwrap();
+        let deps: FxHashSet<String> =
+            ["react", "vite", "@vitejs/plugin-react"].iter().map(|dep| dep.to_string()).collect();
+
+        let mut registry = PluginRegistry::new();
+        registry.register_detected(Arc::new(NeedsPlugin { name: "vite", needs: &["vite"] }));
+        registry.register_detected(Arc::new(NeedsPlugin { name: "files", needs: &[] }));
+        registry.register_detected(Arc::new(NeedsPlugin {
+            name: "combined",
+            needs: &["react", "vite"],
+        }));
+        registry.register(Arc::new(NeedsPlugin { name: "forced", needs: &["missing"] }));
+
+        let triggers: Vec<(String, PluginTrigger)> = registry
+            .active_plugins(temp.path(), &deps)
+            .into_iter()
+            .map(|plugin| (plugin.name, plugin.trigger))
+            .collect();
+        assert_eq!(
+            triggers,
+            vec![
+                ("vite".to_string(), PluginTrigger::Dependency("vite".to_string())),
+                ("files".to_string(), PluginTrigger::Detected),
+                ("combined".to_string(), PluginTrigger::Detected),
+                ("forced".to_string(), PluginTrigger::Config),
+            ]
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use super::{Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, ObjectExpression, ObjectPropertyKind, PropertyKey, Statement};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config files the Rollup CLI looks for
+const CONFIG_FILES: &[&str] =
+    &["rollup.config.js", "rollup.config.mjs", "rollup.config.cjs", "rollup.config.ts"];
+
+/// Extensions to try when an `input` has none
+const RESOLVE_EXTENSIONS: &[&str] = &[".js", ".mjs", ".ts", ".tsx", ".jsx"];
+
+/// Plugin to discover Rollup `input` entry points and the config file
+pub struct RollupPlugin;
+
+impl RollupPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find the Rollup config file in the project root
+    fn find_config_file(&self, cwd: &Path) -> Option<PathBuf> {
+        CONFIG_FILES.iter().map(|name| cwd.join(name)).find(|path| path.is_file())
+    }
+
+    /// Extract `input` values from the exported config (`export default`, or
+    /// `module.exports =`), which is an object, an array of objects, or either
+    /// wrapped in `defineConfig(...)`
+    fn parse_inputs(&self, config_path: &Path) -> Result<Vec<String>, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        let mut inputs = Vec::new();
+
+        for stmt in &parsed.program.body {
+            let exported = match stmt {
+                Statement::ExportDefaultDeclaration(export) => export.declaration.as_expression(),
+                Statement::ExpressionStatement(expr_stmt) => match &expr_stmt.expression {
+                    Expression::AssignmentExpression(assign) => Some(&assign.right),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some(expr) = exported {
+                self.extract_from_config(expr, &mut inputs);
+            }
+        }
+
+        Ok(inputs)
+    }
+
+    /// Collect inputs from a config object, an array of configs, or `defineConfig(...)`
+    fn extract_from_config(&self, expr: &Expression, inputs: &mut Vec<String>) {
+        match expr {
+            Expression::ObjectExpression(obj) => self.extract_input(obj, inputs),
+            Expression::ArrayExpression(arr) => {
+                for elem in &arr.elements {
+                    if let Some(expr) = elem.as_expression() {
+                        self.extract_from_config(expr, inputs);
+                    }
+                }
+            }
+            Expression::CallExpression(call) => {
+                if let Some(arg) = call.arguments.first().and_then(|arg| arg.as_expression()) {
+                    self.extract_from_config(arg, inputs);
+                }
+            }
+            Expression::TSAsExpression(expr) => self.extract_from_config(&expr.expression, inputs),
+            Expression::TSSatisfiesExpression(expr) => {
+                self.extract_from_config(&expr.expression, inputs)
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                self.extract_from_config(&expr.expression, inputs)
+            }
+            _ => {}
+        }
+    }
+
+    /// Read `input`: a string, an array of strings, or an `{ name: path }` object
+    fn extract_input(&self, obj: &ObjectExpression, inputs: &mut Vec<String>) {
+        for prop in &obj.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = prop else {
+                continue;
+            };
+            let is_input = match &property.key {
+                PropertyKey::StaticIdentifier(ident) => ident.name == "input",
+                PropertyKey::StringLiteral(lit) => lit.value == "input",
+                _ => false,
+            };
+            if !is_input {
+                continue;
+            }
+
+            match &property.value {
+                Expression::StringLiteral(lit) => inputs.push(lit.value.to_string()),
+                Expression::ArrayExpression(arr) => {
+                    inputs.extend(arr.elements.iter().filter_map(
+                        |elem| match elem.as_expression() {
+                            Some(Expression::StringLiteral(lit)) => Some(lit.value.to_string()),
+                            _ => None,
+                        },
+                    ));
+                }
+                Expression::ObjectExpression(map) => {
+                    inputs.extend(map.properties.iter().filter_map(|prop| match prop {
+                        ObjectPropertyKind::ObjectProperty(p) => match &p.value {
+                            Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+                            _ => None,
+                        },
+                        _ => None,
+                    }));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Resolve an input relative to the project root, trying common extensions
+    fn resolve_input(&self, input: &str, cwd: &Path) -> Option<PathBuf> {
+        let target = cwd.join(input);
+        std::iter::once(target.clone())
+            .chain(
+                RESOLVE_EXTENSIONS
+                    .iter()
+                    .map(|ext| PathBuf::from(format!("{}{ext}", target.to_string_lossy()))),
+            )
+            .find(|path| path.is_file())
+            .and_then(|path| path.canonicalize().ok())
+    }
+}
+
+impl Default for RollupPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for RollupPlugin {
+    fn name(&self) -> &str {
+        "rollup"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("rollup")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let Some(config_path) = self.find_config_file(cwd) else {
+            return Ok(PluginEntries::Empty);
+        };
+
+        let mut paths = Vec::new();
+        if let Ok(canonical) = config_path.canonicalize() {
+            paths.push(canonical);
+        }
+
+        let inputs = self.parse_inputs(&config_path).unwrap_or_default();
+        for input in inputs {
+            if let Some(path) = self.resolve_input(&input, cwd) {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+
+        Ok(PluginEntries::paths(paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    fn relative_paths(entries: &PluginEntries, root: &Path) -> Vec<String> {
+        let root = root.canonicalize().unwrap();
+        entries
+            .get_paths()
+            .iter()
+            .map(|p| p.strip_prefix(&root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect()
+    }
+
+    #[test]
+    fn test_should_enable_with_rollup() {
+        let plugin = RollupPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("rollup".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_rollup() {
+        let plugin = RollupPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("esbuild".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_string_input() {
+        let plugin = RollupPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["src/main.js"]);
+        fs::write(
+            temp.path().join("rollup.config.mjs"),
+            "export default { input: 'src/main.js', output: { dir: 'dist' } };",
+        )
+        .unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(relative_paths(&entries, temp.path()), vec!["rollup.config.mjs", "src/main.js"]);
+    }
+
+    #[test]
+    fn test_array_input_in_config_array() {
+        let plugin = RollupPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config = r#"
+module.exports = [
+  { input: ['src/index.ts', 'src/cli'], output: { dir: 'dist' } },
+  { input: 'src/index.ts', output: { file: 'dist/index.d.ts' } },
+];
+"#;
+        write_files(temp.path(), &["src/index.ts", "src/cli.ts"]);
+        fs::write(temp.path().join("rollup.config.js"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            relative_paths(&entries, temp.path()),
+            vec!["rollup.config.js", "src/index.ts", "src/cli.ts"]
+        );
+    }
+
+    #[test]
+    fn test_object_input_with_define_config() {
+        let plugin = RollupPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config = r#"
+import { defineConfig } from 'rollup';
+
+export default defineConfig({
+  input: { main: 'src/main.ts', 'vendor/polyfills': './src/polyfills.ts' },
+  output: { dir: 'dist', format: 'es' },
+});
+"#;
+        write_files(temp.path(), &["src/main.ts", "src/polyfills.ts"]);
+        fs::write(temp.path().join("rollup.config.ts"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            relative_paths(&entries, temp.path()),
+            vec!["rollup.config.ts", "src/main.ts", "src/polyfills.ts"]
+        );
+    }
+
+    #[test]
+    fn test_no_config_file() {
+        let plugin = RollupPlugin::new();
+        let temp = tempdir().unwrap();
+
+        assert!(plugin.detect_entries(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: RollupPlugin = Default::default();
+    }
+}
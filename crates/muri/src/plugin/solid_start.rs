@@ -0,0 +1,174 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::path::{Path, PathBuf};
+
+/// SolidStart config files (Vinxi's `app.config`)
+const CONFIG_FILES: &[&str] = &["app.config.ts", "app.config.js", "app.config.mjs"];
+
+/// SolidStart's client and server entry files
+const START_ENTRIES: &[&str] = &[
+    "src/entry-client.tsx",
+    "src/entry-client.jsx",
+    "src/entry-server.tsx",
+    "src/entry-server.jsx",
+];
+
+/// Entry of a plain SolidJS app mounted with `render()`
+const SOLID_ENTRIES: &[&str] = &["src/index.tsx", "src/index.jsx"];
+
+/// Plugin to discover SolidStart and SolidJS entry points.
+///
+/// SolidStart turns every file in `src/routes/` into a route (or an API
+/// route) and boots from `src/entry-client` and `src/entry-server`, configured
+/// by `app.config.ts`. Without a SolidStart config, the project is treated as
+/// a plain SolidJS app mounted from `src/index`.
+pub struct SolidStartPlugin;
+
+impl SolidStartPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Canonical paths of the given project-relative files that exist
+    fn existing_files(&self, cwd: &Path, files: &[&str]) -> Vec<PathBuf> {
+        files
+            .iter()
+            .map(|file| cwd.join(file))
+            .filter(|path| path.is_file())
+            .filter_map(|path| path.canonicalize().ok())
+            .collect()
+    }
+}
+
+impl Default for SolidStartPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for SolidStartPlugin {
+    fn name(&self) -> &str {
+        "solid-start"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("@solidjs/start") || dependencies.contains("solid-js")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let configs = self.existing_files(cwd, CONFIG_FILES);
+        if configs.is_empty() {
+            return Ok(PluginEntries::paths(self.existing_files(cwd, SOLID_ENTRIES)));
+        }
+
+        let patterns = vec![EntryPattern::with_base("**/*.{ts,tsx,js,jsx}", "src/routes")];
+        let mut paths = self.existing_files(cwd, START_ENTRIES);
+        paths.extend(configs);
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_should_enable_with_solid_start() {
+        let plugin = SolidStartPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@solidjs/start".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_enable_with_solid_js() {
+        let plugin = SolidStartPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("solid-js".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_solid() {
+        let plugin = SolidStartPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("react".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_solid_start_routes_and_entries() {
+        let plugin = SolidStartPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(
+            temp.path(),
+            &[
+                "app.config.ts",
+                "src/entry-client.tsx",
+                "src/entry-server.tsx",
+                "src/index.tsx",
+                "src/routes/index.tsx",
+                "src/routes/blog/[slug].tsx",
+            ],
+        );
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+
+        let patterns = entries.get_patterns();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[0].pattern, "**/*.{ts,tsx,js,jsx}");
+        assert_eq!(patterns[0].base, Some(PathBuf::from("src/routes")));
+
+        // `src/index.tsx` isn't an entry of a SolidStart app
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with("src/entry-client.tsx"));
+        assert!(paths[1].ends_with("src/entry-server.tsx"));
+        assert!(paths[2].ends_with("app.config.ts"));
+    }
+
+    #[test]
+    fn test_plain_solid_js_index() {
+        let plugin = SolidStartPlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["src/index.jsx", "src/App.jsx"]);
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.get_patterns().is_empty());
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("src/index.jsx"));
+    }
+
+    #[test]
+    fn test_no_solid_files() {
+        let plugin = SolidStartPlugin::new();
+        let temp = tempdir().unwrap();
+
+        assert!(plugin.detect_entries(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: SolidStartPlugin = Default::default();
+    }
+}
@@ -0,0 +1,257 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Declaration, ExportDefaultDeclarationKind, Expression, ObjectExpression, ObjectPropertyKind,
+    PropertyKey, Statement,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default source directory used by Stencil when `srcDir` is not set
+const DEFAULT_SRC_DIR: &str = "src";
+
+/// Plugin to discover Stencil components, global script/style, and config as entry points.
+///
+/// Stencil compiles every component under `srcDir` without them being imported
+/// from a root module, and loads `globalScript`/`globalStyle` by path.
+pub struct StencilPlugin;
+
+/// Settings read from `stencil.config.*`
+#[derive(Debug, Default, PartialEq)]
+struct StencilConfig {
+    src_dir: Option<String>,
+    global_script: Option<String>,
+    global_style: Option<String>,
+}
+
+impl StencilPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find the Stencil config file in the project root
+    fn find_config_file(&self, cwd: &Path) -> Option<PathBuf> {
+        ["stencil.config.ts", "stencil.config.js"]
+            .iter()
+            .map(|name| cwd.join(name))
+            .find(|path| path.is_file())
+    }
+
+    /// Parse the Stencil config and extract `srcDir`, `globalScript`, and `globalStyle`
+    fn parse_config(&self, config_path: &Path) -> Result<StencilConfig, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        for stmt in &parsed.program.body {
+            if let Some(obj) = self.config_object(stmt) {
+                return Ok(self.extract_config_from_object(obj));
+            }
+        }
+
+        Ok(StencilConfig::default())
+    }
+
+    /// Find the config object in `export const config = { ... }` or `export default { ... }`
+    fn config_object<'a>(&self, stmt: &'a Statement<'a>) -> Option<&'a ObjectExpression<'a>> {
+        match stmt {
+            Statement::ExportNamedDeclaration(export) => {
+                if let Some(Declaration::VariableDeclaration(var_decl)) = &export.declaration {
+                    for decl in &var_decl.declarations {
+                        if let Some(Expression::ObjectExpression(obj)) = &decl.init {
+                            return Some(obj);
+                        }
+                    }
+                }
+                None
+            }
+            Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                ExportDefaultDeclarationKind::ObjectExpression(obj) => Some(obj),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Extract string-valued settings from the config object
+    fn extract_config_from_object(&self, obj: &ObjectExpression) -> StencilConfig {
+        let mut config = StencilConfig::default();
+
+        for prop in &obj.properties {
+            if let ObjectPropertyKind::ObjectProperty(property) = prop {
+                let key_name = match &property.key {
+                    PropertyKey::StaticIdentifier(ident) => ident.name.as_str(),
+                    PropertyKey::StringLiteral(lit) => lit.value.as_str(),
+                    _ => continue,
+                };
+
+                let Expression::StringLiteral(value) = &property.value else {
+                    continue;
+                };
+
+                match key_name {
+                    "srcDir" => config.src_dir = Some(value.value.to_string()),
+                    "globalScript" => config.global_script = Some(value.value.to_string()),
+                    "globalStyle" => config.global_style = Some(value.value.to_string()),
+                    _ => {}
+                }
+            }
+        }
+
+        config
+    }
+
+    /// Resolve a config-relative path to an existing file
+    fn resolve_path(&self, path: &str, cwd: &Path) -> Option<PathBuf> {
+        let resolved = cwd.join(path.trim_start_matches("./"));
+        if resolved.is_file() { resolved.canonicalize().ok() } else { None }
+    }
+}
+
+impl Default for StencilPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for StencilPlugin {
+    fn name(&self) -> &str {
+        "stencil"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("@stencil/core")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let mut paths = Vec::new();
+
+        let config = match self.find_config_file(cwd) {
+            Some(config_path) => {
+                if let Ok(canonical) = config_path.canonicalize() {
+                    paths.push(canonical);
+                }
+                self.parse_config(&config_path).unwrap_or_default()
+            }
+            None => StencilConfig::default(),
+        };
+
+        for global in [&config.global_script, &config.global_style].into_iter().flatten() {
+            if let Some(path) = self.resolve_path(global, cwd) {
+                paths.push(path);
+            }
+        }
+
+        // Every component under srcDir is compiled by Stencil
+        let src_dir = config.src_dir.as_deref().unwrap_or(DEFAULT_SRC_DIR);
+        let patterns = vec![EntryPattern::with_base("**/*.tsx", src_dir.trim_start_matches("./"))];
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_should_enable_with_stencil() {
+        let plugin = StencilPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@stencil/core".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_stencil() {
+        let plugin = StencilPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("lit".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_default_component_pattern() {
+        let plugin = StencilPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let patterns = entries.get_patterns();
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(patterns[0].pattern, "**/*.tsx");
+        assert_eq!(patterns[0].base, Some(PathBuf::from("src")));
+    }
+
+    #[test]
+    fn test_config_global_script_and_style() {
+        let plugin = StencilPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+import { Config } from '@stencil/core';
+
+export const config: Config = {
+  namespace: 'app',
+  srcDir: 'components',
+  globalScript: 'components/global/app.ts',
+  globalStyle: './components/global/app.css',
+  outputTargets: [{ type: 'www' }],
+};
+"#;
+        fs::write(temp.path().join("stencil.config.ts"), config_content).unwrap();
+        fs::create_dir_all(temp.path().join("components/global")).unwrap();
+        fs::write(temp.path().join("components/global/app.ts"), "export default () => {};")
+            .unwrap();
+        fs::write(temp.path().join("components/global/app.css"), ":root {}").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 3);
+        assert!(paths[0].ends_with("stencil.config.ts"));
+        assert!(paths[1].ends_with("components/global/app.ts"));
+        assert!(paths[2].ends_with("components/global/app.css"));
+
+        let patterns = entries.get_patterns();
+        assert_eq!(patterns[0].pattern, "**/*.tsx");
+        assert_eq!(patterns[0].base, Some(PathBuf::from("components")));
+    }
+
+    #[test]
+    fn test_missing_global_script_is_skipped() {
+        let plugin = StencilPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+export const config = {
+  globalScript: 'src/global/missing.ts',
+};
+"#;
+        fs::write(temp.path().join("stencil.config.ts"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("stencil.config.ts"));
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: StencilPlugin = Default::default();
+    }
+}
@@ -0,0 +1,177 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Plugin to discover files referenced by Turborepo's `turbo.json`.
+///
+/// `globalDependencies` and each task's `inputs` are globs of files that
+/// affect task hashing, so scripts listed there are in use even when nothing
+/// imports them.
+pub struct TurboPlugin;
+
+impl TurboPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collect `globalDependencies` and the `inputs` of every task (`tasks` in
+    /// Turborepo 2, `pipeline` in 1.x). `$`-prefixed entries (env vars and
+    /// `$TURBO_DEFAULT$`) and `!` negations are skipped.
+    fn parse_config(&self, config_path: &Path) -> Result<Vec<String>, PluginError> {
+        let mut content = fs::read_to_string(config_path)?;
+        json_strip_comments::strip(&mut content)
+            .map_err(|e| PluginError::ConfigParse(e.to_string()))?;
+        let json: serde_json::Value =
+            serde_json::from_str(&content).map_err(|e| PluginError::ConfigParse(e.to_string()))?;
+
+        let strings = |value: Option<&serde_json::Value>| -> Vec<String> {
+            value
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str())
+                .filter(|glob| !glob.starts_with(['$', '!']))
+                .map(|glob| glob.trim_start_matches("./").to_string())
+                .collect()
+        };
+
+        let mut globs = strings(json.get("globalDependencies"));
+        let tasks = json.get("tasks").or_else(|| json.get("pipeline")).and_then(|t| t.as_object());
+        for task in tasks.into_iter().flat_map(|tasks| tasks.values()) {
+            for glob in strings(task.get("inputs")) {
+                if !globs.contains(&glob) {
+                    globs.push(glob);
+                }
+            }
+        }
+
+        Ok(globs)
+    }
+}
+
+impl Default for TurboPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for TurboPlugin {
+    fn name(&self) -> &str {
+        "turbo"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("turbo")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let config_path = cwd.join("turbo.json");
+        if !config_path.is_file() {
+            return Ok(PluginEntries::Empty);
+        }
+
+        let patterns =
+            self.parse_config(&config_path)?.into_iter().map(EntryPattern::new).collect();
+        let paths: Vec<PathBuf> = config_path.canonicalize().into_iter().collect();
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn patterns(entries: &PluginEntries) -> Vec<&str> {
+        entries.get_patterns().iter().map(|p| p.pattern.as_str()).collect()
+    }
+
+    #[test]
+    fn test_should_enable_with_turbo() {
+        let plugin = TurboPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("turbo".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_turbo() {
+        let plugin = TurboPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("lerna".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_global_dependencies() {
+        let plugin = TurboPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config = r#"{
+  // Shared build tooling
+  "$schema": "https://turbo.build/schema.json",
+  "globalDependencies": ["./scripts/env.js", "tsconfig.base.json", "$NODE_ENV"],
+  "tasks": {}
+}"#;
+        fs::write(temp.path().join("turbo.json"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["scripts/env.js", "tsconfig.base.json"]);
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("turbo.json"));
+    }
+
+    #[test]
+    fn test_task_inputs() {
+        let plugin = TurboPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config = r#"{
+  "tasks": {
+    "build": {
+      "dependsOn": ["^build"],
+      "inputs": ["$TURBO_DEFAULT$", "scripts/build/**/*.ts", "!**/*.md"]
+    },
+    "test": { "inputs": ["test/setup.ts", "scripts/build/**/*.ts"] },
+    "lint": {}
+  }
+}"#;
+        fs::write(temp.path().join("turbo.json"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["scripts/build/**/*.ts", "test/setup.ts"]);
+    }
+
+    #[test]
+    fn test_legacy_pipeline_inputs() {
+        let plugin = TurboPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config = r#"{ "pipeline": { "build": { "inputs": ["src/**/*.tsx"] } } }"#;
+        fs::write(temp.path().join("turbo.json"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["src/**/*.tsx"]);
+    }
+
+    #[test]
+    fn test_no_turbo_json() {
+        let plugin = TurboPlugin::new();
+        let temp = tempdir().unwrap();
+
+        assert!(plugin.detect_entries(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: TurboPlugin = Default::default();
+    }
+}
@@ -1,5 +1,6 @@
 use super::{EntryPattern, Plugin, PluginEntries, PluginError};
-use fast_glob::glob_match;
+use crate::collector::build_glob;
+use crate::dependencies::DependencyInfo;
 use rustc_hash::FxHashSet;
 use serde_json::Value;
 use std::fs;
@@ -43,15 +44,15 @@ impl TypescriptPlugin {
         }
 
         // Find tsconfig.*.json files (e.g., tsconfig.build.json, tsconfig.test.json)
-        // Use fast-glob pattern matching on directory entries
-        let pattern = "tsconfig.*.json";
+        // Match directory entries with the collector's glob semantics
+        let pattern = build_glob("tsconfig.*.json").expect("valid tsconfig glob").compile_matcher();
         if let Ok(read_dir) = std::fs::read_dir(cwd) {
             for entry in read_dir.filter_map(|e| e.ok()) {
                 let path = entry.path();
                 if path.is_file() {
                     if let Some(file_name) = path.file_name() {
                         let name = file_name.to_string_lossy();
-                        if glob_match(pattern, name.as_ref()) {
+                        if pattern.is_match(name.as_ref()) {
                             if let Ok(canonical) = path.canonicalize() {
                                 found.insert(canonical);
                             }
@@ -201,7 +202,7 @@ fn convert_to_dts_pattern(pattern: &str) -> String {
 
 /// Strip single-line (//) and multi-line (/* */) comments from JSON content.
 /// This is needed because tsconfig.json allows comments (JSONC format).
-fn strip_json_comments(content: &str) -> String {
+pub(crate) fn strip_json_comments(content: &str) -> String {
     let mut result = String::with_capacity(content.len());
     let mut chars = content.chars().peekable();
     let mut in_string = false;
@@ -275,6 +276,11 @@ impl Plugin for TypescriptPlugin {
         dependencies.contains("typescript")
     }
 
+    /// A `typescript` peer dependency alone doesn't mean the project compiles TypeScript
+    fn should_enable_for(&self, _cwd: &Path, dependencies: &DependencyInfo) -> bool {
+        dependencies.is_installed("typescript")
+    }
+
     fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
         let mut path_entries = FxHashSet::default();
         let mut pattern_entries = Vec::new();
@@ -330,6 +336,19 @@ mod tests {
         assert!(plugin.should_enable(temp.path(), &deps));
     }
 
+    #[test]
+    fn test_should_enable_only_when_typescript_is_installed() {
+        let plugin = TypescriptPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let mut deps = DependencyInfo::default();
+        deps.peer_deps.insert("typescript".to_string());
+        assert!(!plugin.should_enable_for(temp.path(), &deps));
+
+        deps.dev_deps.insert("typescript".to_string());
+        assert!(plugin.should_enable_for(temp.path(), &deps));
+    }
+
     #[test]
     fn test_should_not_enable_without_typescript() {
         let plugin = TypescriptPlugin::new();
@@ -1,12 +1,27 @@
 use super::{Plugin, PluginEntries, PluginError};
-use rustc_hash::FxHashSet;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    Argument, ExportDefaultDeclarationKind, Expression, FunctionBody, ObjectExpression,
+    ObjectPropertyKind, PropertyKey, Statement,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::fs;
 use std::path::{Path, PathBuf};
 
-/// Plugin to discover Vite config files as entry points.
+/// Extensions to try when an input has none
+const RESOLVE_EXTENSIONS: &[&str] = &[".ts", ".tsx", ".js", ".jsx", ".mjs", ".mts"];
+
+/// Plugin to discover Vite config files and build inputs as entry points.
 ///
 /// Vite config files often import other local files like custom plugins,
 /// shared configurations, or utility modules. By adding the config file
 /// as an entry point, normal import tracing will discover these dependencies.
+///
+/// Library and multi-page apps declare their roots in `build.rollupOptions.input`;
+/// SPAs are served from the root `index.html`. HTML inputs are traced like any
+/// other entry, through the scripts and stylesheets they load.
 pub struct VitePlugin;
 
 impl VitePlugin {
@@ -40,6 +55,214 @@ impl VitePlugin {
 
         found
     }
+
+    /// Extract `build.rollupOptions.input` values from the exported config:
+    /// an object, `defineConfig(...)`, a function returning an object, or a
+    /// variable holding one of those
+    fn parse_inputs(&self, config_path: &Path) -> Result<Vec<String>, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        let mut variables: FxHashMap<&str, &Expression> = FxHashMap::default();
+        for stmt in &parsed.program.body {
+            if let Statement::VariableDeclaration(var_decl) = stmt {
+                for decl in &var_decl.declarations {
+                    if let (Some(name), Some(init)) = (decl.id.get_identifier_name(), &decl.init) {
+                        variables.insert(name.as_str(), init);
+                    }
+                }
+            }
+        }
+
+        let mut inputs = Vec::new();
+        for stmt in &parsed.program.body {
+            match stmt {
+                Statement::ExportDefaultDeclaration(export) => match &export.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                        if let Some(body) = &func.body {
+                            self.extract_from_body(body, &variables, &mut inputs);
+                        }
+                    }
+                    declaration => {
+                        if let Some(expr) = declaration.as_expression() {
+                            self.extract_from_config(expr, &variables, &mut inputs);
+                        }
+                    }
+                },
+                Statement::ExpressionStatement(expr_stmt) => {
+                    if let Expression::AssignmentExpression(assign) = &expr_stmt.expression {
+                        self.extract_from_config(&assign.right, &variables, &mut inputs);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(inputs)
+    }
+
+    /// Collect inputs from a config expression, unwrapping `defineConfig(...)`,
+    /// config functions, TypeScript casts and variable references
+    fn extract_from_config(
+        &self,
+        expr: &Expression,
+        variables: &FxHashMap<&str, &Expression>,
+        inputs: &mut Vec<String>,
+    ) {
+        match expr {
+            Expression::ObjectExpression(obj) => {
+                let input = property(obj, "build")
+                    .and_then(as_object)
+                    .and_then(|build| property(build, "rollupOptions"))
+                    .and_then(as_object)
+                    .and_then(|options| property(options, "input"));
+                if let Some(input) = input {
+                    self.extract_input(input, inputs);
+                }
+            }
+            Expression::CallExpression(call) => {
+                if let Some(arg) = call.arguments.first().and_then(Argument::as_expression) {
+                    self.extract_from_config(arg, variables, inputs);
+                }
+            }
+            Expression::ArrowFunctionExpression(arrow) => {
+                self.extract_from_body(&arrow.body, variables, inputs)
+            }
+            Expression::FunctionExpression(func) => {
+                if let Some(body) = &func.body {
+                    self.extract_from_body(body, variables, inputs);
+                }
+            }
+            Expression::Identifier(ident) => {
+                if let Some(init) = variables.get(ident.name.as_str()) {
+                    self.extract_from_config(init, variables, inputs);
+                }
+            }
+            Expression::TSAsExpression(expr) => {
+                self.extract_from_config(&expr.expression, variables, inputs)
+            }
+            Expression::TSSatisfiesExpression(expr) => {
+                self.extract_from_config(&expr.expression, variables, inputs)
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                self.extract_from_config(&expr.expression, variables, inputs)
+            }
+            _ => {}
+        }
+    }
+
+    /// Collect inputs from the configs a function returns (an arrow's expression
+    /// body counts as a return)
+    fn extract_from_body(
+        &self,
+        body: &FunctionBody,
+        variables: &FxHashMap<&str, &Expression>,
+        inputs: &mut Vec<String>,
+    ) {
+        let mut pending: Vec<&Statement> = body.statements.iter().collect();
+        while let Some(stmt) = pending.pop() {
+            match stmt {
+                Statement::ReturnStatement(ret) => {
+                    if let Some(arg) = &ret.argument {
+                        self.extract_from_config(arg, variables, inputs);
+                    }
+                }
+                Statement::ExpressionStatement(expr_stmt) => {
+                    self.extract_from_config(&expr_stmt.expression, variables, inputs);
+                }
+                Statement::BlockStatement(block) => pending.extend(block.body.iter()),
+                Statement::IfStatement(if_stmt) => {
+                    pending.push(&if_stmt.consequent);
+                    pending.extend(if_stmt.alternate.iter());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Read `input`: a string, an array of strings, or an `{ name: path }` object.
+    /// Each path is a string literal or `resolve(__dirname, 'a', 'b')`.
+    fn extract_input(&self, input: &Expression, inputs: &mut Vec<String>) {
+        match input {
+            Expression::ArrayExpression(arr) => inputs.extend(
+                arr.elements.iter().filter_map(|elem| elem.as_expression()).filter_map(input_path),
+            ),
+            Expression::ObjectExpression(map) => {
+                inputs.extend(map.properties.iter().filter_map(|prop| match prop {
+                    ObjectPropertyKind::ObjectProperty(p) => input_path(&p.value),
+                    _ => None,
+                }));
+            }
+            expr => inputs.extend(input_path(expr)),
+        }
+    }
+
+    /// Resolve an input relative to the project root, trying common extensions
+    fn resolve_input(&self, input: &str, cwd: &Path) -> Option<PathBuf> {
+        let target = cwd.join(input);
+        std::iter::once(target.clone())
+            .chain(
+                RESOLVE_EXTENSIONS
+                    .iter()
+                    .map(|ext| PathBuf::from(format!("{}{ext}", target.to_string_lossy()))),
+            )
+            .find(|path| path.is_file())
+            .and_then(|path| path.canonicalize().ok())
+    }
+}
+
+/// Find a property of an object literal by its static name
+fn property<'a, 'b>(obj: &'b ObjectExpression<'a>, name: &str) -> Option<&'b Expression<'a>> {
+    obj.properties.iter().find_map(|prop| {
+        let ObjectPropertyKind::ObjectProperty(property) = prop else {
+            return None;
+        };
+        let matches = match &property.key {
+            PropertyKey::StaticIdentifier(ident) => ident.name == name,
+            PropertyKey::StringLiteral(lit) => lit.value == name,
+            _ => false,
+        };
+        matches.then_some(&property.value)
+    })
+}
+
+fn as_object<'a, 'b>(expr: &'b Expression<'a>) -> Option<&'b ObjectExpression<'a>> {
+    match expr {
+        Expression::ObjectExpression(obj) => Some(obj),
+        _ => None,
+    }
+}
+
+/// A path given as a string literal, or as `resolve(__dirname, ...segments)`
+/// (also `path.resolve` and `join`) with string literal segments
+fn input_path(expr: &Expression) -> Option<String> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(lit.value.to_string()),
+        Expression::TemplateLiteral(tpl) if tpl.expressions.is_empty() => {
+            tpl.quasis.first().map(|quasi| quasi.value.raw.to_string())
+        }
+        Expression::CallExpression(call) => {
+            let mut segments = Vec::new();
+            for arg in &call.arguments {
+                match arg.as_expression()? {
+                    Expression::Identifier(ident) if ident.name == "__dirname" => {}
+                    Expression::StringLiteral(lit) => segments.push(lit.value.as_str()),
+                    _ => return None,
+                }
+            }
+            (!segments.is_empty()).then(|| segments.join("/"))
+        }
+        _ => None,
+    }
 }
 
 impl Default for VitePlugin {
@@ -58,10 +281,29 @@ impl Plugin for VitePlugin {
     }
 
     fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
-        // Simply return config files as entry points.
-        // The normal import/require tracing will discover any local dependencies
-        // (like custom plugins, shared configs, etc.)
-        Ok(PluginEntries::paths(self.find_config_files(cwd)))
+        // The normal import/require tracing from the config files will discover any
+        // local dependencies (like custom plugins, shared configs, etc.)
+        let config_files = self.find_config_files(cwd);
+
+        let mut inputs: Vec<String> = config_files
+            .iter()
+            .flat_map(|config| self.parse_inputs(config).unwrap_or_default())
+            .collect();
+        // Without explicit inputs, Vite builds the root `index.html`
+        if inputs.is_empty() {
+            inputs.push("index.html".to_string());
+        }
+
+        let mut paths = config_files;
+        for input in inputs {
+            if let Some(path) = self.resolve_input(&input, cwd) {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+
+        Ok(PluginEntries::paths(paths))
     }
 }
 
@@ -243,6 +485,100 @@ module.exports = defineConfig({
         assert_eq!(paths.len(), 2);
     }
 
+    fn write_files(root: &Path, files: &[&str]) {
+        for file in files {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+    }
+
+    fn relative_paths(entries: &PluginEntries, root: &Path) -> Vec<String> {
+        let root = root.canonicalize().unwrap();
+        entries
+            .get_paths()
+            .iter()
+            .map(|p| p.strip_prefix(&root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect()
+    }
+
+    #[test]
+    fn test_rollup_input_object_map() {
+        let plugin = VitePlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(
+            temp.path(),
+            &["src/main.ts", "src/worker.ts", "lib/index.ts", "admin/index.html"],
+        );
+
+        let config = r#"
+import { resolve } from 'node:path';
+import { defineConfig } from 'vite';
+
+export default defineConfig({
+  build: {
+    rollupOptions: {
+      input: {
+        main: 'src/main.ts',
+        worker: resolve(__dirname, 'src/worker'),
+        admin: resolve(__dirname, 'admin', 'index.html'),
+        missing: 'src/missing.ts',
+      },
+    },
+  },
+});
+"#;
+        fs::write(temp.path().join("vite.config.ts"), config).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            relative_paths(&entries, temp.path()),
+            vec!["vite.config.ts", "src/main.ts", "src/worker.ts", "admin/index.html"]
+        );
+    }
+
+    #[test]
+    fn test_rollup_input_in_function_config() {
+        let plugin = VitePlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["src/index.ts", "src/cli.ts", "index.html"]);
+        let config = r#"
+import { defineConfig } from 'vite';
+
+export default defineConfig(({ mode }) => {
+  if (mode === 'cli') {
+    return { build: { rollupOptions: { input: 'src/cli.ts' } } };
+  }
+  return { build: { rollupOptions: { input: ['src/index.ts'] } } };
+});
+"#;
+        fs::write(temp.path().join("vite.config.mts"), config).unwrap();
+
+        // Explicit inputs replace the default `index.html`
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let mut paths = relative_paths(&entries, temp.path());
+        paths.sort();
+        assert_eq!(paths, vec!["src/cli.ts", "src/index.ts", "vite.config.mts"]);
+    }
+
+    #[test]
+    fn test_default_index_html() {
+        let plugin = VitePlugin::new();
+        let temp = tempdir().unwrap();
+
+        write_files(temp.path(), &["index.html", "src/main.tsx"]);
+        fs::write(
+            temp.path().join("vite.config.ts"),
+            "import react from '@vitejs/plugin-react';\nexport default { plugins: [react()] };",
+        )
+        .unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(relative_paths(&entries, temp.path()), vec!["vite.config.ts", "index.html"]);
+    }
+
     #[test]
     fn test_default_impl() {
         let _: VitePlugin = Default::default();
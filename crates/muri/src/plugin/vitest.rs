@@ -1,4 +1,5 @@
 use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use crate::types::PluginSettings;
 use oxc_allocator::Allocator;
 use oxc_ast::ast::{
     Argument, Expression, ModuleDeclaration, ObjectPropertyKind, PropertyKey, Statement,
@@ -39,15 +40,26 @@ const DEFAULT_TEST_PATTERNS: &[&str] = &[
 ];
 
 /// Plugin to discover Vitest test files and setup files as entry points
-pub struct VitestPlugin;
+pub struct VitestPlugin {
+    /// Custom config file path (relative to cwd) from plugin settings
+    config_path: Option<PathBuf>,
+}
 
 impl VitestPlugin {
     pub fn new() -> Self {
-        Self
+        Self { config_path: None }
     }
 
-    /// Find vitest config file in the project
+    /// Find vitest config file in the project.
+    /// A custom config path from plugin settings takes precedence over the default names.
     fn find_vitest_config(&self, cwd: &Path) -> Option<PathBuf> {
+        if let Some(config_path) = &self.config_path {
+            let path = cwd.join(config_path);
+            if path.exists() {
+                return Some(path);
+            }
+        }
+
         let extensions = ["js", "ts", "mjs", "cjs"];
         for ext in extensions {
             let path = cwd.join(format!("vitest.config.{}", ext));
@@ -414,6 +426,10 @@ impl Plugin for VitestPlugin {
         dependencies.contains("vitest")
     }
 
+    fn configure(&mut self, settings: &PluginSettings) {
+        self.config_path = settings.config.clone();
+    }
+
     fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
         let mut paths = Vec::new();
 
@@ -827,4 +843,23 @@ export default defineConfig({
         assert_eq!(resolved.len(), 1);
         assert!(resolved[0].ends_with("setup.ts"));
     }
+
+    #[test]
+    fn test_custom_config_path() {
+        let mut plugin = VitestPlugin::new();
+        let temp = tempdir().unwrap();
+
+        fs::create_dir(temp.path().join("config")).unwrap();
+        fs::write(temp.path().join("config/vitest.unit.ts"), "export default {}").unwrap();
+
+        plugin.configure(&PluginSettings {
+            config: Some(PathBuf::from("config/vitest.unit.ts")),
+            ..Default::default()
+        });
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("config/vitest.unit.ts"));
+    }
 }
@@ -0,0 +1,358 @@
+use super::{EntryPattern, Plugin, PluginEntries, PluginError};
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    ArrayExpressionElement, BindingPatternKind, Declaration, Expression, ObjectExpression,
+    ObjectPropertyKind, PropertyKey, Statement,
+};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use rustc_hash::FxHashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config files the WebdriverIO CLI looks for
+const CONFIG_FILES: &[&str] =
+    &["wdio.conf.js", "wdio.conf.ts", "wdio.conf.mjs", "wdio.conf.cjs", "wdio.conf.mts"];
+
+/// Extensions to try when resolving `require` modules
+const RESOLVE_EXTENSIONS: &[&str] = &[".js", ".ts", ".mjs", ".cjs"];
+
+/// Plugin to discover WebdriverIO spec files and config as entry points
+pub struct WdioPlugin;
+
+/// Globs and hook modules read from a WebdriverIO config
+#[derive(Debug, Default, PartialEq)]
+struct WdioConfig {
+    specs: Vec<String>,
+    require: Vec<String>,
+}
+
+impl WdioPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Find the WebdriverIO config file in the project root
+    fn find_config_file(&self, cwd: &Path) -> Option<PathBuf> {
+        CONFIG_FILES.iter().map(|name| cwd.join(name)).find(|path| path.is_file())
+    }
+
+    /// Parse the config object from `export const config = { ... }`,
+    /// `exports.config = { ... }`, or `module.exports = { config: { ... } }`
+    fn parse_config(&self, config_path: &Path) -> Result<WdioConfig, PluginError> {
+        let content = fs::read_to_string(config_path)?;
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(config_path).unwrap_or_default();
+        let parsed = Parser::new(&allocator, &content, source_type).parse();
+
+        if parsed.panicked {
+            return Err(PluginError::ConfigParse(format!(
+                "Failed to parse {}",
+                config_path.display()
+            )));
+        }
+
+        let mut config = WdioConfig::default();
+
+        for stmt in &parsed.program.body {
+            let obj = match stmt {
+                Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                    Some(Declaration::VariableDeclaration(decl)) => decl
+                        .declarations
+                        .iter()
+                        .find_map(|declarator| match (&declarator.id.kind, &declarator.init) {
+                            (
+                                BindingPatternKind::BindingIdentifier(ident),
+                                Some(Expression::ObjectExpression(obj)),
+                            ) if ident.name == "config" => Some(&**obj),
+                            _ => None,
+                        }),
+                    _ => None,
+                },
+                Statement::ExpressionStatement(expr_stmt) => match &expr_stmt.expression {
+                    Expression::AssignmentExpression(assign) => match &assign.right {
+                        Expression::ObjectExpression(obj) => {
+                            // `module.exports = { config: { ... } }` nests the config
+                            Some(self.property(obj, "config").unwrap_or(obj))
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            if let Some(obj) = obj {
+                self.extract_config_from_object(obj, &mut config);
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Get a nested object property by key
+    fn property<'a, 'b>(
+        &self,
+        obj: &'b ObjectExpression<'a>,
+        key: &str,
+    ) -> Option<&'b ObjectExpression<'a>> {
+        obj.properties.iter().find_map(|prop| match prop {
+            ObjectPropertyKind::ObjectProperty(property)
+                if self.key_name(&property.key) == Some(key) =>
+            {
+                match &property.value {
+                    Expression::ObjectExpression(inner) => Some(&**inner),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    }
+
+    /// Static name of an object key (`specs` or `"specs"`)
+    fn key_name<'a>(&self, key: &'a PropertyKey) -> Option<&'a str> {
+        match key {
+            PropertyKey::StaticIdentifier(ident) => Some(ident.name.as_str()),
+            PropertyKey::StringLiteral(lit) => Some(lit.value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Extract `specs`, every `suites.*` array, and `require` hooks from
+    /// the framework options (`mochaOpts`, `jasmineOpts`, `cucumberOpts`)
+    fn extract_config_from_object(&self, obj: &ObjectExpression, config: &mut WdioConfig) {
+        for prop in &obj.properties {
+            let ObjectPropertyKind::ObjectProperty(property) = prop else {
+                continue;
+            };
+            let Some(key_name) = self.key_name(&property.key) else {
+                continue;
+            };
+
+            match (key_name, &property.value) {
+                ("specs", Expression::ArrayExpression(arr)) => {
+                    self.collect_strings(&arr.elements, &mut config.specs);
+                }
+                ("suites", Expression::ObjectExpression(suites)) => {
+                    for suite in &suites.properties {
+                        if let ObjectPropertyKind::ObjectProperty(suite) = suite {
+                            if let Expression::ArrayExpression(arr) = &suite.value {
+                                self.collect_strings(&arr.elements, &mut config.specs);
+                            }
+                        }
+                    }
+                }
+                (
+                    "mochaOpts" | "jasmineOpts" | "cucumberOpts",
+                    Expression::ObjectExpression(opts),
+                ) => {
+                    for opt in &opts.properties {
+                        let ObjectPropertyKind::ObjectProperty(opt) = opt else {
+                            continue;
+                        };
+                        if self.key_name(&opt.key) != Some("require") {
+                            continue;
+                        }
+                        match &opt.value {
+                            Expression::StringLiteral(lit) => {
+                                config.require.push(lit.value.to_string());
+                            }
+                            Expression::ArrayExpression(arr) => {
+                                self.collect_strings(&arr.elements, &mut config.require);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Collect string literals from an array, flattening nested arrays
+    /// (WebdriverIO runs specs grouped in an inner array in one worker)
+    fn collect_strings(&self, elements: &[ArrayExpressionElement], out: &mut Vec<String>) {
+        for elem in elements {
+            match elem.as_expression() {
+                Some(Expression::StringLiteral(lit)) => out.push(lit.value.to_string()),
+                Some(Expression::ArrayExpression(arr)) => self.collect_strings(&arr.elements, out),
+                _ => {}
+            }
+        }
+    }
+
+    /// Resolve local `require` hooks; package names (e.g. `ts-node/register`) are skipped
+    fn resolve_requires(&self, requires: &[String], cwd: &Path) -> Vec<PathBuf> {
+        requires
+            .iter()
+            .filter(|module| module.starts_with('.'))
+            .filter_map(|module| {
+                let target = cwd.join(module);
+                std::iter::once(target.clone())
+                    .chain(
+                        RESOLVE_EXTENSIONS
+                            .iter()
+                            .map(|ext| PathBuf::from(format!("{}{ext}", target.to_string_lossy()))),
+                    )
+                    .find(|path| path.is_file())
+            })
+            .filter_map(|path| path.canonicalize().ok())
+            .collect()
+    }
+}
+
+impl Default for WdioPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plugin for WdioPlugin {
+    fn name(&self) -> &str {
+        "wdio"
+    }
+
+    fn should_enable(&self, _cwd: &Path, dependencies: &FxHashSet<String>) -> bool {
+        dependencies.contains("@wdio/cli")
+    }
+
+    fn detect_entries(&self, cwd: &Path) -> Result<PluginEntries, PluginError> {
+        let Some(config_path) = self.find_config_file(cwd) else {
+            return Ok(PluginEntries::Empty);
+        };
+
+        let mut paths = Vec::new();
+        if let Ok(canonical) = config_path.canonicalize() {
+            paths.push(canonical);
+        }
+
+        // Spec globs are relative to the config file, which lives in `cwd`
+        let config = self.parse_config(&config_path).unwrap_or_default();
+        let patterns = config
+            .specs
+            .iter()
+            .map(|spec| EntryPattern::new(spec.trim_start_matches("./").to_string()))
+            .collect();
+        paths.extend(self.resolve_requires(&config.require, cwd));
+
+        Ok(PluginEntries::mixed(patterns, paths))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn patterns(entries: &PluginEntries) -> Vec<&str> {
+        entries.get_patterns().iter().map(|p| p.pattern.as_str()).collect()
+    }
+
+    #[test]
+    fn test_should_enable_with_wdio_cli() {
+        let plugin = WdioPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("@wdio/cli".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_should_not_enable_without_wdio_cli() {
+        let plugin = WdioPlugin::new();
+        let mut deps = FxHashSet::default();
+        deps.insert("webdriverio".to_string());
+
+        let temp = tempdir().unwrap();
+        assert!(!plugin.should_enable(temp.path(), &deps));
+    }
+
+    #[test]
+    fn test_no_config_file() {
+        let plugin = WdioPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_specs_array() {
+        let plugin = WdioPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+export const config: WebdriverIO.Config = {
+  runner: 'local',
+  specs: ['./test/specs/**/*.ts', ['./test/grouped/a.ts', './test/grouped/b.ts']],
+  exclude: ['./test/specs/skip.ts'],
+  services: ['chromedriver'],
+  reporters: ['spec'],
+  mochaOpts: {
+    require: ['ts-node/register', './test/hooks'],
+  },
+};
+"#;
+        fs::write(temp.path().join("wdio.conf.ts"), config_content).unwrap();
+        fs::create_dir(temp.path().join("test")).unwrap();
+        fs::write(temp.path().join("test/hooks.ts"), "").unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            patterns(&entries),
+            vec!["test/specs/**/*.ts", "test/grouped/a.ts", "test/grouped/b.ts"]
+        );
+
+        let paths = entries.get_paths();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].ends_with("wdio.conf.ts"));
+        assert!(paths[1].ends_with("test/hooks.ts"));
+    }
+
+    #[test]
+    fn test_suites_map() {
+        let plugin = WdioPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = r#"
+exports.config = {
+  specs: ['./e2e/smoke/*.js'],
+  suites: {
+    login: ['./e2e/login/*.e2e.js'],
+    'checkout-flow': ['./e2e/checkout/**/*.e2e.js', './e2e/cart.e2e.js'],
+  },
+};
+"#;
+        fs::write(temp.path().join("wdio.conf.js"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(
+            patterns(&entries),
+            vec![
+                "e2e/smoke/*.js",
+                "e2e/login/*.e2e.js",
+                "e2e/checkout/**/*.e2e.js",
+                "e2e/cart.e2e.js"
+            ]
+        );
+        assert_eq!(entries.get_paths().len(), 1);
+    }
+
+    #[test]
+    fn test_module_exports_config_key() {
+        let plugin = WdioPlugin::new();
+        let temp = tempdir().unwrap();
+
+        let config_content = "module.exports = { config: { specs: ['./specs/*.js'] } };";
+        fs::write(temp.path().join("wdio.conf.js"), config_content).unwrap();
+
+        let entries = plugin.detect_entries(temp.path()).unwrap();
+        assert_eq!(patterns(&entries), vec!["specs/*.js"]);
+    }
+
+    #[test]
+    fn test_default_impl() {
+        let _: WdioPlugin = Default::default();
+    }
+}
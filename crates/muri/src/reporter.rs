@@ -1,21 +1,297 @@
-use serde::Serialize;
+use crate::codeowners::CodeOwners;
+use crate::exports::UnusedExport;
+use crate::graph::UnresolvedImport;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
 use std::path::{Path, PathBuf};
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "ReportJson", from = "ReportJson")]
 pub struct Report {
+    /// Version of the JSON report schema ([`REPORT_SCHEMA_VERSION`]; 0 if missing)
+    pub schema_version: u32,
     pub unused_files: Vec<PathBuf>,
     pub total_files: usize,
     pub unused_count: usize,
+    /// Size in bytes of each unused file, written as `bytes` on its JSON entry
+    pub file_sizes: BTreeMap<PathBuf, u64>,
+    /// Total bytes that would be reclaimed by deleting all unused files
+    pub total_unused_bytes: u64,
+    /// Number of project files reachable from the entry points
+    pub reachable_count: usize,
+    /// Number of resolved entry files
+    pub entry_count: usize,
+    /// Wall-clock time spent on the analysis, in milliseconds
+    pub duration_ms: u64,
+    /// Exports never imported by a reachable file (only filled when requested)
+    pub unused_exports: Vec<UnusedExport>,
+    /// Imports of reachable files that could not be resolved (only filled in verbose mode)
+    pub unresolved_imports: Vec<UnresolvedImport>,
+    /// Names of the plugins active for the analysis (only filled in verbose mode)
+    pub active_plugins: Vec<String>,
+}
+
+/// JSON shape of a [`Report`], where each unused file carries its own size
+#[derive(Serialize, Deserialize)]
+struct ReportJson {
+    #[serde(default)]
+    schema_version: u32,
+    unused_files: Vec<UnusedFileJson>,
+    // Everything but the file list defaults, so reports saved by earlier
+    // versions still load (e.g. as a `--baseline`)
+    #[serde(default)]
+    total_files: usize,
+    #[serde(default)]
+    unused_count: usize,
+    #[serde(default)]
+    total_unused_bytes: u64,
+    #[serde(default)]
+    reachable_count: usize,
+    #[serde(default)]
+    entry_count: usize,
+    #[serde(default)]
+    duration_ms: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unused_exports: Vec<UnusedExport>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unresolved_imports: Vec<UnresolvedImport>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    active_plugins: Vec<String>,
+}
+
+/// An unused file in the JSON report. Reports before schema version 2 list
+/// bare paths, which still parse.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum UnusedFileJson {
+    Entry {
+        path: PathBuf,
+        /// Left out when the sizes were not read
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bytes: Option<u64>,
+    },
+    Path(PathBuf),
+}
+
+impl From<Report> for ReportJson {
+    fn from(report: Report) -> Self {
+        let file_sizes = report.file_sizes;
+        let unused_files = report
+            .unused_files
+            .into_iter()
+            .map(|path| {
+                let bytes = file_sizes.get(&path).copied();
+                UnusedFileJson::Entry { path, bytes }
+            })
+            .collect();
+        ReportJson {
+            schema_version: report.schema_version,
+            unused_files,
+            total_files: report.total_files,
+            unused_count: report.unused_count,
+            total_unused_bytes: report.total_unused_bytes,
+            reachable_count: report.reachable_count,
+            entry_count: report.entry_count,
+            duration_ms: report.duration_ms,
+            unused_exports: report.unused_exports,
+            unresolved_imports: report.unresolved_imports,
+            active_plugins: report.active_plugins,
+        }
+    }
+}
+
+impl From<ReportJson> for Report {
+    fn from(json: ReportJson) -> Self {
+        let mut unused_files = Vec::with_capacity(json.unused_files.len());
+        let mut file_sizes = BTreeMap::new();
+        for file in json.unused_files {
+            match file {
+                UnusedFileJson::Entry { path, bytes } => {
+                    if let Some(bytes) = bytes {
+                        file_sizes.insert(path.clone(), bytes);
+                    }
+                    unused_files.push(path);
+                }
+                UnusedFileJson::Path(path) => unused_files.push(path),
+            }
+        }
+        Report {
+            schema_version: json.schema_version,
+            unused_files,
+            total_files: json.total_files,
+            unused_count: json.unused_count,
+            file_sizes,
+            total_unused_bytes: json.total_unused_bytes,
+            reachable_count: json.reachable_count,
+            entry_count: json.entry_count,
+            duration_ms: json.duration_ms,
+            unused_exports: json.unused_exports,
+            unresolved_imports: json.unresolved_imports,
+            active_plugins: json.active_plugins,
+        }
+    }
+}
+
+/// Changes in unused files between a baseline report and the current one
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ReportDiff {
+    /// Files unused now but not in the baseline (regressions), sorted
+    pub added: Vec<PathBuf>,
+    /// Files unused in the baseline but no longer (deleted or now imported), sorted
+    pub removed: Vec<PathBuf>,
+}
+
+impl ReportDiff {
+    /// Whether the unused files are the same in both reports
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Version of the JSON [`Report`] schema, bumped on breaking changes
+pub const REPORT_SCHEMA_VERSION: u32 = 2;
+
+/// Version of the camelCase [`FullReport`] schema, bumped on breaking changes.
+/// Versioned separately from [`REPORT_SCHEMA_VERSION`], as the shapes differ.
+pub const FULL_REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Stable, versioned summary of a run, meant to be stored as a CI artifact
+/// and diffed over time.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FullReport<'a> {
+    pub schema_version: u32,
+    pub total_files: usize,
+    pub reachable_files: usize,
+    pub unused_count: usize,
+    pub unused_files: &'a [PathBuf],
+    pub entry_count: usize,
+    pub duration_ms: u64,
 }
 
 impl Report {
+    /// Create a report of `unused_files` out of `total_files`. File sizes are left
+    /// empty; see [`Report::with_file_sizes`].
     pub fn new(unused_files: Vec<PathBuf>, total_files: usize) -> Self {
-        let unused_count = unused_files.len();
-        Self { unused_files, total_files, unused_count }
+        Self {
+            schema_version: REPORT_SCHEMA_VERSION,
+            unused_count: unused_files.len(),
+            unused_files,
+            total_files,
+            file_sizes: BTreeMap::new(),
+            total_unused_bytes: 0,
+            reachable_count: 0,
+            entry_count: 0,
+            duration_ms: 0,
+            unused_exports: Vec::new(),
+            unresolved_imports: Vec::new(),
+            active_plugins: Vec::new(),
+        }
+    }
+
+    /// Read the size of each unused file from disk. Files that vanish before they
+    /// can be stat-ed count as zero bytes.
+    pub fn with_file_sizes(mut self) -> Self {
+        self.file_sizes = self
+            .unused_files
+            .iter()
+            .map(|file| (file.clone(), std::fs::metadata(file).map_or(0, |m| m.len())))
+            .collect();
+        self.total_unused_bytes = self.file_sizes.values().sum();
+        self
+    }
+
+    /// Build the versioned [`FullReport`] view of this report
+    pub fn to_full(&self) -> FullReport<'_> {
+        FullReport {
+            schema_version: FULL_REPORT_SCHEMA_VERSION,
+            total_files: self.total_files,
+            reachable_files: self.reachable_count,
+            unused_count: self.unused_count,
+            unused_files: &self.unused_files,
+            entry_count: self.entry_count,
+            duration_ms: self.duration_ms,
+        }
+    }
+
+    /// Serialize the versioned [`FullReport`] view of this report
+    pub fn to_json_full(&self) -> serde_json::Value {
+        serde_json::to_value(self.to_full()).expect("report serializes to JSON")
+    }
+
+    /// Copy of this report with paths relative to `cwd` (paths outside it are kept
+    /// as they are) and unused files sorted, as written by [`report_json`]
+    pub fn relative_to(&self, cwd: &Path) -> Report {
+        let relative = |path: &PathBuf| path.strip_prefix(cwd).unwrap_or(path).to_path_buf();
+
+        let mut unused_files: Vec<PathBuf> = self.unused_files.iter().map(relative).collect();
+        unused_files.sort();
+        let mut unused_exports: Vec<UnusedExport> = self
+            .unused_exports
+            .iter()
+            .map(|export| UnusedExport { file: relative(&export.file), name: export.name.clone() })
+            .collect();
+        unused_exports.sort();
+        let mut unresolved_imports: Vec<UnresolvedImport> = self
+            .unresolved_imports
+            .iter()
+            .map(|import| UnresolvedImport {
+                importer: relative(&import.importer),
+                specifier: import.specifier.clone(),
+            })
+            .collect();
+        unresolved_imports.sort();
+
+        Report {
+            unused_files,
+            file_sizes: self
+                .file_sizes
+                .iter()
+                .map(|(path, size)| (relative(path), *size))
+                .collect(),
+            unused_exports,
+            unresolved_imports,
+            ..self.clone()
+        }
+    }
+
+    /// Compare the unused files of this report against an earlier `baseline` run
+    pub fn diff(&self, baseline: &Report) -> ReportDiff {
+        let current: BTreeSet<&PathBuf> = self.unused_files.iter().collect();
+        let previous: BTreeSet<&PathBuf> = baseline.unused_files.iter().collect();
+        ReportDiff {
+            added: current.difference(&previous).map(|path| (*path).clone()).collect(),
+            removed: previous.difference(&current).map(|path| (*path).clone()).collect(),
+        }
+    }
+}
+
+/// Format a byte count for humans (e.g. `1.2 MB`)
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
     }
+    format!("{value:.1} {}", UNITS[unit])
 }
 
-pub fn report_text(report: &Report, cwd: &Path) {
+/// Print the text report. With `summary`, a breakdown of unused files by extension
+/// follows the list.
+pub fn report_text(report: &Report, cwd: &Path, summary: bool) {
+    print_active_plugins(report);
+    print_unresolved_imports(report, cwd);
+    print_unused_exports(report, cwd);
+
     if report.unused_files.is_empty() {
         println!("No unused files found.");
         return;
@@ -26,9 +302,485 @@ pub fn report_text(report: &Report, cwd: &Path) {
         let relative = file.strip_prefix(cwd).unwrap_or(file);
         println!("  {}", relative.display());
     }
+    if summary {
+        print_extension_histogram(report);
+    }
+    print_summary(report);
+}
+
+/// Like [`report_text`], but with unused files grouped under their CODEOWNERS owners
+pub fn report_text_by_owner(report: &Report, cwd: &Path, codeowners: &CodeOwners, summary: bool) {
+    print_active_plugins(report);
+    print_unresolved_imports(report, cwd);
+    print_unused_exports(report, cwd);
+
+    if report.unused_files.is_empty() {
+        println!("No unused files found.");
+        return;
+    }
+
+    println!("Unused files ({}):", report.unused_count);
+    for (owner, files) in codeowners.group(&report.unused_files, cwd) {
+        println!("  {} ({}):", owner.as_deref().unwrap_or("unowned"), files.len());
+        for file in files {
+            let relative = file.strip_prefix(cwd).unwrap_or(file);
+            println!("    {}", relative.display());
+        }
+    }
+    if summary {
+        print_extension_histogram(report);
+    }
+    print_summary(report);
+}
+
+fn print_active_plugins(report: &Report) {
+    if !report.active_plugins.is_empty() {
+        println!("Active plugins: {}", report.active_plugins.join(", "));
+        println!();
+    }
+}
+
+fn print_unresolved_imports(report: &Report, cwd: &Path) {
+    if !report.unresolved_imports.is_empty() {
+        println!("Could not resolve ({}):", report.unresolved_imports.len());
+        for import in &report.unresolved_imports {
+            let relative = import.importer.strip_prefix(cwd).unwrap_or(&import.importer);
+            println!("  {}: {}", relative.display(), import.specifier);
+        }
+        println!();
+    }
+}
+
+fn print_unused_exports(report: &Report, cwd: &Path) {
+    if !report.unused_exports.is_empty() {
+        println!("Unused exports ({}):", report.unused_exports.len());
+        for export in &report.unused_exports {
+            let relative = export.file.strip_prefix(cwd).unwrap_or(&export.file);
+            println!("  {}: {}", relative.display(), export.name);
+        }
+        println!();
+    }
+}
+
+/// Count `files` by extension (e.g. `.ts`), most common first and ties by name.
+/// Files without an extension are counted under `(none)`.
+pub fn extension_histogram(files: &[PathBuf]) -> Vec<(String, usize)> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for file in files {
+        let extension = match file.extension() {
+            Some(ext) => format!(".{}", ext.to_string_lossy()),
+            None => "(none)".to_string(),
+        };
+        *counts.entry(extension).or_default() += 1;
+    }
+
+    let mut histogram: Vec<(String, usize)> = counts.into_iter().collect();
+    // Stable sort keeps the name order among equal counts
+    histogram.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    histogram
+}
+
+fn print_extension_histogram(report: &Report) {
+    let histogram = extension_histogram(&report.unused_files);
+    let width = histogram.iter().map(|(ext, _)| ext.len()).max().unwrap_or(0);
+    println!("\nBy extension:");
+    for (extension, count) in histogram {
+        println!("  {extension:width$}  {count}");
+    }
+}
+
+fn print_summary(report: &Report) {
     println!("\n{}/{} files unused", report.unused_count, report.total_files);
+    println!("{} reclaimable", format_bytes(report.total_unused_bytes));
 }
 
-pub fn report_json(report: &Report) {
-    println!("{}", serde_json::to_string_pretty(report).unwrap());
+/// Print each unused file relative to `cwd` on its own line, with no header or
+/// summary, for piping into other tools
+pub fn report_paths(report: &Report, cwd: &Path) {
+    for file in &report.unused_files {
+        println!("{}", file.strip_prefix(cwd).unwrap_or(file).display());
+    }
+}
+
+/// Print the changes against a baseline report, with paths relative to `cwd`
+pub fn report_diff_text(diff: &ReportDiff, cwd: &Path) {
+    if diff.is_empty() {
+        println!("No changes in unused files since the baseline.");
+        return;
+    }
+
+    if !diff.added.is_empty() {
+        println!("Newly unused files ({}):", diff.added.len());
+        for file in &diff.added {
+            println!("  + {}", file.strip_prefix(cwd).unwrap_or(file).display());
+        }
+    }
+    if !diff.removed.is_empty() {
+        if !diff.added.is_empty() {
+            println!();
+        }
+        println!("No longer unused ({}):", diff.removed.len());
+        for file in &diff.removed {
+            println!("  - {}", file.strip_prefix(cwd).unwrap_or(file).display());
+        }
+    }
+}
+
+/// Render the JSON report: versioned, with paths relative to `cwd` and sorted unused
+/// files. The output can be read back as a [`Report`] (e.g. for `--baseline`).
+/// Without `pretty`, the report is rendered on a single line.
+pub fn json_report(report: &Report, cwd: &Path, pretty: bool) -> String {
+    let report = report.relative_to(cwd);
+    let json =
+        if pretty { serde_json::to_string_pretty(&report) } else { serde_json::to_string(&report) };
+    json.expect("report serializes to JSON")
+}
+
+pub fn report_json(report: &Report, cwd: &Path, pretty: bool) {
+    println!("{}", json_report(report, cwd, pretty));
+}
+
+/// Build a SARIF 2.1.0 document where each unused file is a `unused-file` result
+pub fn sarif_document(report: &Report, cwd: &Path) -> serde_json::Value {
+    let results: Vec<_> = report
+        .unused_files
+        .iter()
+        .map(|file| {
+            let relative = file.strip_prefix(cwd).unwrap_or(file);
+            json!({
+                "ruleId": "unused-file",
+                "level": "warning",
+                "message": { "text": "File is not reachable from any entry point" },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": relative.to_string_lossy().replace('\\', "/"),
+                            "uriBaseId": "%SRCROOT%"
+                        }
+                    }
+                }]
+            })
+        })
+        .collect();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "muri",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/agneym/muri",
+                    "rules": [{
+                        "id": "unused-file",
+                        "name": "UnusedFile",
+                        "shortDescription": { "text": "Unused file" },
+                        "fullDescription": {
+                            "text": "The file is not imported, directly or transitively, by any entry point."
+                        },
+                        "defaultConfiguration": { "level": "warning" }
+                    }]
+                }
+            },
+            "results": results
+        }]
+    })
+}
+
+/// Print the SARIF document, on a single line without `pretty`
+pub fn report_sarif(report: &Report, cwd: &Path, pretty: bool) {
+    let document = sarif_document(report, cwd);
+    let json = if pretty {
+        serde_json::to_string_pretty(&document)
+    } else {
+        serde_json::to_string(&document)
+    };
+    println!("{}", json.expect("SARIF document serializes to JSON"));
+}
+
+/// Number of entries above which the Markdown file list is collapsed
+const MARKDOWN_COLLAPSE_THRESHOLD: usize = 20;
+
+/// Render a Markdown report suitable for pull request comments.
+///
+/// Paths are relative to `cwd`, sorted, and grouped by top-level directory.
+pub fn markdown_report(report: &Report, cwd: &Path) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "**{} unused of {} files**", report.unused_count, report.total_files);
+
+    if report.unused_files.is_empty() {
+        return out;
+    }
+
+    // Group relative paths by top-level directory (files at the root go under ".")
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for file in &report.unused_files {
+        let relative = file.strip_prefix(cwd).unwrap_or(file);
+        let display = relative.to_string_lossy().replace('\\', "/");
+        let group = match display.split_once('/') {
+            Some((dir, _)) => dir.to_string(),
+            None => ".".to_string(),
+        };
+        groups.entry(group).or_default().push(display);
+    }
+
+    let collapse = report.unused_files.len() > MARKDOWN_COLLAPSE_THRESHOLD;
+    out.push('\n');
+    if collapse {
+        let _ = writeln!(out, "<details>\n<summary>Show unused files</summary>\n");
+    }
+
+    for (group, mut files) in groups {
+        files.sort();
+        let _ = writeln!(out, "#### `{group}`\n");
+        for file in files {
+            let _ = writeln!(out, "- `{file}`");
+        }
+        out.push('\n');
+    }
+
+    if collapse {
+        let _ = writeln!(out, "</details>");
+    }
+
+    out
+}
+
+pub fn report_markdown(report: &Report, cwd: &Path) {
+    print!("{}", markdown_report(report, cwd));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sarif_document_round_trips() {
+        let cwd = Path::new("/project");
+        let report = Report::new(
+            vec![PathBuf::from("/project/src/a.ts"), PathBuf::from("/project/src/b.ts")],
+            10,
+        );
+
+        let document = sarif_document(&report, cwd);
+        let serialized = serde_json::to_string(&document).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(parsed, document);
+
+        assert_eq!(parsed["version"], "2.1.0");
+        let run = &parsed["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "muri");
+        assert_eq!(run["tool"]["driver"]["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "unused-file");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "unused-file");
+        assert_eq!(results[0]["level"], "warning");
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/a.ts"
+        );
+    }
+
+    #[test]
+    fn test_extension_histogram_counts_by_extension() {
+        let files: Vec<PathBuf> = [
+            "/project/src/a.ts",
+            "/project/src/b.tsx",
+            "/project/src/c.ts",
+            "/project/styles/d.scss",
+            "/project/src/e.ts",
+            "/project/src/f.tsx",
+            "/project/bin/run",
+            "/project/src/g.js",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+        assert_eq!(
+            extension_histogram(&files),
+            vec![
+                (".ts".to_string(), 3),
+                (".tsx".to_string(), 2),
+                ("(none)".to_string(), 1),
+                (".js".to_string(), 1),
+                (".scss".to_string(), 1),
+            ]
+        );
+        assert!(extension_histogram(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_markdown_report_header_and_relative_paths() {
+        let cwd = Path::new("/project");
+        let report = Report::new(
+            vec![
+                PathBuf::from("/project/src/b.ts"),
+                PathBuf::from("/project/src/a.ts"),
+                PathBuf::from("/project/scripts/build.js"),
+                PathBuf::from("/project/root.ts"),
+            ],
+            12,
+        );
+
+        let markdown = markdown_report(&report, cwd);
+        assert!(markdown.starts_with("**4 unused of 12 files**\n"));
+        assert!(!markdown.contains("/project"));
+        assert!(!markdown.contains("<details>"));
+
+        let a = markdown.find("- `src/a.ts`").unwrap();
+        let b = markdown.find("- `src/b.ts`").unwrap();
+        assert!(a < b);
+        assert!(markdown.contains("#### `scripts`"));
+        assert!(markdown.contains("#### `.`\n\n- `root.ts`"));
+    }
+
+    #[test]
+    fn test_markdown_report_collapses_long_lists() {
+        let cwd = Path::new("/project");
+        let files = (0..21).map(|i| PathBuf::from(format!("/project/src/{i}.ts"))).collect();
+        let report = Report::new(files, 30);
+
+        let markdown = markdown_report(&report, cwd);
+        assert!(markdown.contains("<details>"));
+        assert!(markdown.trim_end().ends_with("</details>"));
+    }
+
+    #[test]
+    fn test_report_aggregates_file_sizes() {
+        let temp = tempfile::tempdir().unwrap();
+        let a = temp.path().join("a.ts");
+        let b = temp.path().join("b.ts");
+        std::fs::write(&a, "12345").unwrap();
+        std::fs::write(&b, "1234567890").unwrap();
+        let missing = temp.path().join("missing.ts");
+
+        let report = Report::new(vec![a.clone(), b.clone(), missing.clone()], 5);
+        assert!(report.file_sizes.is_empty());
+        assert_eq!(report.total_unused_bytes, 0);
+
+        let report = report.with_file_sizes();
+        assert_eq!(report.file_sizes[&a], 5);
+        assert_eq!(report.file_sizes[&b], 10);
+        assert_eq!(report.file_sizes[&missing], 0);
+        assert_eq!(report.total_unused_bytes, 15);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["total_unused_bytes"], 15);
+        assert_eq!(json["unused_files"][0], json!({ "path": a, "bytes": 5 }));
+        assert_eq!(json["unused_files"][2], json!({ "path": missing, "bytes": 0 }));
+        assert!(json.get("file_sizes").is_none());
+    }
+
+    #[test]
+    fn test_json_report_compact_and_pretty() {
+        let cwd = Path::new("/project");
+        let report = Report::new(
+            vec![PathBuf::from("/project/src/a.ts"), PathBuf::from("/project/src/b.ts")],
+            10,
+        );
+
+        let compact = json_report(&report, cwd, false);
+        assert!(!compact.contains('\n'));
+        let pretty = json_report(&report, cwd, true);
+        assert!(pretty.contains('\n'));
+
+        let compact: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let pretty: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(compact, pretty);
+    }
+
+    #[test]
+    fn test_unversioned_report_parses_with_defaults() {
+        let parsed: Report = serde_json::from_str(r#"{ "unused_files": ["src/a.ts"] }"#).unwrap();
+        assert_eq!(parsed.schema_version, 0);
+        assert_eq!(parsed.unused_files, vec![PathBuf::from("src/a.ts")]);
+        assert_eq!(parsed.total_files, 0);
+        assert!(parsed.file_sizes.is_empty());
+        assert_eq!(parsed.duration_ms, 0);
+    }
+
+    #[test]
+    fn test_unused_files_without_sizes_omit_bytes() {
+        let report = Report::new(vec![PathBuf::from("/project/src/a.ts")], 1);
+        let json = json_report(&report, Path::new("/project"), false);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["unused_files"], json!([{ "path": "src/a.ts" }]));
+
+        let parsed: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.unused_files, vec![PathBuf::from("src/a.ts")]);
+        assert!(parsed.file_sizes.is_empty());
+    }
+
+    #[test]
+    fn test_json_report_round_trips() {
+        let cwd = Path::new("/project");
+        let mut report = Report::new(
+            vec![PathBuf::from("/project/src/b.ts"), PathBuf::from("/project/src/a.ts")],
+            10,
+        )
+        .with_file_sizes();
+        report.reachable_count = 8;
+        report.entry_count = 1;
+        report.unused_exports =
+            vec![UnusedExport { file: PathBuf::from("/project/src/c.ts"), name: "c".to_string() }];
+
+        let json = json_report(&report, cwd, true);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(
+            value["unused_files"],
+            json!([{ "path": "src/a.ts", "bytes": 0 }, { "path": "src/b.ts", "bytes": 0 }])
+        );
+
+        let parsed: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.schema_version, REPORT_SCHEMA_VERSION);
+        assert_eq!(parsed.unused_files, vec![PathBuf::from("src/a.ts"), PathBuf::from("src/b.ts")]);
+        assert_eq!(parsed.unused_count, 2);
+        assert_eq!(parsed.total_files, 10);
+        assert_eq!(parsed.reachable_count, 8);
+        assert_eq!(parsed.entry_count, 1);
+        assert_eq!(
+            parsed.file_sizes.keys().collect::<Vec<_>>(),
+            parsed.unused_files.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(parsed.unused_exports[0].file, PathBuf::from("src/c.ts"));
+
+        // Serializing the parsed report gives the same document
+        assert_eq!(serde_json::to_string_pretty(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_diff_against_baseline() {
+        let paths = |names: &[&str]| -> Vec<PathBuf> {
+            names.iter().map(|name| PathBuf::from(format!("/project/{name}"))).collect()
+        };
+        let baseline = Report::new(paths(&["a.ts", "b.ts", "c.ts"]), 10);
+        let current = Report::new(paths(&["d.ts", "b.ts", "a.ts"]), 10);
+
+        let diff = current.diff(&baseline);
+        assert_eq!(diff.added, paths(&["d.ts"]));
+        assert_eq!(diff.removed, paths(&["c.ts"]));
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn test_diff_unchanged() {
+        let baseline = Report::new(vec![PathBuf::from("/project/a.ts")], 3);
+        let current = Report::new(vec![PathBuf::from("/project/a.ts")], 4);
+
+        assert!(current.diff(&baseline).is_empty());
+        assert_eq!(current.diff(&baseline), ReportDiff::default());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(1_258_291), "1.2 MB");
+    }
 }
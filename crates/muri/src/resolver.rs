@@ -1,9 +1,29 @@
+use crate::parser::ImportKind;
+use crate::plugin::{ModuleAlias, strip_json_comments};
 use crate::types::{DEFAULT_EXTENSIONS, FOREIGN_FILE_EXTENSIONS};
-use oxc_resolver::{ResolveOptions, Resolver, TsconfigOptions, TsconfigReferences};
-use std::path::{Path, PathBuf};
+use crate::workspace::workspace_package_index;
+use dashmap::DashMap;
+use globset::{GlobBuilder, GlobSetBuilder};
+use ignore::WalkBuilder;
+use oxc_resolver::{ResolveError, ResolveOptions, Resolver, TsconfigOptions, TsconfigReferences};
+use regex::Regex;
+use rustc_hash::FxHashMap;
+use std::path::{Component, Path, PathBuf};
 
 pub struct ModuleResolver {
     resolver: Resolver,
+    /// `compilerOptions.rootDirs` from tsconfig.json: roots merged into one virtual
+    /// directory, so relative imports may cross from one root into another
+    root_dirs: Vec<PathBuf>,
+    /// Workspace packages by name, so `@acme/utils` resolves to the local package
+    /// directory rather than whatever `node_modules` holds
+    workspace_packages: FxHashMap<String, PathBuf>,
+    /// Tool-specific module aliases (e.g. Jest's `moduleNameMapper`), tried when an
+    /// import fails to resolve
+    aliases: Vec<(Regex, Vec<String>)>,
+    /// Resolutions by `(importer directory, specifier)`, since the same specifier is
+    /// usually imported from many files in a directory
+    cache: DashMap<(PathBuf, String), Option<PathBuf>>,
 }
 
 impl ModuleResolver {
@@ -30,6 +50,8 @@ impl ModuleResolver {
         let options = ResolveOptions {
             builtin_modules: true,
             tsconfig,
+            // Specifiers with an explicit extension (e.g. `./foo.ts` under
+            // `allowImportingTsExtensions`) resolve to that exact file before probing
             extensions,
             extension_alias: vec![
                 (".js".into(), vec![".js".into(), ".ts".into(), ".tsx".into()]),
@@ -43,15 +65,52 @@ impl ModuleResolver {
                 "node".into(),
                 "default".into(),
             ],
-            main_fields: vec!["module".into(), "main".into()],
+            // package.json `browser`: a string replaces `main`, an object remaps
+            // module paths (e.g. `{ "./node.js": "./browser.js" }`)
+            main_fields: vec!["browser".into(), "module".into(), "main".into()],
+            alias_fields: vec![vec!["browser".into()]],
+            // Server-relative URLs (`/src/main.ts` in `index.html`) point into the
+            // project, or into `public/`, which Vite and other dev servers serve at `/`
+            roots: vec![cwd.to_path_buf(), cwd.join("public")],
             ..Default::default()
         };
 
-        Self { resolver: Resolver::new(options) }
+        Self {
+            resolver: Resolver::new(options),
+            root_dirs: read_root_dirs(cwd),
+            workspace_packages: workspace_package_index(cwd),
+            aliases: Vec::new(),
+            cache: DashMap::new(),
+        }
+    }
+
+    /// Apply module aliases from plugins to imports that don't resolve otherwise.
+    /// Aliases with an invalid pattern are skipped.
+    pub fn with_aliases(mut self, aliases: &[ModuleAlias]) -> Self {
+        self.aliases.extend(
+            aliases.iter().filter_map(|alias| {
+                Some((Regex::new(&alias.pattern).ok()?, alias.targets.clone()))
+            }),
+        );
+        self
     }
 
     pub fn resolve(&self, from: &Path, specifier: &str) -> Option<PathBuf> {
         let dir = from.parent()?;
+        let key = (dir.to_path_buf(), specifier.to_string());
+        if let Some(resolved) = self.cache.get(&key) {
+            return resolved.clone();
+        }
+        let resolved = self.resolve_in_dir(dir, specifier);
+        self.cache.insert(key, resolved.clone());
+        resolved
+    }
+
+    /// Resolve `specifier` from `dir`, bypassing the cache
+    fn resolve_in_dir(&self, dir: &Path, specifier: &str) -> Option<PathBuf> {
+        if let Some(path) = self.resolve_workspace_package(specifier) {
+            return Some(path);
+        }
 
         if let Ok(resolution) = self.resolver.resolve(dir, specifier) {
             if let Ok(path) = resolution.into_path_buf().canonicalize() {
@@ -59,6 +118,649 @@ impl ModuleResolver {
             }
         }
 
-        None
+        self.resolve_in_root_dirs(dir, specifier).or_else(|| self.resolve_alias(dir, specifier))
+    }
+
+    /// Resolve `specifier` through the first matching alias whose target exists.
+    /// `$1`-style references in a target are replaced with the pattern's captures.
+    fn resolve_alias(&self, dir: &Path, specifier: &str) -> Option<PathBuf> {
+        self.aliases.iter().find_map(|(pattern, targets)| {
+            let captures = pattern.captures(specifier)?;
+            targets.iter().find_map(|target| {
+                let mapped = expand_captures(target, &captures);
+                let resolution = self.resolver.resolve(dir, &mapped).ok()?;
+                resolution.into_path_buf().canonicalize().ok()
+            })
+        })
+    }
+
+    /// Resolve a bare import of a workspace package (`@acme/utils`, `@acme/utils/format`)
+    /// inside the package directory. The package's own `exports` apply, as for a
+    /// self-reference; without them the subpath (or `main`) is resolved relative to
+    /// the package.
+    fn resolve_workspace_package(&self, specifier: &str) -> Option<PathBuf> {
+        let (name, subpath) = split_package_specifier(specifier)?;
+        let dir = self.workspace_packages.get(name)?;
+
+        let resolution = self.resolver.resolve(dir, specifier).or_else(|_| {
+            let relative =
+                subpath.map_or_else(|| ".".to_string(), |subpath| format!("./{subpath}"));
+            self.resolver.resolve(dir, &relative)
+        });
+        resolution.ok()?.into_path_buf().canonicalize().ok()
+    }
+
+    /// Retry a relative import that failed in `dir` under each of the other `rootDirs`:
+    /// `./gen/api` from `src/views` is also looked up as `generated/views/gen/api`
+    /// when `src` and `generated` are both roots
+    fn resolve_in_root_dirs(&self, dir: &Path, specifier: &str) -> Option<PathBuf> {
+        if !specifier.starts_with("./") && !specifier.starts_with("../") {
+            return None;
+        }
+
+        let target = normalize(&dir.join(specifier));
+        // The most specific root containing the target, as TypeScript picks it
+        let (root, relative) = self
+            .root_dirs
+            .iter()
+            .filter_map(|root| Some((root, target.strip_prefix(root).ok()?)))
+            .max_by_key(|(root, _)| root.as_os_str().len())?;
+
+        self.root_dirs.iter().filter(|other| *other != root).find_map(|other| {
+            let specifier = format!("./{}", relative.to_string_lossy().replace('\\', "/"));
+            let resolution = self.resolver.resolve(other, &specifier).ok()?;
+            resolution.into_path_buf().canonicalize().ok()
+        })
+    }
+
+    /// Whether `specifier` fails to resolve to a file from `from`. Node.js builtins
+    /// and modules disabled through the package.json `browser` field are not failures.
+    pub fn is_unresolved(&self, from: &Path, specifier: &str, kind: &ImportKind) -> bool {
+        let Some(dir) = from.parent() else {
+            return true;
+        };
+        if *kind == ImportKind::UseSass && resolve_sass(dir, specifier).is_some() {
+            return false;
+        }
+        if self.resolve_workspace_package(specifier).is_some() {
+            return false;
+        }
+        match self.resolver.resolve(dir, specifier) {
+            Ok(resolution) => resolution.into_path_buf().canonicalize().is_err(),
+            Err(ResolveError::Builtin { .. } | ResolveError::Ignored(_)) => false,
+            Err(_) => {
+                self.resolve_in_root_dirs(dir, specifier).is_none()
+                    && self.resolve_alias(dir, specifier).is_none()
+            }
+        }
+    }
+
+    /// Resolve an import to the files it may load: one file for a specifier,
+    /// or every match for a `DynamicGlob`. Sass loads try Sass's partial and
+    /// index files first.
+    pub fn resolve_import(&self, from: &Path, specifier: &str, kind: &ImportKind) -> Vec<PathBuf> {
+        match kind {
+            ImportKind::DynamicGlob => self.expand_glob(from, specifier),
+            ImportKind::UseSass => from
+                .parent()
+                .and_then(|dir| resolve_sass(dir, specifier))
+                .or_else(|| self.resolve(from, specifier))
+                .into_iter()
+                .collect(),
+            _ => self.resolve(from, specifier).into_iter().collect(),
+        }
+    }
+
+    /// Expand a relative glob (e.g. `./locales/*.js`) against the filesystem.
+    /// `*` does not cross directories, and `.js`-style extensions also match
+    /// their TypeScript counterparts, mirroring `extension_alias`.
+    pub fn expand_glob(&self, from: &Path, pattern: &str) -> Vec<PathBuf> {
+        let Some(dir) = from.parent() else {
+            return Vec::new();
+        };
+
+        // Split into the static directory to walk and the glob relative to it
+        let first_wildcard = pattern.find(['*', '?', '[', '{']).unwrap_or(pattern.len());
+        let static_end = pattern[..first_wildcard].rfind('/').map_or(0, |i| i + 1);
+        let base = dir.join(&pattern[..static_end]);
+        let relative_glob = &pattern[static_end..];
+
+        let mut builder = GlobSetBuilder::new();
+        for candidate in with_extension_aliases(relative_glob) {
+            if let Ok(glob) = GlobBuilder::new(&candidate).literal_separator(true).build() {
+                builder.add(glob);
+            }
+        }
+        let Ok(matcher) = builder.build() else {
+            return Vec::new();
+        };
+
+        let depth = relative_glob.matches('/').count() + 1;
+        let mut matches: Vec<PathBuf> = WalkBuilder::new(&base)
+            .max_depth(Some(depth))
+            .hidden(false)
+            .require_git(false)
+            .build()
+            .flatten()
+            .filter(|entry| entry.file_type().is_some_and(|t| t.is_file()))
+            .filter(|entry| {
+                entry.path().strip_prefix(&base).is_ok_and(|relative| matcher.is_match(relative))
+            })
+            .filter_map(|entry| entry.path().canonicalize().ok())
+            .collect();
+
+        matches.sort();
+        matches
+    }
+}
+
+/// Read `compilerOptions.rootDirs` from the project's tsconfig.json, as canonical
+/// paths. Roots that don't exist are dropped.
+fn read_root_dirs(cwd: &Path) -> Vec<PathBuf> {
+    let Ok(content) = std::fs::read_to_string(cwd.join("tsconfig.json")) else {
+        return Vec::new();
+    };
+    let Ok(tsconfig) = serde_json::from_str::<serde_json::Value>(&strip_json_comments(&content))
+    else {
+        return Vec::new();
+    };
+
+    tsconfig
+        .pointer("/compilerOptions/rootDirs")
+        .and_then(|dirs| dirs.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|dir| dir.as_str())
+        .filter_map(|dir| cwd.join(dir).canonicalize().ok())
+        .collect()
+}
+
+/// Lexically resolve `.` and `..` components, without touching the filesystem
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// A glob plus variants with TypeScript extensions (`*.js` also matches `*.ts`/`*.tsx`)
+fn with_extension_aliases(glob: &str) -> Vec<String> {
+    const ALIASES: &[(&str, &[&str])] =
+        &[(".js", &[".ts", ".tsx"]), (".jsx", &[".tsx"]), (".mjs", &[".mts"]), (".cjs", &[".cts"])];
+
+    let mut globs = vec![glob.to_string()];
+    for (ext, aliases) in ALIASES {
+        if let Some(stem) = glob.strip_suffix(ext) {
+            globs.extend(aliases.iter().map(|alias| format!("{stem}{alias}")));
+        }
+    }
+    globs
+}
+
+/// Stylesheet extensions Sass loads, in the order it tries them
+const SASS_EXTENSIONS: &[&str] = &["scss", "sass", "css"];
+
+/// Resolve a `@use`/`@forward`/`@import` load relative to the stylesheet, as Sass
+/// does before falling back to load paths: `button` may be `button.scss`, the
+/// partial `_button.scss`, or the directory index `button/_index.scss` (each with
+/// `.sass` and `.css` variants). Bare loads are tried relative to `dir` too.
+fn resolve_sass(dir: &Path, specifier: &str) -> Option<PathBuf> {
+    if specifier.starts_with('~') || specifier.starts_with("sass:") || specifier.contains("://") {
+        return None;
+    }
+
+    let target = dir.join(specifier);
+    let name = target.file_name()?.to_string_lossy().into_owned();
+    let has_extension = Path::new(&name)
+        .extension()
+        .is_some_and(|ext| SASS_EXTENSIONS.iter().any(|sass_ext| ext == *sass_ext));
+
+    let candidates: Vec<PathBuf> = if has_extension {
+        vec![target.clone(), target.with_file_name(format!("_{name}"))]
+    } else {
+        let files = SASS_EXTENSIONS
+            .iter()
+            .map(|ext| format!("{name}.{ext}"))
+            .chain(SASS_EXTENSIONS.iter().map(|ext| format!("_{name}.{ext}")))
+            .map(|file| target.with_file_name(file));
+        let indexes = ["_index", "index"]
+            .iter()
+            .flat_map(|index| SASS_EXTENSIONS.iter().map(move |ext| format!("{index}.{ext}")))
+            .map(|file| target.join(file));
+        files.chain(indexes).collect()
+    };
+
+    candidates.into_iter().find(|candidate| candidate.is_file())?.canonicalize().ok()
+}
+
+/// Replace `$1`-style references in an alias target with the matching captures.
+/// References to missing groups become empty, as in JavaScript's `String.replace`.
+fn expand_captures(target: &str, captures: &regex::Captures) -> String {
+    let mut expanded = String::with_capacity(target.len());
+    let mut rest = target;
+    while let Some(dollar) = rest.find('$') {
+        expanded.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+        let digits = after.len() - after.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            expanded.push('$');
+        } else if let Ok(group) = after[..digits].parse::<usize>() {
+            expanded.push_str(captures.get(group).map_or("", |m| m.as_str()));
+        }
+        rest = &after[digits..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Split a bare specifier into its package name and subpath:
+/// `@acme/utils/format` -> (`@acme/utils`, `Some("format")`). Relative, absolute,
+/// and protocol (`node:fs`) specifiers are not package imports.
+fn split_package_specifier(specifier: &str) -> Option<(&str, Option<&str>)> {
+    if specifier.starts_with(['.', '/', '#']) || specifier.contains(':') {
+        return None;
+    }
+    let name_len = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        specifier[scope_end + 1..].find('/').map_or(specifier.len(), |i| scope_end + 1 + i)
+    } else {
+        specifier.find('/').unwrap_or(specifier.len())
+    };
+    let (name, rest) = specifier.split_at(name_len);
+    Some((name, rest.strip_prefix('/').filter(|subpath| !subpath.is_empty())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_explicit_ts_extension() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("index.ts"), "import foo from './foo.ts';").unwrap();
+        fs::write(cwd.join("foo.ts"), "export default 1;").unwrap();
+        // A sibling with the same stem must not take precedence over the explicit extension
+        fs::write(cwd.join("foo.ts.js"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let resolved = resolver.resolve(&cwd.join("index.ts"), "./foo.ts");
+        assert_eq!(resolved, Some(cwd.join("foo.ts")));
+    }
+
+    #[test]
+    fn test_repeated_resolution_is_cached() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(cwd.join("src")).unwrap();
+        fs::write(cwd.join("src/utils.ts"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let first = resolver.resolve(&cwd.join("src/a.ts"), "./utils");
+        assert_eq!(first, Some(cwd.join("src/utils.ts")));
+        assert_eq!(resolver.cache.len(), 1);
+
+        // Another importer in the same directory is served from the cache, even once
+        // the file is gone
+        fs::remove_file(cwd.join("src/utils.ts")).unwrap();
+        assert_eq!(resolver.resolve(&cwd.join("src/b.ts"), "./utils"), first);
+        assert_eq!(resolver.cache.len(), 1);
+        assert_eq!(ModuleResolver::new(&cwd).resolve(&cwd.join("src/b.ts"), "./utils"), None);
+
+        // Misses are cached too, per directory
+        assert_eq!(resolver.resolve(&cwd.join("index.ts"), "./utils"), None);
+        assert_eq!(resolver.cache.len(), 2);
+    }
+
+    #[test]
+    fn test_explicit_tsx_extension() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("index.tsx"), "import Foo from './Foo.tsx';").unwrap();
+        fs::write(cwd.join("Foo.tsx"), "export default () => null;").unwrap();
+        fs::write(cwd.join("Foo.ts"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let resolved = resolver.resolve(&cwd.join("index.tsx"), "./Foo.tsx");
+        assert_eq!(resolved, Some(cwd.join("Foo.tsx")));
+    }
+
+    #[test]
+    fn test_explicit_extension_with_allow_importing_ts_extensions() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        let tsconfig = r#"{
+  "compilerOptions": {
+    "allowImportingTsExtensions": true,
+    "noEmit": true
+  }
+}"#;
+        fs::write(cwd.join("tsconfig.json"), tsconfig).unwrap();
+        fs::create_dir(cwd.join("src")).unwrap();
+        fs::write(cwd.join("src/index.ts"), "import { a } from './a.ts';").unwrap();
+        fs::write(cwd.join("src/a.ts"), "export const a = 1;").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let resolved = resolver.resolve(&cwd.join("src/index.ts"), "./a.ts");
+        assert_eq!(resolved, Some(cwd.join("src/a.ts")));
+    }
+
+    #[test]
+    fn test_nodenext_js_extension_maps_to_ts_source() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("index.ts"), "").unwrap();
+        for file in ["a.ts", "b.tsx", "c.tsx", "d.mts", "e.cts"] {
+            fs::write(cwd.join(file), "").unwrap();
+        }
+
+        let resolver = ModuleResolver::new(&cwd);
+        let from = cwd.join("index.ts");
+        for (specifier, expected) in [
+            ("./a.js", "a.ts"),
+            ("./b.js", "b.tsx"),
+            ("./c.jsx", "c.tsx"),
+            ("./d.mjs", "d.mts"),
+            ("./e.cjs", "e.cts"),
+        ] {
+            assert_eq!(resolver.resolve(&from, specifier), Some(cwd.join(expected)), "{specifier}");
+        }
+    }
+
+    #[test]
+    fn test_existing_js_file_wins_over_ts_source() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("index.ts"), "").unwrap();
+        fs::write(cwd.join("a.js"), "").unwrap();
+        fs::write(cwd.join("a.ts"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        assert_eq!(resolver.resolve(&cwd.join("index.ts"), "./a.js"), Some(cwd.join("a.js")));
+    }
+
+    #[test]
+    fn test_root_dirs_merge_relative_imports() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(
+            cwd.join("tsconfig.json"),
+            r#"{
+  // Generated code lives beside the sources in a virtual merged tree
+  "compilerOptions": { "rootDirs": ["src", "generated"] }
+}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(cwd.join("src/views")).unwrap();
+        fs::create_dir_all(cwd.join("generated/views")).unwrap();
+        fs::write(cwd.join("src/views/home.ts"), "import { api } from './api.generated';").unwrap();
+        fs::write(cwd.join("src/theme.ts"), "").unwrap();
+        fs::write(cwd.join("generated/views/api.generated.ts"), "").unwrap();
+        fs::write(cwd.join("generated/views/client.ts"), "import '../theme';").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let home = cwd.join("src/views/home.ts");
+        assert_eq!(
+            resolver.resolve(&home, "./api.generated"),
+            Some(cwd.join("generated/views/api.generated.ts"))
+        );
+        assert!(!resolver.is_unresolved(&home, "./api.generated", &ImportKind::Static));
+
+        // And back from the generated root into the sources
+        let client = cwd.join("generated/views/client.ts");
+        assert_eq!(resolver.resolve(&client, "../theme"), Some(cwd.join("src/theme.ts")));
+
+        // Paths missing from every root still fail, as do imports leaving the roots
+        assert_eq!(resolver.resolve(&home, "./missing"), None);
+        assert!(resolver.is_unresolved(&home, "./missing", &ImportKind::Static));
+        assert_eq!(resolver.resolve(&home, "../../generated/missing"), None);
+    }
+
+    #[test]
+    fn test_relative_imports_without_root_dirs() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(cwd.join("src")).unwrap();
+        fs::create_dir_all(cwd.join("generated")).unwrap();
+        fs::write(cwd.join("src/index.ts"), "").unwrap();
+        fs::write(cwd.join("generated/api.ts"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        assert_eq!(resolver.resolve(&cwd.join("src/index.ts"), "./api"), None);
+    }
+
+    #[test]
+    fn test_base_url_src() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("tsconfig.json"), r#"{"compilerOptions":{"baseUrl":"src"}}"#).unwrap();
+        fs::create_dir_all(cwd.join("src/components/Button")).unwrap();
+        fs::write(cwd.join("src/index.ts"), "").unwrap();
+        fs::write(cwd.join("src/components/Button/index.tsx"), "").unwrap();
+        fs::write(cwd.join("src/utils.ts"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let from = cwd.join("src/index.ts");
+        assert_eq!(
+            resolver.resolve(&from, "components/Button"),
+            Some(cwd.join("src/components/Button/index.tsx"))
+        );
+        assert_eq!(resolver.resolve(&from, "utils"), Some(cwd.join("src/utils.ts")));
+    }
+
+    #[test]
+    fn test_base_url_dot_via_extends() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("tsconfig.base.json"), r#"{"compilerOptions":{"baseUrl":"."}}"#)
+            .unwrap();
+        fs::write(cwd.join("tsconfig.json"), r#"{"extends":"./tsconfig.base.json"}"#).unwrap();
+        fs::create_dir_all(cwd.join("src/lib")).unwrap();
+        fs::write(cwd.join("src/index.ts"), "").unwrap();
+        fs::write(cwd.join("src/lib/format.ts"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        assert_eq!(
+            resolver.resolve(&cwd.join("src/index.ts"), "src/lib/format"),
+            Some(cwd.join("src/lib/format.ts"))
+        );
+    }
+
+    #[test]
+    fn test_browser_field_string_replaces_main() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        let pkg = cwd.join("node_modules/pkg");
+        fs::create_dir_all(&pkg).unwrap();
+        fs::write(pkg.join("package.json"), r#"{"main":"node.js","browser":"browser.js"}"#)
+            .unwrap();
+        fs::write(pkg.join("node.js"), "").unwrap();
+        fs::write(pkg.join("browser.js"), "").unwrap();
+        fs::write(cwd.join("index.ts"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        assert_eq!(resolver.resolve(&cwd.join("index.ts"), "pkg"), Some(pkg.join("browser.js")));
+    }
+
+    #[test]
+    fn test_browser_field_object_remaps_module() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::write(cwd.join("package.json"), r#"{"browser":{"./src/node.js":"./src/browser.js"}}"#)
+            .unwrap();
+        fs::create_dir(cwd.join("src")).unwrap();
+        fs::write(cwd.join("src/index.js"), "").unwrap();
+        fs::write(cwd.join("src/node.js"), "").unwrap();
+        fs::write(cwd.join("src/browser.js"), "").unwrap();
+        fs::write(cwd.join("src/other.js"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let from = cwd.join("src/index.js");
+        assert_eq!(resolver.resolve(&from, "./node.js"), Some(cwd.join("src/browser.js")));
+        assert_eq!(resolver.resolve(&from, "./other.js"), Some(cwd.join("src/other.js")));
+    }
+
+    #[test]
+    fn test_expand_glob_matches_files_and_ts_aliases() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(cwd.join("locales/nested")).unwrap();
+        fs::write(cwd.join("index.ts"), "").unwrap();
+        fs::write(cwd.join("locales/en.js"), "").unwrap();
+        fs::write(cwd.join("locales/fr.ts"), "").unwrap();
+        fs::write(cwd.join("locales/readme.md"), "").unwrap();
+        fs::write(cwd.join("locales/nested/de.js"), "").unwrap();
+
+        let resolver = ModuleResolver::new(&cwd);
+        let matches = resolver.expand_glob(&cwd.join("index.ts"), "./locales/*.js");
+        assert_eq!(matches, vec![cwd.join("locales/en.js"), cwd.join("locales/fr.ts")]);
+    }
+
+    #[test]
+    fn test_workspace_package_by_name() {
+        let temp = tempdir().unwrap();
+        let root = temp.path().canonicalize().unwrap();
+        fs::write(root.join("package.json"), r#"{ "workspaces": ["packages/*"] }"#).unwrap();
+        for dir in ["packages/app/src", "packages/utils/src", "packages/ui/src"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("packages/app/package.json"), r#"{ "name": "app" }"#).unwrap();
+        fs::write(root.join("packages/app/src/index.ts"), "").unwrap();
+        fs::write(
+            root.join("packages/utils/package.json"),
+            r#"{ "name": "@acme/utils", "main": "./src/index.ts" }"#,
+        )
+        .unwrap();
+        fs::write(root.join("packages/utils/src/index.ts"), "").unwrap();
+        fs::write(root.join("packages/utils/src/format.ts"), "").unwrap();
+        fs::write(
+            root.join("packages/ui/package.json"),
+            r#"{ "name": "@acme/ui", "exports": { ".": "./src/index.tsx", "./button": "./src/button.tsx" } }"#,
+        )
+        .unwrap();
+        fs::write(root.join("packages/ui/src/index.tsx"), "").unwrap();
+        fs::write(root.join("packages/ui/src/button.tsx"), "").unwrap();
+
+        // Run from a single package: its siblings are still found from the workspace root
+        let resolver = ModuleResolver::new(&root.join("packages/app"));
+        let from = root.join("packages/app/src/index.ts");
+        assert_eq!(
+            resolver.resolve(&from, "@acme/utils"),
+            Some(root.join("packages/utils/src/index.ts"))
+        );
+        assert_eq!(
+            resolver.resolve(&from, "@acme/utils/src/format"),
+            Some(root.join("packages/utils/src/format.ts"))
+        );
+        assert_eq!(
+            resolver.resolve(&from, "@acme/ui"),
+            Some(root.join("packages/ui/src/index.tsx"))
+        );
+        assert_eq!(
+            resolver.resolve(&from, "@acme/ui/button"),
+            Some(root.join("packages/ui/src/button.tsx"))
+        );
+        assert!(!resolver.is_unresolved(&from, "@acme/ui/button", &ImportKind::Static));
+        assert!(resolver.is_unresolved(&from, "@acme/missing", &ImportKind::Static));
+    }
+
+    #[test]
+    fn test_split_package_specifier() {
+        assert_eq!(split_package_specifier("react"), Some(("react", None)));
+        assert_eq!(split_package_specifier("lodash/get"), Some(("lodash", Some("get"))));
+        assert_eq!(split_package_specifier("@acme/utils"), Some(("@acme/utils", None)));
+        assert_eq!(split_package_specifier("@acme/utils/a/b"), Some(("@acme/utils", Some("a/b"))));
+        assert_eq!(split_package_specifier("./local"), None);
+        assert_eq!(split_package_specifier("node:fs"), None);
+        assert_eq!(split_package_specifier("#internal"), None);
+    }
+
+    #[test]
+    fn test_aliases_resolve_only_unresolved_imports() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(cwd.join("src/utils")).unwrap();
+        fs::write(cwd.join("src/utils/format.ts"), "export {};").unwrap();
+        fs::write(cwd.join("index.ts"), "").unwrap();
+
+        let aliases = [
+            ModuleAlias::new("^[", vec![]),
+            ModuleAlias::new(
+                "^@/(.*)$",
+                vec![format!("{}/missing/$1", cwd.display()), format!("{}/src/$1", cwd.display())],
+            ),
+        ];
+        let resolver = ModuleResolver::new(&cwd).with_aliases(&aliases);
+        let from = cwd.join("index.ts");
+
+        assert_eq!(
+            resolver.resolve(&from, "@/utils/format"),
+            Some(cwd.join("src/utils/format.ts"))
+        );
+        assert!(!resolver.is_unresolved(&from, "@/utils/format", &ImportKind::Static));
+        assert!(resolver.is_unresolved(&from, "@/utils/missing", &ImportKind::Static));
+    }
+
+    #[test]
+    fn test_expand_captures() {
+        let re = Regex::new(r"^@(\w+)/(.*)$").unwrap();
+        let captures = re.captures("@app/utils/format").unwrap();
+        assert_eq!(expand_captures("<root>/$1/src/$2", &captures), "<root>/app/src/utils/format");
+        assert_eq!(expand_captures("$3/static", &captures), "/static");
+    }
+
+    #[test]
+    fn test_sass_partials_and_index_files() {
+        let temp = tempdir().unwrap();
+        let cwd = temp.path().canonicalize().unwrap();
+        fs::create_dir_all(cwd.join("styles/components")).unwrap();
+        fs::create_dir_all(cwd.join("styles/theme")).unwrap();
+        for file in [
+            "styles/main.scss",
+            "styles/_button.scss",
+            "styles/components/_card.sass",
+            "styles/theme/_index.scss",
+            "styles/plain.scss",
+            "styles/_plain.scss",
+            "styles/_explicit.scss",
+        ] {
+            fs::write(cwd.join(file), "").unwrap();
+        }
+
+        let resolver = ModuleResolver::new(&cwd);
+        let from = cwd.join("styles/main.scss");
+        let styles = cwd.join("styles");
+        let sass = |specifier| resolver.resolve_import(&from, specifier, &ImportKind::UseSass);
+        assert_eq!(sass("button"), vec![styles.join("_button.scss")]);
+        assert_eq!(sass("./button"), vec![styles.join("_button.scss")]);
+        assert_eq!(sass("components/card"), vec![styles.join("components/_card.sass")]);
+        assert_eq!(sass("theme"), vec![styles.join("theme/_index.scss")]);
+        // A plain file wins over its partial
+        assert_eq!(sass("plain"), vec![styles.join("plain.scss")]);
+        assert_eq!(sass("explicit.scss"), vec![styles.join("_explicit.scss")]);
+        assert!(!resolver.is_unresolved(&from, "button", &ImportKind::UseSass));
+        assert!(resolver.is_unresolved(&from, "~bootstrap/scss/bootstrap", &ImportKind::UseSass));
+
+        // The importing file doesn't matter: a component's `<style lang="scss">` loads
+        // partials too
+        let component = cwd.join("styles/Button.vue");
+        assert_eq!(
+            resolver.resolve_import(&component, "./button", &ImportKind::UseSass),
+            vec![styles.join("_button.scss")]
+        );
+
+        // Partials are Sass-only: a script importing `./button` doesn't find `_button.scss`
+        fs::write(cwd.join("styles/index.ts"), "").unwrap();
+        assert_eq!(resolver.resolve(&cwd.join("styles/index.ts"), "./button"), None);
+        assert_eq!(resolver.resolve(&from, "button"), None);
     }
 }
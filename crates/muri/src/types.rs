@@ -1,5 +1,10 @@
-use serde::Deserialize;
+use crate::classify::FileClass;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use thiserror::Error;
 
 /// Default extensions for JavaScript/TypeScript module resolution
@@ -7,12 +12,60 @@ pub const DEFAULT_EXTENSIONS: &[&str] =
     &[".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mjs", ".cjs", ".mts", ".cts", ".json"];
 
 /// Foreign file extensions - assets that can be imported but don't contain JS/TS code.
-/// These files can be resolved but are not added to the reachable set or parsed for imports.
+/// These files can be resolved but are not parsed for imports, and (apart from
+/// `REACHABLE_ASSET_EXTENSIONS`) are not added to the reachable set.
 pub const FOREIGN_FILE_EXTENSIONS: &[&str] = &[
     ".avif", ".css", ".eot", ".gif", ".html", ".ico", ".jpeg", ".jpg", ".less", ".mp3", ".png",
     ".sass", ".scss", ".sh", ".svg", ".ttf", ".webp", ".woff", ".woff2", ".yaml", ".yml",
 ];
 
+/// Component file extensions that are collected as project files (and so reported
+/// when unused) while the compiler handling them is active
+pub const COMPONENT_EXTENSIONS: &[&str] = &[".vue", ".svelte", ".astro"];
+
+/// Foreign file extensions that are added to the reachable set (as leaves) when imported.
+/// SVGs are commonly imported as components (e.g. via SVGR) and should not be reported
+/// as unresolved.
+pub const REACHABLE_ASSET_EXTENSIONS: &[&str] = &[".svg"];
+
+/// Default upper bound on the number of files the collector will visit
+pub const DEFAULT_MAX_FILES: usize = 200_000;
+
+/// Default directory (relative to cwd) for the persistent module cache
+pub const DEFAULT_CACHE_DIR: &str = ".muri-cache";
+
+/// Progress of a running analysis, reported through [`ProgressHandler`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// Collection finished with this many project files
+    FilesCollected(usize),
+    /// Files parsed so far during traversal, out of the expected total
+    Parsed(usize, usize),
+    /// Analysis finished
+    Done,
+}
+
+/// Callback invoked with [`ProgressEvent`]s. Events are emitted from the calling
+/// thread, in order.
+#[derive(Clone)]
+pub struct ProgressHandler(Arc<dyn Fn(ProgressEvent) + Send + Sync>);
+
+impl ProgressHandler {
+    pub fn new(f: impl Fn(ProgressEvent) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    pub fn emit(&self, event: ProgressEvent) {
+        (self.0)(event)
+    }
+}
+
+impl fmt::Debug for ProgressHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressHandler")
+    }
+}
+
 /// Configuration for finding unused files
 #[derive(Debug, Clone)]
 pub struct MuriConfig {
@@ -31,8 +84,74 @@ pub struct MuriConfig {
     /// Plugin configuration
     pub plugins: PluginConfig,
 
+    /// Compiler overrides
+    pub compilers: CompilerConfig,
+
     /// Enable verbose output
     pub verbose: bool,
+
+    /// Only report unused files of these classes (empty = all classes)
+    pub report_classes: Vec<FileClass>,
+
+    /// Project files matching these patterns are never reported as unused, e.g. files
+    /// loaded by tooling muri can't see. Unlike `ignore`, they are still collected.
+    pub allowlist: Vec<String>,
+
+    /// Abort collection once more than this many files have been visited
+    pub max_files: usize,
+
+    /// Only collect files at most this many directory levels below cwd (None = unlimited)
+    pub max_depth: Option<usize>,
+
+    /// Directory for the persistent module cache, relative to cwd (None = no disk cache)
+    pub cache_dir: Option<PathBuf>,
+
+    /// Also report exports of reachable files that are never imported
+    pub unused_exports: bool,
+
+    /// Skip paths excluded by `.gitignore` files (in addition to `ignore` patterns)
+    pub respect_gitignore: bool,
+
+    /// Follow symlinked files and directories while collecting project files
+    pub follow_symlinks: bool,
+
+    /// Check `.d.ts` declaration files too (they are skipped when collecting project files
+    /// by default, since ambient declarations are never imported)
+    pub include_dts: bool,
+
+    /// When no entry pattern matches a file, use the entry points declared in
+    /// package.json (`main`, `module`, `types`, `exports`, `bin`)
+    pub auto_entry: bool,
+
+    /// Number of threads used to parse and traverse files (None = one per CPU,
+    /// from rayon's global pool). `Some(1)` runs sequentially.
+    pub concurrency: Option<usize>,
+
+    /// Explicit project files (relative to cwd or absolute). When set, these replace
+    /// `project` globbing and the filesystem walk.
+    pub project_files: Option<Vec<PathBuf>>,
+
+    /// Only report these files (relative to cwd or absolute) as unused, e.g. the files
+    /// changed on a branch. Entries and reachability still cover every project file.
+    pub changed_files: Option<Vec<PathBuf>>,
+
+    /// Called as the analysis progresses (None = no progress reporting)
+    pub progress: Option<ProgressHandler>,
+
+    /// Stop token: once set, the analysis stops and returns `MuriError::Cancelled`
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl MuriConfig {
+    /// Whether the stop token has been set
+    pub fn is_cancelled(&self) -> bool {
+        is_cancelled(self.cancel.as_ref())
+    }
+}
+
+/// Whether an optional stop token has been set
+pub(crate) fn is_cancelled(cancel: Option<&Arc<AtomicBool>>) -> bool {
+    cancel.is_some_and(|flag| flag.load(Ordering::Relaxed))
 }
 
 impl Default for MuriConfig {
@@ -43,65 +162,216 @@ impl Default for MuriConfig {
             cwd: PathBuf::from("."),
             ignore: Vec::new(),
             plugins: PluginConfig::default(),
+            compilers: CompilerConfig::default(),
             verbose: false,
+            report_classes: Vec::new(),
+            allowlist: Vec::new(),
+            max_files: DEFAULT_MAX_FILES,
+            max_depth: None,
+            cache_dir: None,
+            unused_exports: false,
+            respect_gitignore: true,
+            follow_symlinks: false,
+            include_dts: false,
+            auto_entry: false,
+            concurrency: None,
+            project_files: None,
+            changed_files: None,
+            progress: None,
+            cancel: None,
         }
     }
 }
 
 /// Configuration for plugins that discover entry points
-#[derive(Debug, Clone, Default, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct PluginConfig {
-    /// Enable/disable Storybook plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub storybook: Option<bool>,
+    /// Settings for Storybook plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storybook: Option<PluginSettings>,
 
-    /// Enable/disable Tailwind CSS plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub tailwind: Option<bool>,
+    /// Settings for Tailwind CSS plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tailwind: Option<PluginSettings>,
 
-    /// Enable/disable PostCSS plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub postcss: Option<bool>,
+    /// Settings for PostCSS plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postcss: Option<PluginSettings>,
 
-    /// Enable/disable Jest plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub jest: Option<bool>,
+    /// Settings for Jest plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jest: Option<PluginSettings>,
 
-    /// Enable/disable Vitest plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub vitest: Option<bool>,
+    /// Settings for Vitest plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vitest: Option<PluginSettings>,
 
-    /// Enable/disable Next.js plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub nextjs: Option<bool>,
+    /// Settings for Next.js plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nextjs: Option<PluginSettings>,
 
-    /// Enable/disable ESLint plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub eslint: Option<bool>,
+    /// Settings for ESLint plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eslint: Option<PluginSettings>,
 
-    /// Enable/disable Vite plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub vite: Option<bool>,
+    /// Settings for Vite plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vite: Option<PluginSettings>,
 
-    /// Enable/disable TypeScript plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub typescript: Option<bool>,
+    /// Settings for TypeScript plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typescript: Option<PluginSettings>,
 
-    /// Enable/disable Cypress plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub cypress: Option<bool>,
+    /// Settings for Cypress plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cypress: Option<PluginSettings>,
 
-    /// Enable/disable Playwright plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub playwright: Option<bool>,
+    /// Settings for Playwright plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub playwright: Option<PluginSettings>,
 
-    /// Enable/disable husky plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub husky: Option<bool>,
+    /// Settings for husky plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub husky: Option<PluginSettings>,
 
-    /// Enable/disable lint-staged plugin (None = auto-detect based on dependencies)
-    #[serde(default)]
-    pub lint_staged: Option<bool>,
+    /// Settings for lint-staged plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lint_staged: Option<PluginSettings>,
+
+    /// Settings for Capacitor plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capacitor: Option<PluginSettings>,
+
+    /// Settings for Electron plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub electron: Option<PluginSettings>,
+
+    /// Settings for Stencil plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stencil: Option<PluginSettings>,
+
+    /// Settings for AVA plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ava: Option<PluginSettings>,
+
+    /// Settings for Mocha plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mocha: Option<PluginSettings>,
+
+    /// Settings for WebdriverIO plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wdio: Option<PluginSettings>,
+
+    /// Settings for Karma plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub karma: Option<PluginSettings>,
+
+    /// Settings for Gatsby plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gatsby: Option<PluginSettings>,
+
+    /// Settings for Angular plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub angular: Option<PluginSettings>,
+
+    /// Settings for esbuild plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub esbuild: Option<PluginSettings>,
+
+    /// Settings for Rollup plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<PluginSettings>,
+
+    /// Settings for Turborepo plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub turbo: Option<PluginSettings>,
+
+    /// Settings for Nx plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nx: Option<PluginSettings>,
+
+    /// Settings for SolidStart plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solid_start: Option<PluginSettings>,
+
+    /// Settings for Qwik plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qwik: Option<PluginSettings>,
+
+    /// Settings for MSW plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub msw: Option<PluginSettings>,
+
+    /// Settings for Babel plugin (None = auto-detect based on dependencies)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub babel: Option<PluginSettings>,
+}
+
+/// Compiler overrides from the config file, keyed by compiler name
+/// (e.g. `{ "scss": false, "stylus": true }`). Compilers without an entry
+/// are enabled based on dependencies.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct CompilerConfig(pub FxHashMap<String, bool>);
+
+impl CompilerConfig {
+    /// Forced state of a compiler (None = auto-detect)
+    pub fn enabled(&self, name: &str) -> Option<bool> {
+        self.0.get(name).copied()
+    }
+}
+
+/// Per-plugin settings from the config file.
+///
+/// Accepts either a boolean toggle (`"jest": false`) or an object with
+/// additional settings (`"jest": { "config": "config/jest.js" }`).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(from = "PluginSettingsInput")]
+pub struct PluginSettings {
+    /// Force the plugin on/off (None = auto-detect based on dependencies)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Extra entry patterns to add when the plugin is enabled
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entry: Vec<String>,
+
+    /// Custom config file path, relative to cwd
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<PathBuf>,
+}
+
+impl PluginSettings {
+    /// Create settings that only force the plugin on or off
+    pub fn enabled(enabled: bool) -> Self {
+        Self { enabled: Some(enabled), ..Default::default() }
+    }
+}
+
+/// Raw config file forms accepted for a plugin entry
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PluginSettingsInput {
+    Toggle(bool),
+    Settings {
+        #[serde(default)]
+        enabled: Option<bool>,
+        #[serde(default)]
+        entry: Vec<String>,
+        #[serde(default)]
+        config: Option<PathBuf>,
+    },
+}
+
+impl From<PluginSettingsInput> for PluginSettings {
+    fn from(input: PluginSettingsInput) -> Self {
+        match input {
+            PluginSettingsInput::Toggle(enabled) => Self::enabled(enabled),
+            PluginSettingsInput::Settings { enabled, entry, config } => {
+                Self { enabled, entry, config }
+            }
+        }
+    }
 }
 
 /// Error types for muri operations
@@ -112,10 +382,27 @@ pub enum MuriError {
 
     #[error("Invalid working directory: {0}")]
     InvalidCwd(#[from] std::io::Error),
+
+    #[error(
+        "Found more than {limit} files under {cwd}. Narrow `project`/`cwd` or raise --max-files."
+    )]
+    TooManyFiles { limit: usize, cwd: PathBuf },
+
+    #[error("Analysis was cancelled")]
+    Cancelled,
+
+    #[error("Failed to parse config file {}: {message}", path.display())]
+    ConfigParse { path: PathBuf, message: String },
+
+    #[error("Invalid glob pattern: {0:?}")]
+    InvalidPattern(String),
+
+    #[error("Failed to start {threads} analysis threads: {message}")]
+    ThreadPool { threads: usize, message: String },
 }
 
-/// Config file structure for muri.json / muri.jsonc
-#[derive(Debug, Clone, Deserialize)]
+/// Config file structure for muri.json / muri.jsonc / muri.yaml
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct FileConfig {
     #[serde(default)]
     pub entry: Vec<String>,
@@ -128,4 +415,35 @@ pub struct FileConfig {
 
     #[serde(default)]
     pub plugins: PluginConfig,
+
+    /// Force compilers on (`true`) or off (`false`) regardless of dependencies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compilers: Option<CompilerConfig>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub report_classes: Vec<FileClass>,
+
+    /// Patterns of project files that are never reported as unused
+    #[serde(default, alias = "ignore_unused", skip_serializing_if = "Vec::is_empty")]
+    pub allowlist: Vec<String>,
+
+    /// Persist parsed modules between runs (stored in `.muri-cache/`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<bool>,
+
+    /// Also report exports of reachable files that are never imported
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unused_exports: bool,
+
+    /// Also check `.d.ts` declaration files
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub include_dts: bool,
+
+    /// Fall back to package.json entry points when no entry pattern matches
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_entry: bool,
+
+    /// Number of threads to analyze with (unset = one per CPU)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<usize>,
 }
@@ -0,0 +1,200 @@
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use rustc_hash::FxHashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Read workspace package globs from `package.json` (`workspaces`, either an array
+/// or yarn's `{ "packages": [...] }`) and `pnpm-workspace.yaml`
+pub fn workspace_patterns(root: &Path) -> Vec<String> {
+    let mut patterns = Vec::new();
+
+    if let Some(json) = fs::read_to_string(root.join("package.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+    {
+        let workspaces = json.get("workspaces");
+        let list = workspaces.and_then(|w| w.as_array().or_else(|| w.get("packages")?.as_array()));
+        patterns.extend(list.into_iter().flatten().filter_map(|v| v.as_str().map(String::from)));
+    }
+
+    if let Ok(content) = fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        patterns.extend(parse_pnpm_workspace(&content));
+    }
+
+    patterns
+}
+
+/// Extract the `packages:` list from `pnpm-workspace.yaml`
+fn parse_pnpm_workspace(content: &str) -> Vec<String> {
+    let mut patterns = Vec::new();
+    let mut in_packages = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        // A new top-level key ends the `packages` list
+        if !line.starts_with([' ', '\t', '-']) {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+
+        if in_packages {
+            if let Some(item) = trimmed.strip_prefix('-') {
+                let item = item.split(" #").next().unwrap_or(item).trim();
+                let item = item.trim_matches(|c| c == '"' || c == '\'');
+                if !item.is_empty() {
+                    patterns.push(item.to_string());
+                }
+            }
+        }
+    }
+
+    patterns
+}
+
+/// Build a glob set where `*` does not cross directory boundaries
+fn build_globset<'a>(patterns: impl Iterator<Item = &'a str>) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        if let Ok(glob) = GlobBuilder::new(pattern).literal_separator(true).build() {
+            builder.add(glob);
+        }
+    }
+    builder.build().unwrap_or_else(|_| GlobSetBuilder::new().build().unwrap())
+}
+
+/// Find workspace package directories (canonical, sorted) under `root`.
+///
+/// A directory is a package if it matches a workspace glob, doesn't match a
+/// negated (`!`) glob, and contains a `package.json`.
+pub fn find_workspace_packages(root: &Path) -> Vec<PathBuf> {
+    let patterns = workspace_patterns(root);
+    let (negated, positive): (Vec<&str>, Vec<&str>) =
+        patterns.iter().map(String::as_str).partition(|p| p.starts_with('!'));
+    if positive.is_empty() {
+        return Vec::new();
+    }
+
+    let include = build_globset(positive.into_iter());
+    let exclude = build_globset(negated.into_iter().map(|p| &p[1..]));
+
+    let walker = WalkBuilder::new(root)
+        .hidden(false)
+        .require_git(false)
+        .filter_entry(|entry| entry.file_name() != "node_modules")
+        .build();
+
+    let mut packages: Vec<PathBuf> = walker
+        .flatten()
+        .filter(|entry| entry.file_type().is_some_and(|t| t.is_dir()))
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(root).ok()?;
+            if relative.as_os_str().is_empty()
+                || !include.is_match(relative)
+                || exclude.is_match(relative)
+                || !entry.path().join("package.json").is_file()
+            {
+                return None;
+            }
+            entry.path().canonicalize().ok()
+        })
+        .collect();
+
+    packages.sort();
+    packages.dedup();
+    packages
+}
+
+/// Map the `name` of each workspace package to its directory.
+///
+/// The workspace root is `cwd` or the closest ancestor declaring workspaces, so
+/// a single package of a monorepo still sees its siblings. Packages without a
+/// name are skipped.
+pub fn workspace_package_index(cwd: &Path) -> FxHashMap<String, PathBuf> {
+    let Some(root) = cwd.ancestors().find(|dir| !workspace_patterns(dir).is_empty()) else {
+        return FxHashMap::default();
+    };
+
+    find_workspace_packages(root)
+        .into_iter()
+        .filter_map(|dir| {
+            let content = fs::read_to_string(dir.join("package.json")).ok()?;
+            let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+            Some((json.get("name")?.as_str()?.to_string(), dir))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn package(root: &Path, dir: &str) {
+        fs::create_dir_all(root.join(dir)).unwrap();
+        fs::write(root.join(dir).join("package.json"), "{}").unwrap();
+    }
+
+    fn relative(packages: &[PathBuf], root: &Path) -> Vec<String> {
+        let root = root.canonicalize().unwrap();
+        packages.iter().map(|p| p.strip_prefix(&root).unwrap().display().to_string()).collect()
+    }
+
+    #[test]
+    fn test_package_json_workspaces() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "workspaces": ["packages/*", "!packages/private"] }"#,
+        )
+        .unwrap();
+        package(temp.path(), "packages/a");
+        package(temp.path(), "packages/b");
+        package(temp.path(), "packages/private");
+        package(temp.path(), "packages/a/nested");
+        fs::create_dir_all(temp.path().join("packages/no-manifest")).unwrap();
+
+        let packages = find_workspace_packages(temp.path());
+        assert_eq!(relative(&packages, temp.path()), vec!["packages/a", "packages/b"]);
+    }
+
+    #[test]
+    fn test_yarn_workspaces_object() {
+        let temp = tempdir().unwrap();
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{ "workspaces": { "packages": ["apps/*"] } }"#,
+        )
+        .unwrap();
+        package(temp.path(), "apps/web");
+
+        let packages = find_workspace_packages(temp.path());
+        assert_eq!(relative(&packages, temp.path()), vec!["apps/web"]);
+    }
+
+    #[test]
+    fn test_pnpm_workspace_yaml() {
+        let content = r#"
+packages:
+  # all packages
+  - 'packages/*'
+  - "apps/**"
+  - '!**/test/**'
+catalog:
+  react: ^18.0.0
+"#;
+        assert_eq!(parse_pnpm_workspace(content), vec!["packages/*", "apps/**", "!**/test/**"]);
+    }
+
+    #[test]
+    fn test_no_workspaces() {
+        let temp = tempdir().unwrap();
+        fs::write(temp.path().join("package.json"), r#"{ "name": "app" }"#).unwrap();
+        assert!(find_workspace_packages(temp.path()).is_empty());
+    }
+}
@@ -21,6 +21,12 @@ export interface UnusedFilesOptions {
    * Patterns to ignore
    */
   ignore?: string[];
+
+  /**
+   * Cancels the analysis when aborted; the promise rejects with
+   * "Analysis was cancelled". Only used by the async functions.
+   */
+  signal?: AbortSignal;
 }
 
 export interface UnusedFilesReport {
@@ -92,3 +98,24 @@ export function findUnusedSync(options: UnusedFilesOptions): UnusedFilesReport;
  * ```
  */
 export function findReachable(options: UnusedFilesOptions): Promise<string[]>;
+
+/**
+ * Build the dependency graph of a project
+ *
+ * Maps each project file (and each file reachable from an entry point),
+ * relative to cwd, to the files it imports.
+ *
+ * @example
+ * ```ts
+ * import { buildGraph } from 'muri';
+ *
+ * const graph = buildGraph({
+ *   entry: ['src/index.ts'],
+ * });
+ *
+ * for (const [file, imports] of Object.entries(graph)) {
+ *   console.log(file, '->', imports);
+ * }
+ * ```
+ */
+export function buildGraph(options: UnusedFilesOptions): Record<string, string[]>;
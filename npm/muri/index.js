@@ -50,6 +50,28 @@ function getNativeModule() {
   return nativeModule;
 }
 
+/**
+ * Run a cancelable native call, cancelling it when `signal` aborts.
+ * The token stays referenced until the call settles.
+ */
+async function withSignal(signal, run) {
+  if (!signal) {
+    return run(undefined);
+  }
+
+  const token = new (getNativeModule().CancelToken)();
+  if (signal.aborted) {
+    token.cancel();
+  }
+  const onAbort = () => token.cancel();
+  signal.addEventListener('abort', onAbort);
+  try {
+    return await run(token);
+  } finally {
+    signal.removeEventListener('abort', onAbort);
+  }
+}
+
 /**
  * Find unused files in a JavaScript/TypeScript project
  * @param {Object} options - Configuration options
@@ -57,6 +79,7 @@ function getNativeModule() {
  * @param {string|string[]} [options.project] - Project files to check (glob patterns)
  * @param {string} [options.cwd] - Working directory (defaults to current directory)
  * @param {string[]} [options.ignore] - Patterns to ignore
+ * @param {AbortSignal} [options.signal] - Aborting rejects with "Analysis was cancelled"
  * @returns {Promise<{unusedFiles: string[], totalFiles: number, unusedCount: number}>}
  */
 async function findUnused(options) {
@@ -70,12 +93,12 @@ async function findUnused(options) {
     ? (Array.isArray(options.project) ? options.project : [options.project])
     : undefined;
 
-  return native.findUnused({
+  return withSignal(options.signal, (token) => native.findUnused({
     entry,
     project,
     cwd: options.cwd,
     ignore: options.ignore,
-  });
+  }, token));
 }
 
 /**
@@ -110,6 +133,7 @@ function findUnusedSync(options) {
  * @param {string|string[]} [options.project] - Project files to check (glob patterns)
  * @param {string} [options.cwd] - Working directory (defaults to current directory)
  * @param {string[]} [options.ignore] - Patterns to ignore
+ * @param {AbortSignal} [options.signal] - Aborting rejects with "Analysis was cancelled"
  * @returns {Promise<string[]>}
  */
 async function findReachable(options) {
@@ -120,7 +144,32 @@ async function findReachable(options) {
     ? (Array.isArray(options.project) ? options.project : [options.project])
     : undefined;
 
-  return native.findReachable({
+  return withSignal(options.signal, (token) => native.findReachable({
+    entry,
+    project,
+    cwd: options.cwd,
+    ignore: options.ignore,
+  }, token));
+}
+
+/**
+ * Build the dependency graph of a project
+ * @param {Object} options - Configuration options
+ * @param {string|string[]} options.entry - Entry point files or glob patterns
+ * @param {string|string[]} [options.project] - Project files to check (glob patterns)
+ * @param {string} [options.cwd] - Working directory (defaults to current directory)
+ * @param {string[]} [options.ignore] - Patterns to ignore
+ * @returns {Object<string, string[]>} Each file (relative to cwd) mapped to the files it imports
+ */
+function buildGraph(options) {
+  const native = getNativeModule();
+
+  const entry = Array.isArray(options.entry) ? options.entry : [options.entry];
+  const project = options.project
+    ? (Array.isArray(options.project) ? options.project : [options.project])
+    : undefined;
+
+  return native.buildGraph({
     entry,
     project,
     cwd: options.cwd,
@@ -132,4 +181,5 @@ module.exports = {
   findUnused,
   findUnusedSync,
   findReachable,
+  buildGraph,
 };
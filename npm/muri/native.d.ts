@@ -3,6 +3,17 @@
 
 /* auto-generated by NAPI-RS */
 
+/**
+ * Token for cancelling a running analysis, e.g. from an `AbortSignal`:
+ * `signal.addEventListener('abort', () => token.cancel())`
+ */
+export declare class CancelToken {
+  constructor()
+  /** Stop the analysis; it rejects with "Analysis was cancelled" */
+  cancel(): void
+  /** Whether `cancel` has been called */
+  get isCancelled(): boolean
+}
 /** Options for finding unused files */
 export interface UnusedFilesOptions {
   /** Entry point files or glob patterns */
@@ -27,9 +38,10 @@ export interface UnusedFilesReport {
  * Find unused files in a JavaScript/TypeScript project (async)
  *
  * @param options - Configuration options
+ * @param token - Optional token to cancel the analysis
  * @returns Promise with report containing unused files and statistics
  */
-export declare function findUnused(options: UnusedFilesOptions): Promise<UnusedFilesReport>
+export declare function findUnused(options: UnusedFilesOptions, token?: CancelToken | undefined | null): Promise<UnusedFilesReport>
 /**
  * Find unused files in a JavaScript/TypeScript project (sync)
  *
@@ -41,6 +53,14 @@ export declare function findUnusedSync(options: UnusedFilesOptions): UnusedFiles
  * Find all files reachable from entry points (async)
  *
  * @param options - Configuration options
+ * @param token - Optional token to cancel the analysis
  * @returns Promise with array of reachable file paths
  */
-export declare function findReachable(options: UnusedFilesOptions): Promise<Array<string>>
+export declare function findReachable(options: UnusedFilesOptions, token?: CancelToken | undefined | null): Promise<Array<string>>
+/**
+ * Build the dependency graph of a project
+ *
+ * @param options - Configuration options
+ * @returns Object mapping each file (relative to cwd) to the files it imports
+ */
+export declare function buildGraph(options: UnusedFilesOptions): Record<string, Array<string>>
@@ -310,8 +310,10 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { findUnused, findUnusedSync, findReachable } = nativeBinding
+const { CancelToken, findUnused, findUnusedSync, findReachable, buildGraph } = nativeBinding
 
+module.exports.CancelToken = CancelToken
 module.exports.findUnused = findUnused
 module.exports.findUnusedSync = findUnusedSync
 module.exports.findReachable = findReachable
+module.exports.buildGraph = buildGraph
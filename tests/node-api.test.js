@@ -6,11 +6,14 @@
  *   node tests/node-api.test.js
  */
 
+const assert = require('assert');
 const path = require('path');
-const { findUnused, findUnusedSync, findReachable } = require('../npm/muri');
+const { findUnused, findUnusedSync, findReachable, buildGraph } = require('../npm/muri');
 
 const testProjectPath = path.join(__dirname, 'fixtures', 'node-api');
 
+let failures = 0;
+
 async function runTests() {
   console.log('Testing unused-files Node.js API\n');
   console.log('Test project:', testProjectPath);
@@ -26,6 +29,7 @@ async function runTests() {
     console.log('  Result:', JSON.stringify(result, null, 2));
     console.log('  ✓ findUnused works');
   } catch (e) {
+    failures++;
     console.error('  ✗ findUnused failed:', e.message);
   }
 
@@ -39,6 +43,7 @@ async function runTests() {
     console.log('  Result:', JSON.stringify(result, null, 2));
     console.log('  ✓ findUnusedSync works');
   } catch (e) {
+    failures++;
     console.error('  ✗ findUnusedSync failed:', e.message);
   }
 
@@ -52,6 +57,7 @@ async function runTests() {
     console.log('  Reachable files:', files);
     console.log('  ✓ findReachable works');
   } catch (e) {
+    failures++;
     console.error('  ✗ findReachable failed:', e.message);
   }
 
@@ -65,6 +71,7 @@ async function runTests() {
     console.log('  Result:', JSON.stringify(result, null, 2));
     console.log('  ✓ String entry works');
   } catch (e) {
+    failures++;
     console.error('  ✗ String entry failed:', e.message);
   }
 
@@ -79,11 +86,39 @@ async function runTests() {
     console.log('  Result:', JSON.stringify(result, null, 2));
     console.log('  ✓ Ignore patterns work');
   } catch (e) {
+    failures++;
     console.error('  ✗ Ignore patterns failed:', e.message);
   }
 
+  // Test buildGraph
+  console.log('\n6. Testing buildGraph (sync):');
+  try {
+    const graph = buildGraph({
+      entry: ['src/index.ts'],
+      cwd: testProjectPath,
+    });
+    console.log('  Graph:', JSON.stringify(graph, null, 2));
+    assert.ok('src/index.ts' in graph, 'src/index.ts is missing from the graph');
+    assert.ok(
+      graph['src/index.ts'].includes('src/helper.ts'),
+      'src/index.ts has no edge to src/helper.ts'
+    );
+    console.log('  ✓ buildGraph works');
+  } catch (e) {
+    failures++;
+    console.error('  ✗ buildGraph failed:', e.message);
+  }
+
   console.log('\n---');
-  console.log('Tests complete!');
+  if (failures > 0) {
+    console.error(`${failures} test(s) failed`);
+    process.exitCode = 1;
+  } else {
+    console.log('Tests complete!');
+  }
 }
 
-runTests().catch(console.error);
+runTests().catch((e) => {
+  console.error(e);
+  process.exitCode = 1;
+});